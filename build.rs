@@ -0,0 +1,87 @@
+/* build.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+//! With the `const-table` feature, generates the spellings of 0 to 999 as static tables so
+//! that the library doesn't need to compose them at runtime.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const UNDER_20: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+fn under_100(x: usize) -> String {
+    match x {
+        0..=19 => UNDER_20[x].to_string(),
+        _ if x % 10 == 0 => TENS[x / 10].to_string(),
+        _ => format!("{}-{}", TENS[x / 10], UNDER_20[x % 10]),
+    }
+}
+
+fn under_1000(x: usize, and: &str) -> String {
+    match x {
+        0..=99 => under_100(x),
+        _ if x % 100 == 0 => format!("{}-hundred", UNDER_20[x / 100]),
+        _ => format!("{}-hundred{}{}", UNDER_20[x / 100], and, under_100(x % 100)),
+    }
+}
+
+fn table(name: &str, and: &str) -> String {
+    let entries: Vec<String> = (0..1000)
+        .map(|x| format!("    {:?},", under_1000(x, and)))
+        .collect();
+    format!(
+        "pub static {}: [&str; 1000] = [\n{}\n];\n",
+        name,
+        entries.join("\n")
+    )
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_CONST_TABLE").is_none() {
+        return;
+    }
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR is set by cargo");
+    let contents = format!(
+        "{}\n{}",
+        table("UNDER_1000", " "),
+        table("UNDER_1000_AND", " and ")
+    );
+    fs::write(Path::new(&out_dir).join("tables.rs"), contents).expect("can write tables.rs");
+}
@@ -0,0 +1,209 @@
+/* src/approximate.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::AndBehavior;
+use super::to_word;
+
+/// Relative distance (in percent of the rounded value) below which the rounding is
+/// described as "nearly" or "just over" instead of "about"
+const CLOSE_PERCENT: u128 = 5;
+
+/// Word describing how a rounded value relates to the exact value
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Qualifier {
+    /// The value was rounded by a noticeable amount in either direction
+    About,
+
+    /// The value was rounded down by a small amount
+    JustOver,
+
+    /// The value was rounded up by a small amount
+    Nearly,
+}
+
+impl Qualifier {
+    /// Returns the qualifier as words
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::About => "about",
+            Self::JustOver => "just over",
+            Self::Nearly => "nearly",
+        }
+    }
+}
+
+/// A value rounded to a number of significant digits, with a qualifier describing the
+/// rounding (`None` when the rounding was exact)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Approximation {
+    /// The rounded value
+    pub value: u64,
+
+    /// How the rounded value relates to the exact value
+    pub qualifier: Option<Qualifier>,
+}
+
+impl Approximation {
+    /// Writes the rounded value as words, preceded by its qualifier
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::AndBehavior;
+    /// use numbers_into_words::approximate::approximate;
+    /// assert_eq!(
+    ///     approximate(3_271_004, 1).to_words(AndBehavior::All),
+    ///     "about three million".to_string()
+    /// );
+    /// assert_eq!(
+    ///     approximate(2_040, 1).to_words(AndBehavior::All),
+    ///     "just over two thousand".to_string()
+    /// );
+    /// assert_eq!(
+    ///     approximate(496, 1).to_words(AndBehavior::All),
+    ///     "nearly five-hundred".to_string()
+    /// );
+    /// assert_eq!(
+    ///     approximate(500, 1).to_words(AndBehavior::All),
+    ///     "five-hundred".to_string()
+    /// );
+    /// ```
+    pub fn to_words(&self, and_behavior: AndBehavior) -> String {
+        match self.qualifier {
+            Some(q) => format!("{} {}", q.as_str(), to_word(self.value, and_behavior)),
+            None => to_word(self.value, and_behavior),
+        }
+    }
+}
+
+/// Rounds `x` (half up) to `significant_digits` significant digits and chooses the
+/// qualifier from the direction and distance of the rounding
+///
+/// If rounding up would overflow `u64`, more significant digits are kept.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::approximate::{approximate, Approximation, Qualifier};
+/// assert_eq!(
+///     approximate(1_249, 1),
+///     Approximation { value: 1_000, qualifier: Some(Qualifier::About) }
+/// );
+/// assert_eq!(
+///     approximate(1_199, 2),
+///     Approximation { value: 1_200, qualifier: Some(Qualifier::Nearly) }
+/// );
+/// ```
+pub fn approximate(x: u64, significant_digits: u32) -> Approximation {
+    let digits = x.checked_ilog10().map_or(1, |d| d + 1);
+    let mut keep = significant_digits.max(1);
+    let rounded = loop {
+        if keep >= digits {
+            break x as u128;
+        }
+        let unit = 10_u128.pow(digits - keep);
+        let rounded = (x as u128 + unit / 2) / unit * unit;
+        if rounded <= u64::MAX as u128 {
+            break rounded;
+        }
+        keep += 1;
+    };
+    let exact = x as u128;
+    let qualifier = match rounded.cmp(&exact) {
+        std::cmp::Ordering::Equal => None,
+        _ if rounded.abs_diff(exact) * 100 >= rounded * CLOSE_PERCENT => Some(Qualifier::About),
+        std::cmp::Ordering::Greater => Some(Qualifier::Nearly),
+        std::cmp::Ordering::Less => Some(Qualifier::JustOver),
+    };
+    Approximation {
+        value: rounded as u64,
+        qualifier,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approximate() {
+        assert_eq!(
+            approximate(0, 1),
+            Approximation {
+                value: 0,
+                qualifier: None
+            }
+        );
+        assert_eq!(
+            approximate(7, 1),
+            Approximation {
+                value: 7,
+                qualifier: None
+            }
+        );
+        assert_eq!(
+            approximate(2_950_000, 1),
+            Approximation {
+                value: 3_000_000,
+                qualifier: Some(Qualifier::Nearly)
+            }
+        );
+        assert_eq!(
+            approximate(3_100_000, 1),
+            Approximation {
+                value: 3_000_000,
+                qualifier: Some(Qualifier::JustOver)
+            }
+        );
+        assert_eq!(
+            approximate(2_600_000, 1),
+            Approximation {
+                value: 3_000_000,
+                qualifier: Some(Qualifier::About)
+            }
+        );
+        assert_eq!(
+            approximate(3_400_000, 0),
+            Approximation {
+                value: 3_000_000,
+                qualifier: Some(Qualifier::About)
+            }
+        );
+        assert_eq!(
+            approximate(123_456, 3),
+            Approximation {
+                value: 123_000,
+                qualifier: Some(Qualifier::JustOver)
+            }
+        );
+        assert_eq!(
+            approximate(u64::MAX, 1),
+            Approximation {
+                value: 18_000_000_000_000_000_000,
+                qualifier: Some(Qualifier::JustOver)
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_words() {
+        assert_eq!(
+            approximate(u64::MAX, 1).to_words(AndBehavior::All),
+            String::from("just over eighteen quintillion")
+        );
+        assert_eq!(
+            approximate(149_999, 2).to_words(AndBehavior::None),
+            String::from("nearly one-hundred fifty thousand")
+        );
+    }
+}
@@ -0,0 +1,245 @@
+/* src/benchmark.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::AndBehavior;
+use super::{to_word, to_word_cow, to_word_u128};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Default number of conversions for a benchmark run
+pub const DEFAULT_COUNT: u64 = 1_000_000;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator that counts allocations, so benchmark reports can include them
+///
+/// The command-line program installs it only with the `count-allocations` feature, since
+/// every allocation pays for the counting.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::benchmark::CountingAllocator;
+///
+/// #[global_allocator]
+/// static GLOBAL: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Results of a benchmark run
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BenchReport {
+    /// Number of conversions performed
+    pub conversions: u64,
+
+    /// Wall-clock time for all conversions
+    pub elapsed: Duration,
+
+    /// Total length of the words produced, in bytes
+    pub output_bytes: usize,
+
+    /// Number of allocations, if `CountingAllocator` is the global allocator
+    pub allocations: Option<usize>,
+
+    /// Number of bytes allocated, if `CountingAllocator` is the global allocator
+    pub allocated_bytes: Option<usize>,
+
+    /// Whether the spellings of 0 to 999 came from the tables of the `const-table`
+    /// feature, for comparing a run with one built without it
+    pub const_table: bool,
+}
+
+impl fmt::Display for BenchReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secs = self.elapsed.as_secs_f64();
+        writeln!(
+            f,
+            "Converted {} numbers in {:.3} s ({:.0} numbers/s)",
+            self.conversions,
+            secs,
+            if secs > 0.0 {
+                self.conversions as f64 / secs
+            } else {
+                f64::INFINITY
+            }
+        )?;
+        writeln!(f, "Output: {} bytes of words", self.output_bytes)?;
+        writeln!(
+            f,
+            "Spellings under 1000: {}",
+            if self.const_table {
+                "generated at build time (const-table)"
+            } else {
+                "composed at runtime"
+            }
+        )?;
+        match (self.allocations, self.allocated_bytes) {
+            (Some(count), Some(bytes)) => write!(
+                f,
+                "Allocations: {} ({:.2} per number), {} bytes",
+                count,
+                count as f64 / self.conversions.max(1) as f64,
+                bytes
+            ),
+            _ => write!(
+                f,
+                "Allocations: not measured (build with the count-allocations feature)"
+            ),
+        }
+    }
+}
+
+/// Deterministic workload: a mix of small values, group boundaries, and spread-out large
+/// values, cycling through every `AndBehavior`
+fn workload(k: u64, state: &mut u64) -> (u64, AndBehavior) {
+    // xorshift64
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    let value = match k % 4 {
+        0 => *state % 1000,
+        1 => 10_u64.pow((*state % 20) as u32),
+        _ => *state >> (*state % 64),
+    };
+    // `run` picks the function with `k % 3`, so the "and" behavior changes every third
+    // number to meet each function with each behavior
+    let and_behavior = match (k / 3) % 4 {
+        0 => AndBehavior::All,
+        1 => AndBehavior::LastGroup,
+        2 => AndBehavior::OnlyUnderThousand,
+        _ => AndBehavior::None,
+    };
+    (value, and_behavior)
+}
+
+/// Converts a deterministic workload of `count` numbers and reports throughput
+///
+/// The numbers go in turn through `to_word`, `to_word_cow`, and `to_word_u128`, which all
+/// use the tables of the `const-table` feature when it is enabled, so that runs built
+/// with and without it can be compared.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::benchmark::run;
+/// let report = run(1000);
+/// assert_eq!(report.conversions, 1000);
+/// assert!(report.output_bytes > 0);
+/// ```
+pub fn run(count: u64) -> BenchReport {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes_before = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let mut output_bytes = 0;
+    for k in 0..count {
+        let (value, and_behavior) = workload(k, &mut state);
+        let value = black_box(value);
+        output_bytes += match k % 3 {
+            0 => black_box(to_word(value, and_behavior)).len(),
+            1 => black_box(to_word_cow(value, and_behavior)).len(),
+            _ => black_box(to_word_u128(value as u128, and_behavior)).len(),
+        };
+    }
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes_before;
+
+    // `to_word` always allocates, so a count of zero means the counting allocator
+    // isn't installed.
+    let counted = allocations > 0;
+    BenchReport {
+        conversions: count,
+        elapsed,
+        output_bytes,
+        allocations: counted.then_some(allocations),
+        allocated_bytes: counted.then_some(allocated_bytes),
+        const_table: cfg!(feature = "const-table"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workload_is_deterministic() {
+        let mut a: u64 = 1;
+        let mut b: u64 = 1;
+        for k in 0..100 {
+            assert_eq!(workload(k, &mut a), workload(k, &mut b));
+        }
+    }
+
+    #[test]
+    fn test_workload_pairs_every_function_and_behavior() {
+        let mut state: u64 = 1;
+        let mut pairs: Vec<(u64, AndBehavior)> = Vec::new();
+        for k in 0..12 {
+            let pair = (k % 3, workload(k, &mut state).1);
+            assert!(!pairs.contains(&pair));
+            pairs.push(pair);
+        }
+    }
+
+    #[test]
+    fn test_report_display() {
+        let report = BenchReport {
+            conversions: 4,
+            elapsed: Duration::from_millis(2),
+            output_bytes: 40,
+            allocations: Some(10),
+            allocated_bytes: Some(512),
+            const_table: true,
+        };
+        assert_eq!(
+            report.to_string(),
+            "Converted 4 numbers in 0.002 s (2000 numbers/s)\n\
+             Output: 40 bytes of words\n\
+             Spellings under 1000: generated at build time (const-table)\n\
+             Allocations: 10 (2.50 per number), 512 bytes"
+        );
+        let report = BenchReport {
+            allocations: None,
+            allocated_bytes: None,
+            const_table: false,
+            ..report
+        };
+        assert!(report.to_string().ends_with(
+            "Spellings under 1000: composed at runtime\n\
+             Allocations: not measured (build with the count-allocations feature)"
+        ));
+    }
+}
@@ -0,0 +1,156 @@
+/* src/bigint.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{group_words, scale_word, AndBehavior};
+use super::to_word_u128;
+
+/// Scale words past "undecillion", for the 13th group of three digits onwards
+const EXTENDED_POWERS_THOUSAND: [&str; 9] = [
+    " duodecillion",
+    " tredecillion",
+    " quattuordecillion",
+    " quindecillion",
+    " sexdecillion",
+    " septendecillion",
+    " octodecillion",
+    " novemdecillion",
+    " vigintillion",
+];
+
+/// Index of the first group named in `EXTENDED_POWERS_THOUSAND`
+const FIRST_EXTENDED_GROUP: usize = 13;
+
+/// The scale for a group index, falling back to a power of ten past "vigintillion"
+fn big_scale_word(group: usize, and_behavior: AndBehavior) -> String {
+    if group < FIRST_EXTENDED_GROUP {
+        scale_word(group).to_string()
+    } else if group - FIRST_EXTENDED_GROUP < EXTENDED_POWERS_THOUSAND.len() {
+        EXTENDED_POWERS_THOUSAND[group - FIRST_EXTENDED_GROUP].to_string()
+    } else {
+        format!(
+            " times ten to the power of {}",
+            to_word_u128(3 * group as u128, and_behavior)
+        )
+    }
+}
+
+/// Convert a string of decimal digits of any length to words
+///
+/// Groups up to "vigintillion" (10^63) use dictionary names; larger groups are read as
+/// "times ten to the power of" their exponent.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::bigint::to_word_big;
+/// use numbers_into_words::AndBehavior;
+/// assert_eq!(
+///     to_word_big(&format!("3{}", "0".repeat(63)), AndBehavior::All),
+///     Ok("three vigintillion".to_string())
+/// );
+/// assert_eq!(
+///     to_word_big(&format!("12{}5", "0".repeat(65)), AndBehavior::All),
+///     Ok("twelve times ten to the power of sixty-six, five".to_string())
+/// );
+/// assert_eq!(to_word_big("12x", AndBehavior::All), Err("Invalid digit."));
+/// ```
+pub fn to_word_big(digits: &str, and_behavior: AndBehavior) -> Result<String, &'static str> {
+    if digits.is_empty() {
+        return Err("No digits.");
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("Invalid digit.");
+    }
+    let digits = digits.trim_start_matches('0');
+    if digits.len() < 39 {
+        return Ok(to_word_u128(
+            digits.parse().unwrap_or_default(),
+            and_behavior,
+        ));
+    }
+    // Past this point the value exceeds 999, which is all `AndBehavior` needs to know
+    let full_value = u128::MAX;
+    let groups = digits.len().div_ceil(3);
+    let first_len = digits.len() - 3 * (groups - 1);
+    Ok((0..groups)
+        .filter_map(|k| {
+            let start = if k == 0 { 0 } else { first_len + 3 * (k - 1) };
+            let end = first_len + 3 * k;
+            let value: u64 = digits[start..end].parse().expect("at most three digits");
+            let group = groups - 1 - k;
+            (value != 0).then(|| {
+                format!(
+                    "{}{}",
+                    group_words(value, group, and_behavior, full_value),
+                    big_scale_word(group, and_behavior)
+                )
+            })
+        })
+        .collect::<Vec<String>>()
+        .join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_word_big() {
+        assert_eq!(
+            to_word_big("000", AndBehavior::All),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            to_word_big(&u128::MAX.to_string(), AndBehavior::LastGroup),
+            Ok(to_word_u128(u128::MAX, AndBehavior::LastGroup))
+        );
+        assert_eq!(
+            to_word_big("340282366920938463463374607431768211456", AndBehavior::None),
+            Ok(String::from(
+                "three-hundred forty undecillion, two-hundred eighty-two decillion, \
+                 three-hundred sixty-six nonillion, nine-hundred twenty octillion, \
+                 nine-hundred thirty-eight septillion, four-hundred sixty-three sextillion, \
+                 four-hundred sixty-three quintillion, three-hundred seventy-four \
+                 quadrillion, six-hundred seven trillion, four-hundred thirty-one billion, \
+                 seven-hundred sixty-eight million, two-hundred eleven thousand, \
+                 four-hundred fifty-six"
+            ))
+        );
+        assert_eq!(
+            to_word_big(
+                &format!("1105{}", "0".repeat(39)),
+                AndBehavior::OnlyUnderThousand
+            ),
+            Ok(String::from(
+                "one tredecillion, one-hundred five duodecillion"
+            ))
+        );
+        assert_eq!(
+            to_word_big(&format!("101{}101", "0".repeat(60)), AndBehavior::LastGroup),
+            Ok(String::from(
+                "one-hundred one vigintillion, one-hundred and one"
+            ))
+        );
+        assert_eq!(
+            to_word_big(&format!("7{}", "0".repeat(303)), AndBehavior::All),
+            Ok(String::from(
+                "seven times ten to the power of three-hundred and three"
+            ))
+        );
+        assert_eq!(to_word_big("", AndBehavior::All), Err("No digits."));
+        assert_eq!(
+            to_word_big("1_000", AndBehavior::All),
+            Err("Invalid digit.")
+        );
+    }
+}
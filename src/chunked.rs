@@ -0,0 +1,141 @@
+/* src/chunked.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{group_to_word, nonzero_groups, scale_word, AndBehavior};
+
+/// Signals how the digits of each chunk are rendered
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChunkStyle {
+    /// Keep the digits as numerals ("18 quintillion.")
+    Numerals,
+
+    /// Spell out the digits ("Eighteen quintillion.")
+    Words,
+}
+
+fn sentence(text: String) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}.", first.to_uppercase(), chars.as_str()),
+        None => text,
+    }
+}
+
+/// Breaks a number into one short sentence per group of three digits, so that a screen
+/// reader can read each scale separately
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::AndBehavior;
+/// use numbers_into_words::chunked::{to_chunks, ChunkStyle};
+/// assert_eq!(
+///     to_chunks(18_446_000_000_000_000_615, ChunkStyle::Numerals, AndBehavior::All),
+///     vec!["18 quintillion.", "446 quadrillion.", "615."]
+/// );
+/// assert_eq!(
+///     to_chunks(2_859, ChunkStyle::Words, AndBehavior::All),
+///     vec!["Two thousand.", "Eight-hundred and fifty-nine."]
+/// );
+/// ```
+pub fn to_chunks(x: u64, style: ChunkStyle, and_behavior: AndBehavior) -> Vec<String> {
+    if x == 0 {
+        return vec![sentence(match style {
+            ChunkStyle::Numerals => "0".to_string(),
+            ChunkStyle::Words => super::to_word(0, and_behavior),
+        })];
+    }
+    nonzero_groups(x as u128)
+        .into_iter()
+        .map(|(value, group)| {
+            sentence(match style {
+                ChunkStyle::Numerals => format!("{}{}", value, scale_word(group)),
+                ChunkStyle::Words => group_to_word(value, group, and_behavior, x as u128),
+            })
+        })
+        .collect()
+}
+
+/// Joins the chunks of a number into a single string, with `pause` (if given) placed
+/// between the chunks
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::AndBehavior;
+/// use numbers_into_words::chunked::{chunked_reading, ChunkStyle};
+/// assert_eq!(
+///     chunked_reading(5_000_012, ChunkStyle::Words, AndBehavior::All, None),
+///     "Five million. Twelve.".to_string()
+/// );
+/// assert_eq!(
+///     chunked_reading(5_000_012, ChunkStyle::Numerals, AndBehavior::All, Some(" ... ")),
+///     "5 million. ... 12.".to_string()
+/// );
+/// ```
+pub fn chunked_reading(
+    x: u64,
+    style: ChunkStyle,
+    and_behavior: AndBehavior,
+    pause: Option<&str>,
+) -> String {
+    to_chunks(x, style, and_behavior).join(pause.unwrap_or(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_chunks() {
+        assert_eq!(
+            to_chunks(0, ChunkStyle::Numerals, AndBehavior::All),
+            vec!["0."]
+        );
+        assert_eq!(
+            to_chunks(0, ChunkStyle::Words, AndBehavior::All),
+            vec!["Zero."]
+        );
+        assert_eq!(
+            to_chunks(u64::MAX, ChunkStyle::Numerals, AndBehavior::All),
+            vec![
+                "18 quintillion.",
+                "446 quadrillion.",
+                "744 trillion.",
+                "73 billion.",
+                "709 million.",
+                "551 thousand.",
+                "615."
+            ]
+        );
+        assert_eq!(
+            to_chunks(1_000_105, ChunkStyle::Words, AndBehavior::LastGroup),
+            vec!["One million.", "One-hundred and five."]
+        );
+        assert_eq!(
+            to_chunks(105_000_105, ChunkStyle::Words, AndBehavior::LastGroup),
+            vec!["One-hundred five million.", "One-hundred and five."]
+        );
+    }
+
+    #[test]
+    fn test_chunked_reading() {
+        assert_eq!(
+            chunked_reading(u64::MAX, ChunkStyle::Numerals, AndBehavior::All, Some("\n")),
+            String::from(
+                "18 quintillion.\n446 quadrillion.\n744 trillion.\n73 billion.\n\
+                 709 million.\n551 thousand.\n615."
+            )
+        );
+    }
+}
@@ -0,0 +1,90 @@
+/* src/clipboard.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Clipboard tools tried in order when copying, with their arguments
+const COPY_COMMANDS: [(&str, &[&str]); 5] = [
+    ("pbcopy", &[]),
+    ("wl-copy", &[]),
+    ("xclip", &["-selection", "clipboard"]),
+    ("xsel", &["--clipboard", "--input"]),
+    ("clip.exe", &[]),
+];
+
+/// Clipboard tools tried in order when pasting, with their arguments
+const PASTE_COMMANDS: [(&str, &[&str]); 5] = [
+    ("pbpaste", &[]),
+    ("wl-paste", &["--no-newline"]),
+    ("xclip", &["-selection", "clipboard", "-o"]),
+    ("xsel", &["--clipboard", "--output"]),
+    (
+        "powershell.exe",
+        &["-NoProfile", "-Command", "Get-Clipboard"],
+    ),
+];
+
+/// Copies `text` to the system clipboard using the first clipboard tool that works
+pub fn copy(text: &str) -> Result<(), String> {
+    for (program, args) in COPY_COMMANDS {
+        let Ok(mut child) = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        else {
+            continue;
+        };
+        let written = match child.stdin.take() {
+            Some(mut stdin) => stdin.write_all(text.as_bytes()).is_ok(),
+            None => false,
+        };
+        if child.wait().map(|s| s.success()).unwrap_or(false) && written {
+            return Ok(());
+        }
+    }
+    Err(format!(
+        "Could not copy to the clipboard (tried {})",
+        tool_list(&COPY_COMMANDS)
+    ))
+}
+
+/// Reads the contents of the system clipboard using the first clipboard tool that works
+pub fn paste() -> Result<String, String> {
+    for (program, args) in PASTE_COMMANDS {
+        if let Ok(output) = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+        {
+            if output.status.success() {
+                return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+            }
+        }
+    }
+    Err(format!(
+        "Could not read the clipboard (tried {})",
+        tool_list(&PASTE_COMMANDS)
+    ))
+}
+
+fn tool_list(commands: &[(&str, &[&str])]) -> String {
+    commands
+        .iter()
+        .map(|(program, _)| *program)
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
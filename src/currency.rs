@@ -0,0 +1,914 @@
+/* src/currency.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{
+    to_word_with, uncased_words, AndBehavior, Case, FormatOptions, Hyphenation,
+};
+use super::parse_words::from_words;
+
+/// A currency, with the names of its major and minor units
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Currency {
+    /// US dollars and cents
+    Usd,
+
+    /// British pounds and pence
+    Gbp,
+
+    /// Euros and cents
+    Eur,
+
+    /// Indian rupees and paise
+    Inr,
+
+    /// Japanese yen, which have no minor unit
+    Jpy,
+
+    /// Kuwaiti dinars and fils, with a thousand fils to the dinar
+    Kwd,
+
+    /// Bahraini dinars and fils, with a thousand fils to the dinar
+    Bhd,
+
+    /// Any other currency, given by the names of its units and the number of digits of
+    /// minor units in each major unit
+    Custom {
+        major: &'static str,
+        major_plural: &'static str,
+        minor: &'static str,
+        minor_plural: &'static str,
+        minor_digits: u8,
+    },
+}
+
+impl Currency {
+    /// The name of the major unit, e.g. "dollar" or "dollars"
+    pub fn major_name(&self, plural: bool) -> &'static str {
+        match (self, plural) {
+            (Self::Usd, false) => "dollar",
+            (Self::Usd, true) => "dollars",
+            (Self::Gbp, false) => "pound",
+            (Self::Gbp, true) => "pounds",
+            (Self::Eur, false) => "euro",
+            (Self::Eur, true) => "euros",
+            (Self::Inr, false) => "rupee",
+            (Self::Inr, true) => "rupees",
+            (Self::Jpy, _) => "yen",
+            (Self::Kwd | Self::Bhd, false) => "dinar",
+            (Self::Kwd | Self::Bhd, true) => "dinars",
+            (Self::Custom { major, .. }, false) => major,
+            (Self::Custom { major_plural, .. }, true) => major_plural,
+        }
+    }
+
+    /// The name of the minor unit, e.g. "cent" or "cents"
+    pub fn minor_name(&self, plural: bool) -> &'static str {
+        match (self, plural) {
+            (Self::Usd | Self::Eur, false) => "cent",
+            (Self::Usd | Self::Eur, true) => "cents",
+            (Self::Gbp, false) => "penny",
+            (Self::Gbp, true) => "pence",
+            (Self::Inr, false) => "paisa",
+            (Self::Inr, true) => "paise",
+            (Self::Jpy, _) => "sen",
+            (Self::Kwd | Self::Bhd, _) => "fils",
+            (Self::Custom { minor, .. }, false) => minor,
+            (Self::Custom { minor_plural, .. }, true) => minor_plural,
+        }
+    }
+
+    /// The number of digits of minor units in each major unit, e.g. 2 for the 100 cents
+    /// in a dollar
+    pub fn minor_digits(&self) -> u8 {
+        match self {
+            Self::Jpy => 0,
+            Self::Kwd | Self::Bhd => 3,
+            Self::Custom { minor_digits, .. } => *minor_digits,
+            _ => 2,
+        }
+    }
+
+    /// The symbol written before an amount, e.g. "US$", or the code and a space where the
+    /// currency has no widely used symbol (nothing for custom currencies)
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Usd => "US$",
+            Self::Gbp => "£",
+            Self::Eur => "€",
+            Self::Inr => "₹",
+            Self::Jpy => "¥",
+            Self::Kwd => "KWD ",
+            Self::Bhd => "BHD ",
+            Self::Custom { .. } => "",
+        }
+    }
+
+    /// Looks up a currency by its ISO 4217 code, e.g. "usd"
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_lowercase().as_str() {
+            "usd" => Some(Self::Usd),
+            "gbp" => Some(Self::Gbp),
+            "eur" => Some(Self::Eur),
+            "inr" => Some(Self::Inr),
+            "jpy" => Some(Self::Jpy),
+            "kwd" => Some(Self::Kwd),
+            "bhd" => Some(Self::Bhd),
+            _ => None,
+        }
+    }
+
+    /// Looks up a currency by its symbol, e.g. '$'
+    fn from_symbol(symbol: char) -> Option<Self> {
+        match symbol {
+            '$' => Some(Self::Usd),
+            '£' => Some(Self::Gbp),
+            '€' => Some(Self::Eur),
+            '₹' => Some(Self::Inr),
+            '¥' => Some(Self::Jpy),
+            _ => None,
+        }
+    }
+
+    /// Checks that `minor` is less than one major unit
+    fn check_minor(&self, minor: u16) -> Result<(), &'static str> {
+        if (minor as u64) < 10_u64.saturating_pow(self.minor_digits() as u32) {
+            Ok(())
+        } else {
+            Err("Minor units out of range.")
+        }
+    }
+}
+
+/// Convert an amount of money to words, e.g. "twelve dollars and fifty cents"
+///
+/// The minor units are left out when there are none, and the major units when there are
+/// only minor units.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::currency::{to_currency_words, Currency};
+/// use numbers_into_words::AndBehavior;
+/// assert_eq!(
+///     to_currency_words(123, 45, Currency::Usd, AndBehavior::All),
+///     Ok("one-hundred and twenty-three dollars and forty-five cents".to_string())
+/// );
+/// assert_eq!(
+///     to_currency_words(1, 0, Currency::Usd, AndBehavior::All),
+///     Ok("one dollar".to_string())
+/// );
+/// assert_eq!(
+///     to_currency_words(1, 100, Currency::Usd, AndBehavior::All),
+///     Err("Minor units out of range.")
+/// );
+/// ```
+pub fn to_currency_words(
+    major: u64,
+    minor: u16,
+    currency: Currency,
+    and_behavior: AndBehavior,
+) -> Result<String, &'static str> {
+    to_currency_words_with(
+        major,
+        minor,
+        currency,
+        &FormatOptions::new().and_behavior(and_behavior),
+    )
+}
+
+/// Convert an amount of money to words in the style given by `options`
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::conversion_to_words::{FormatOptions, Scale};
+/// use numbers_into_words::currency::{to_currency_words_with, Currency};
+/// assert_eq!(
+///     to_currency_words_with(
+///         1_234_567,
+///         50,
+///         Currency::Inr,
+///         &FormatOptions::new().scale(Scale::Indian)
+///     ),
+///     Ok(concat!(
+///         "twelve lakh, thirty-four thousand, five-hundred and sixty-seven rupees ",
+///         "and fifty paise"
+///     )
+///     .to_string())
+/// );
+/// ```
+pub fn to_currency_words_with(
+    major: u64,
+    minor: u16,
+    currency: Currency,
+    options: &FormatOptions,
+) -> Result<String, &'static str> {
+    uncased_currency_words(major, minor, currency, options).map(|w| options.apply_case(w))
+}
+
+/// How a negative amount of money is written
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NegativeAmount {
+    /// "negative one-hundred dollars", with the negative word and placement of the format
+    /// options (default behavior)
+    Word,
+
+    /// "(one-hundred dollars)", as in accounting
+    Parentheses,
+
+    /// "one-hundred dollars deficit"
+    Deficit,
+}
+
+/// Convert a negative amount of money (given by the size of its major and minor units) to
+/// words in the style given by `options`
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::conversion_to_words::FormatOptions;
+/// use numbers_into_words::currency::{to_negative_currency_words, Currency, NegativeAmount};
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_negative_currency_words(100, 0, Currency::Usd, NegativeAmount::Word, &options),
+///     Ok("negative one-hundred dollars".to_string())
+/// );
+/// assert_eq!(
+///     to_negative_currency_words(100, 0, Currency::Usd, NegativeAmount::Parentheses, &options),
+///     Ok("(one-hundred dollars)".to_string())
+/// );
+/// assert_eq!(
+///     to_negative_currency_words(100, 0, Currency::Usd, NegativeAmount::Deficit, &options),
+///     Ok("one-hundred dollars deficit".to_string())
+/// );
+/// ```
+pub fn to_negative_currency_words(
+    major: u64,
+    minor: u16,
+    currency: Currency,
+    style: NegativeAmount,
+    options: &FormatOptions,
+) -> Result<String, &'static str> {
+    let words = uncased_currency_words(major, minor, currency, options)?;
+    Ok(match style {
+        NegativeAmount::Word => options.apply_case(options.negate(words)),
+        NegativeAmount::Parentheses => format!("({})", options.apply_case(words)),
+        NegativeAmount::Deficit => options.apply_case(format!("{} deficit", words)),
+    })
+}
+
+/// Words for an amount of money, except for the letter case
+fn uncased_currency_words(
+    major: u64,
+    minor: u16,
+    currency: Currency,
+    options: &FormatOptions,
+) -> Result<String, &'static str> {
+    currency.check_minor(minor)?;
+    let major_words = format!(
+        "{} {}",
+        uncased_words(major as u128, options),
+        currency.major_name(major != 1)
+    );
+    let minor_words = format!(
+        "{} {}",
+        uncased_words(minor as u128, options),
+        currency.minor_name(minor != 1)
+    );
+    Ok(match (major, minor) {
+        (_, 0) => major_words,
+        (0, _) => minor_words,
+        _ => format!("{} and {}", major_words, minor_words),
+    })
+}
+
+/// Reads an amount of money such as "$1,234.56", "¥1500", or "BHD 1.234" into its currency
+/// and its major and minor units
+///
+/// The amount starts with a currency symbol or code, falling back to `default` when it
+/// has neither, and can have no more decimal places than the currency has minor digits.
+/// Commas can only separate the whole units into groups of three digits.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::currency::{parse_amount, Currency};
+/// assert_eq!(parse_amount("$1,234.5", Currency::Eur), Ok((Currency::Usd, 1_234, 50)));
+/// assert_eq!(parse_amount("BHD 1.234", Currency::Usd), Ok((Currency::Bhd, 1, 234)));
+/// assert_eq!(parse_amount("¥1500", Currency::Usd), Ok((Currency::Jpy, 1_500, 0)));
+/// assert_eq!(parse_amount("12", Currency::Gbp), Ok((Currency::Gbp, 12, 0)));
+/// assert_eq!(parse_amount("¥1.5", Currency::Usd), Err("Too many decimal places."));
+/// ```
+pub fn parse_amount(text: &str, default: Currency) -> Result<(Currency, u64, u16), &'static str> {
+    read_amount(text, default, None)
+}
+
+/// How to round an amount with more decimal places than its currency has minor digits
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round halves up, so 4.995 becomes 5.00
+    HalfUp,
+
+    /// Round halves to the nearest even minor unit (banker's rounding), so 4.985 becomes
+    /// 4.98 but 4.995 becomes 5.00
+    HalfEven,
+
+    /// Drop the extra digits, so 4.999 becomes 4.99
+    Truncate,
+}
+
+impl RoundingMode {
+    /// Whether to round up after dropping the (nonempty) digits `dropped`, where `odd`
+    /// tells whether the amount kept ends in an odd minor unit
+    fn rounds_up(&self, dropped: &str, odd: bool) -> bool {
+        let first = dropped.as_bytes()[0];
+        match self {
+            Self::HalfUp => first >= b'5',
+            Self::HalfEven => {
+                first > b'5' || (first == b'5' && (odd || dropped[1..].contains(|c| c != '0')))
+            }
+            Self::Truncate => false,
+        }
+    }
+}
+
+/// Like `parse_amount`, but rounding amounts with too many decimal places instead of
+/// rejecting them
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::currency::{parse_amount_rounded, Currency, RoundingMode};
+/// assert_eq!(
+///     parse_amount_rounded("4.999", Currency::Usd, RoundingMode::HalfUp),
+///     Ok((Currency::Usd, 5, 0))
+/// );
+/// assert_eq!(
+///     parse_amount_rounded("4.985", Currency::Usd, RoundingMode::HalfEven),
+///     Ok((Currency::Usd, 4, 98))
+/// );
+/// assert_eq!(
+///     parse_amount_rounded("4.999", Currency::Usd, RoundingMode::Truncate),
+///     Ok((Currency::Usd, 4, 99))
+/// );
+/// ```
+pub fn parse_amount_rounded(
+    text: &str,
+    default: Currency,
+    rounding: RoundingMode,
+) -> Result<(Currency, u64, u16), &'static str> {
+    read_amount(text, default, Some(rounding))
+}
+
+/// Splits a currency symbol or code off the start of `text`
+pub(crate) fn split_currency(text: &str) -> Option<(Currency, &str)> {
+    let mut chars = text.chars();
+    match chars.next().and_then(Currency::from_symbol) {
+        Some(currency) => Some((currency, chars.as_str())),
+        None => text
+            .get(..3)
+            .and_then(Currency::from_code)
+            .map(|currency| (currency, &text[3..])),
+    }
+}
+
+/// Whether the commas in `whole`, if any, each come before a group of three digits, as in
+/// "1,234,567"
+fn grouped_by_thousands(whole: &str) -> bool {
+    let mut groups = whole.split(',');
+    let first = groups.next().unwrap_or("");
+    !whole.contains(',') || (!first.is_empty() && first.len() <= 3 && groups.all(|g| g.len() == 3))
+}
+
+/// Reads an amount of money, rounding extra decimal places if a rounding mode is given
+fn read_amount(
+    text: &str,
+    default: Currency,
+    rounding: Option<RoundingMode>,
+) -> Result<(Currency, u64, u16), &'static str> {
+    let text = text.trim();
+    let (currency, number) = split_currency(text).unwrap_or((default, text));
+    let number = number.trim().replace('_', "");
+    let (whole, fraction) = number.split_once('.').unwrap_or((&number, ""));
+    if !grouped_by_thousands(whole) {
+        return Err("Invalid amount.");
+    }
+    let whole = whole.replace(',', "");
+    let all_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !all_digits(&whole) || !all_digits(fraction) {
+        return Err("Invalid amount.");
+    }
+    let digits = currency.minor_digits() as usize;
+    let (kept, dropped) = fraction.split_at(fraction.len().min(digits));
+    let per_major = 10_u128
+        .checked_pow(digits as u32)
+        .ok_or("Minor units out of range.")?;
+    let kept = match digits {
+        0 => 0,
+        _ => format!("{:0<digits$}", kept, digits = digits)
+            .parse::<u128>()
+            .map_err(|_| "Minor units out of range.")?,
+    };
+    let mut total = match whole.as_str() {
+        "" => Some(0),
+        _ => whole.parse::<u128>().ok(),
+    }
+    .and_then(|x| x.checked_mul(per_major))
+    .and_then(|x| x.checked_add(kept))
+    .ok_or("Value too big.")?;
+    if !dropped.is_empty() {
+        match rounding {
+            None => return Err("Too many decimal places."),
+            Some(mode) if mode.rounds_up(dropped, total % 2 == 1) => total += 1,
+            Some(_) => {}
+        }
+    }
+    let major = u64::try_from(total / per_major).map_err(|_| "Value too big.")?;
+    let minor = u16::try_from(total % per_major).map_err(|_| "Minor units out of range.")?;
+    Ok((currency, major, minor))
+}
+
+/// The currencies with names, in the order `parse_currency_words` tries them
+const NAMED_CURRENCIES: [Currency; 7] = [
+    Currency::Usd,
+    Currency::Gbp,
+    Currency::Eur,
+    Currency::Inr,
+    Currency::Jpy,
+    Currency::Kwd,
+    Currency::Bhd,
+];
+
+/// Parse an amount of money written in words, such as "one-hundred and twenty dollars and
+/// five cents", back into its major and minor units and currency
+///
+/// The cheque and legal styles are also read, and letter case is ignored. Where units are
+/// shared, as with the cents of dollars and euros, the first currency listed in `Currency`
+/// is chosen.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::currency::{parse_currency_words, Currency};
+/// assert_eq!(
+///     parse_currency_words("one-hundred and twenty dollars and five cents"),
+///     Ok((120, 5, Currency::Usd))
+/// );
+/// assert_eq!(
+///     parse_currency_words("One Hundred Twenty-Three and 45/100 Dollars Only"),
+///     Ok((123, 45, Currency::Usd))
+/// );
+/// assert_eq!(parse_currency_words("seventy-five paise"), Ok((0, 75, Currency::Inr)));
+/// assert_eq!(parse_currency_words("twelve"), Err("No currency units found."));
+/// ```
+pub fn parse_currency_words(text: &str) -> Result<(u64, u16, Currency), &'static str> {
+    let mut text = text.trim().to_lowercase();
+    if text.ends_with(')') {
+        if let Some((words, _)) = text.rsplit_once(" (") {
+            text = words.to_string();
+        }
+    }
+    let text = text.strip_suffix(" only").unwrap_or(&text);
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let read = |words: &str| from_words(words).map_err(|e| e.kind.message());
+    let minor_units = |words: &[&str]| -> Result<u16, &'static str> {
+        u16::try_from(read(&words.join(" "))?).map_err(|_| "Minor units out of range.")
+    };
+    for currency in NAMED_CURRENCIES {
+        let names = [currency.major_name(false), currency.major_name(true)];
+        let Some(k) = words.iter().position(|w| names.contains(w)) else {
+            continue;
+        };
+        let before = words[..k].join(" ");
+        let minor_names = [currency.minor_name(false), currency.minor_name(true)];
+        let (major, minor) = match (before.rsplit_once(" and "), &words[k + 1..]) {
+            (Some((whole, fraction)), []) if fraction.contains('/') => {
+                (read(whole)?, cheque_fraction(fraction, currency)?)
+            }
+            (_, []) => (read(&before)?, 0),
+            (_, ["and", rest @ .., unit]) if minor_names.contains(unit) => {
+                (read(&before)?, minor_units(rest)?)
+            }
+            _ => return Err("Unexpected words after the amount."),
+        };
+        currency.check_minor(minor)?;
+        return Ok((major, minor, currency));
+    }
+    for currency in NAMED_CURRENCIES {
+        let minor_names = [currency.minor_name(false), currency.minor_name(true)];
+        if let Some((unit, rest)) = words.split_last() {
+            if minor_names.contains(unit) {
+                let minor = minor_units(rest)?;
+                currency.check_minor(minor)?;
+                return Ok((0, minor, currency));
+            }
+        }
+    }
+    Err("No currency units found.")
+}
+
+/// Reads the minor units of a cheque, such as "45/100"
+fn cheque_fraction(fraction: &str, currency: Currency) -> Result<u16, &'static str> {
+    let digits = currency.minor_digits() as usize;
+    match fraction.split_once('/') {
+        Some((minor, per_major))
+            if minor.len() == digits
+                && minor.chars().all(|c| c.is_ascii_digit())
+                && per_major.parse::<u64>().ok() == 10_u64.checked_pow(digits as u32) =>
+        {
+            minor
+                .parse::<u16>()
+                .map_err(|_| "Minor units out of range.")
+        }
+        _ => Err("Invalid fraction."),
+    }
+}
+
+/// Convert an amount of money to words in the style written on a cheque, e.g. "One Hundred
+/// Twenty-Three and 45/100 Dollars"
+///
+/// The minor units are always written as a fraction of a major unit (e.g. "45/100"), unless
+/// the currency has none, and `only` appends a trailing "Only" to guard against additions.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::currency::{to_cheque_words, Currency};
+/// assert_eq!(
+///     to_cheque_words(123, 45, Currency::Usd, false),
+///     Ok("One Hundred Twenty-Three and 45/100 Dollars".to_string())
+/// );
+/// assert_eq!(
+///     to_cheque_words(1_500, 0, Currency::Usd, true),
+///     Ok("One Thousand Five Hundred and 00/100 Dollars Only".to_string())
+/// );
+/// assert_eq!(
+///     to_cheque_words(1_500, 0, Currency::Jpy, false),
+///     Ok("One Thousand Five Hundred Yen".to_string())
+/// );
+/// ```
+pub fn to_cheque_words(
+    major: u64,
+    minor: u16,
+    currency: Currency,
+    only: bool,
+) -> Result<String, &'static str> {
+    currency.check_minor(minor)?;
+    let mut words = to_word_with(major as u128, &formal_options());
+    let digits = currency.minor_digits() as usize;
+    if digits > 0 {
+        words.push_str(&format!(
+            " and {:0digits$}/{}",
+            minor,
+            10_u64.saturating_pow(digits as u32),
+            digits = digits
+        ));
+    }
+    words.push(' ');
+    words.push_str(&capitalize(currency.major_name(true)));
+    if only {
+        words.push_str(" Only");
+    }
+    Ok(words)
+}
+
+/// Convert an amount of money to words in the style of a legal contract, restating the
+/// amount in numerals, e.g. "One Thousand Two Hundred Thirty-Four Dollars and Fifty-Six
+/// Cents (US$1,234.56)"
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::currency::{to_legal_words, Currency};
+/// assert_eq!(
+///     to_legal_words(1_234, 56, Currency::Usd),
+///     Ok("One Thousand Two Hundred Thirty-Four Dollars and Fifty-Six Cents (US$1,234.56)"
+///         .to_string())
+/// );
+/// assert_eq!(
+///     to_legal_words(2_000_000, 0, Currency::Jpy),
+///     Ok("Two Million Yen (¥2,000,000)".to_string())
+/// );
+/// ```
+pub fn to_legal_words(major: u64, minor: u16, currency: Currency) -> Result<String, &'static str> {
+    let words = to_currency_words_with(major, minor, currency, &formal_options())?;
+    Ok(format!(
+        "{} ({})",
+        words,
+        amount_numeral(major, minor, currency)
+    ))
+}
+
+/// The style of the words on cheques and contracts: "One Thousand Two Hundred
+/// Thirty-Four"
+fn formal_options() -> FormatOptions {
+    FormatOptions::new()
+        .and_behavior(AndBehavior::None)
+        .hyphenation(Hyphenation::TensOnly)
+        .group_separator(" ")
+        .case(Case::Title)
+}
+
+/// Writes an amount with its currency symbol and a comma every three digits, e.g.
+/// "US$1,234.56"
+fn amount_numeral(major: u64, minor: u16, currency: Currency) -> String {
+    let digits = major.to_string();
+    let mut numeral = String::from(currency.symbol());
+    for (idx, c) in digits.chars().enumerate() {
+        if idx != 0 && (digits.len() - idx) % 3 == 0 {
+            numeral.push(',');
+        }
+        numeral.push(c);
+    }
+    match currency.minor_digits() as usize {
+        0 => numeral,
+        d => format!("{}.{:0d$}", numeral, minor, d = d),
+    }
+}
+
+/// Uppercases the first letter of a unit name
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currency_words() {
+        assert_eq!(
+            to_currency_words(0, 0, Currency::Usd, AndBehavior::All),
+            Ok(String::from("zero dollars"))
+        );
+        assert_eq!(
+            to_currency_words(0, 1, Currency::Usd, AndBehavior::All),
+            Ok(String::from("one cent"))
+        );
+        assert_eq!(
+            to_currency_words(1, 1, Currency::Usd, AndBehavior::All),
+            Ok(String::from("one dollar and one cent"))
+        );
+        assert_eq!(
+            to_currency_words(2_500, 0, Currency::Usd, AndBehavior::None),
+            Ok(String::from("two thousand, five-hundred dollars"))
+        );
+        assert_eq!(
+            to_currency_words(105, 99, Currency::Usd, AndBehavior::OnlyUnderThousand),
+            Ok(String::from(
+                "one-hundred and five dollars and ninety-nine cents"
+            ))
+        );
+        assert_eq!(
+            to_currency_words(5, 255, Currency::Usd, AndBehavior::All),
+            Err("Minor units out of range.")
+        );
+    }
+
+    #[test]
+    fn test_currencies() {
+        assert_eq!(
+            to_currency_words(3, 1, Currency::Gbp, AndBehavior::All),
+            Ok(String::from("three pounds and one penny"))
+        );
+        assert_eq!(
+            to_currency_words(1, 50, Currency::Eur, AndBehavior::All),
+            Ok(String::from("one euro and fifty cents"))
+        );
+        assert_eq!(
+            to_currency_words(0, 75, Currency::Inr, AndBehavior::All),
+            Ok(String::from("seventy-five paise"))
+        );
+        assert_eq!(
+            to_currency_words(1_500, 0, Currency::Jpy, AndBehavior::All),
+            Ok(String::from("one thousand, five-hundred yen"))
+        );
+        assert_eq!(
+            to_currency_words(1_500, 1, Currency::Jpy, AndBehavior::All),
+            Err("Minor units out of range.")
+        );
+        let rand = Currency::Custom {
+            major: "rand",
+            major_plural: "rand",
+            minor: "cent",
+            minor_plural: "cents",
+            minor_digits: 2,
+        };
+        assert_eq!(
+            to_currency_words(20, 5, rand, AndBehavior::All),
+            Ok(String::from("twenty rand and five cents"))
+        );
+        assert_eq!(
+            to_cheque_words(20, 5, rand, false),
+            Ok(String::from("Twenty and 05/100 Rand"))
+        );
+    }
+
+    #[test]
+    fn test_minor_digits() {
+        assert_eq!(
+            to_currency_words(1, 234, Currency::Bhd, AndBehavior::All),
+            Ok(String::from(
+                "one dinar and two-hundred and thirty-four fils"
+            ))
+        );
+        assert_eq!(
+            to_currency_words(0, 1, Currency::Kwd, AndBehavior::All),
+            Ok(String::from("one fils"))
+        );
+        assert_eq!(
+            to_currency_words(0, 1_000, Currency::Kwd, AndBehavior::All),
+            Err("Minor units out of range.")
+        );
+        assert_eq!(
+            to_cheque_words(7, 5, Currency::Kwd, false),
+            Ok(String::from("Seven and 005/1000 Dinars"))
+        );
+        assert_eq!(
+            parse_amount("kwd0.05", Currency::Usd),
+            Ok((Currency::Kwd, 0, 50))
+        );
+        assert_eq!(
+            parse_amount("€.5", Currency::Usd),
+            Ok((Currency::Eur, 0, 50))
+        );
+        assert_eq!(parse_amount("$", Currency::Usd), Err("Invalid amount."));
+        assert_eq!(
+            parse_amount("$1.2.3", Currency::Usd),
+            Err("Invalid amount.")
+        );
+        assert_eq!(
+            parse_amount("$1,234,567.89", Currency::Usd),
+            Ok((Currency::Usd, 1_234_567, 89))
+        );
+        for text in ["$1,2,3.4", "$1234,567", "$,123", "$123,", "$1,234.5,6"] {
+            assert_eq!(parse_amount(text, Currency::Usd), Err("Invalid amount."));
+        }
+        assert_eq!(
+            parse_amount("1.2345", Currency::Bhd),
+            Err("Too many decimal places.")
+        );
+    }
+
+    #[test]
+    fn test_negative_currency_words() {
+        use crate::conversion_to_words::NegativePlacement;
+        let options = FormatOptions::new()
+            .negative_word("in debt")
+            .negative_placement(NegativePlacement::After)
+            .case(Case::Sentence);
+        assert_eq!(
+            to_negative_currency_words(0, 50, Currency::Eur, NegativeAmount::Word, &options),
+            Ok(String::from("Fifty cents in debt"))
+        );
+        assert_eq!(
+            to_negative_currency_words(3, 1, Currency::Gbp, NegativeAmount::Parentheses, &options),
+            Ok(String::from("(Three pounds and one penny)"))
+        );
+        assert_eq!(
+            to_negative_currency_words(1, 0, Currency::Jpy, NegativeAmount::Deficit, &options),
+            Ok(String::from("One yen deficit"))
+        );
+        assert_eq!(
+            to_negative_currency_words(1, 1, Currency::Jpy, NegativeAmount::Deficit, &options),
+            Err("Minor units out of range.")
+        );
+    }
+
+    #[test]
+    fn test_parse_currency_words() {
+        for (major, minor, currency) in [
+            (0, 1, Currency::Usd),
+            (1, 0, Currency::Gbp),
+            (3, 1, Currency::Gbp),
+            (1_234, 56, Currency::Eur),
+            (1_500, 0, Currency::Jpy),
+            (7, 5, Currency::Kwd),
+            (1_000_000, 99, Currency::Inr),
+        ] {
+            let round_trip = |text: String| parse_currency_words(&text);
+            assert_eq!(
+                round_trip(to_currency_words(major, minor, currency, AndBehavior::All).unwrap()),
+                Ok((major, minor, currency))
+            );
+            assert_eq!(
+                round_trip(to_cheque_words(major, minor, currency, true).unwrap()),
+                Ok((major, minor, currency))
+            );
+            assert_eq!(
+                round_trip(to_legal_words(major, minor, currency).unwrap()),
+                Ok((major, minor, currency))
+            );
+        }
+        assert_eq!(
+            parse_currency_words("five and 5/100 dollars"),
+            Err("Invalid fraction.")
+        );
+        assert_eq!(
+            parse_currency_words("five dollars and six"),
+            Err("Unexpected words after the amount.")
+        );
+        assert_eq!(
+            parse_currency_words("five yen and six sen"),
+            Err("Minor units out of range.")
+        );
+        assert_eq!(parse_currency_words("fivty dollars"), Err("Unknown word."));
+    }
+
+    #[test]
+    fn test_legal_words() {
+        assert_eq!(
+            to_legal_words(0, 5, Currency::Eur),
+            Ok(String::from("Five Cents (€0.05)"))
+        );
+        assert_eq!(
+            to_legal_words(100_000, 0, Currency::Gbp),
+            Ok(String::from("One Hundred Thousand Pounds (£100,000.00)"))
+        );
+        assert_eq!(
+            to_legal_words(1, 1, Currency::Bhd),
+            Ok(String::from("One Dinar and One Fils (BHD 1.001)"))
+        );
+        assert_eq!(
+            to_legal_words(1, 100, Currency::Usd),
+            Err("Minor units out of range.")
+        );
+    }
+
+    #[test]
+    fn test_rounding() {
+        let round = |text, mode| parse_amount_rounded(text, Currency::Usd, mode);
+        assert_eq!(
+            round("0.125", RoundingMode::HalfUp),
+            Ok((Currency::Usd, 0, 13))
+        );
+        assert_eq!(
+            round("0.125", RoundingMode::HalfEven),
+            Ok((Currency::Usd, 0, 12))
+        );
+        assert_eq!(
+            round("0.1251", RoundingMode::HalfEven),
+            Ok((Currency::Usd, 0, 13))
+        );
+        assert_eq!(
+            round("0.135", RoundingMode::HalfEven),
+            Ok((Currency::Usd, 0, 14))
+        );
+        assert_eq!(
+            round("0.124", RoundingMode::HalfUp),
+            Ok((Currency::Usd, 0, 12))
+        );
+        assert_eq!(
+            round("9.999", RoundingMode::Truncate),
+            Ok((Currency::Usd, 9, 99))
+        );
+        assert_eq!(
+            round("¥2.5", RoundingMode::HalfEven),
+            Ok((Currency::Jpy, 2, 0))
+        );
+        assert_eq!(
+            round("¥2.5", RoundingMode::HalfUp),
+            Ok((Currency::Jpy, 3, 0))
+        );
+        assert_eq!(
+            round("18446744073709551615.995", RoundingMode::HalfUp),
+            Err("Value too big.")
+        );
+    }
+
+    #[test]
+    fn test_cheque_words() {
+        assert_eq!(
+            to_cheque_words(0, 7, Currency::Usd, false),
+            Ok(String::from("Zero and 07/100 Dollars"))
+        );
+        assert_eq!(
+            to_cheque_words(1_234, 56, Currency::Usd, true),
+            Ok(String::from(
+                "One Thousand Two Hundred Thirty-Four and 56/100 Dollars Only"
+            ))
+        );
+        assert_eq!(
+            to_cheque_words(1_000_001, 0, Currency::Usd, false),
+            Ok(String::from("One Million One and 00/100 Dollars"))
+        );
+        assert_eq!(
+            to_cheque_words(1, 100, Currency::Usd, false),
+            Err("Minor units out of range.")
+        );
+    }
+}
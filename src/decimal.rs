@@ -0,0 +1,204 @@
+/* src/decimal.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::AndBehavior;
+use super::digits::DigitAlphabet;
+use super::to_word_u128;
+use std::fmt;
+
+/// A decimal number, kept exactly as written so that trailing zeros are read aloud
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Decimal {
+    /// Whether the number is below zero
+    pub negative: bool,
+
+    /// The part before the decimal point
+    pub whole: u128,
+
+    /// The digits after the decimal point
+    pub fraction: String,
+}
+
+impl Decimal {
+    /// Parses text like "3.1415", "-0.5", or ".25", ignoring `_` and `,` separators
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::decimal::Decimal;
+    /// assert_eq!(
+    ///     Decimal::parse("-1_234.50"),
+    ///     Ok(Decimal {
+    ///         negative: true,
+    ///         whole: 1234,
+    ///         fraction: "50".to_string()
+    ///     })
+    /// );
+    /// assert_eq!(Decimal::parse("1.2.3"), Err("Invalid digit."));
+    /// ```
+    pub fn parse(text: &str) -> Result<Self, &'static str> {
+        let cleaned = text.trim().replace(['_', ','], "");
+        let (negative, magnitude) = match cleaned.strip_prefix('-') {
+            Some(m) => (true, m),
+            None => (false, cleaned.as_str()),
+        };
+        let (whole, fraction) = magnitude.split_once('.').unwrap_or((magnitude, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return Err("No digits.");
+        }
+        if !whole
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+        {
+            return Err("Invalid digit.");
+        }
+        let whole = match whole {
+            "" => 0,
+            w => w.parse::<u128>().map_err(|_| "Value too big.")?,
+        };
+        Ok(Self {
+            negative: negative && (whole != 0 || fraction.bytes().any(|b| b != b'0')),
+            whole,
+            fraction: fraction.to_string(),
+        })
+    }
+
+    /// Returns the number as words, reading the digits after the point one at a time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::decimal::Decimal;
+    /// use numbers_into_words::AndBehavior;
+    /// assert_eq!(
+    ///     Decimal::parse("-1_234.50").unwrap().to_words(AndBehavior::None),
+    ///     "negative one thousand, two-hundred thirty-four point five zero".to_string()
+    /// );
+    /// ```
+    pub fn to_words(&self, and_behavior: AndBehavior) -> String {
+        let mut words = to_word_u128(self.whole, and_behavior);
+        if !self.fraction.is_empty() {
+            words.push_str(" point");
+            for word in self
+                .fraction
+                .chars()
+                .filter_map(|c| DigitAlphabet::Plain.word(c))
+            {
+                words.push(' ');
+                words.push_str(word);
+            }
+        }
+        if self.negative {
+            format!("negative {}", words)
+        } else {
+            words
+        }
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}{}{}{}",
+            if self.negative { "-" } else { "" },
+            self.whole,
+            if self.fraction.is_empty() { "" } else { "." },
+            self.fraction
+        )
+    }
+}
+
+/// Convert a floating-point number to words, using its shortest exact decimal form
+///
+/// Returns an error for finite values of 2^128 or more.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::decimal::to_word_decimal;
+/// use numbers_into_words::AndBehavior;
+/// assert_eq!(
+///     to_word_decimal(3.1415, AndBehavior::All),
+///     Ok("three point one four one five".to_string())
+/// );
+/// assert_eq!(
+///     to_word_decimal(-0.25, AndBehavior::All),
+///     Ok("negative zero point two five".to_string())
+/// );
+/// assert_eq!(
+///     to_word_decimal(f64::NEG_INFINITY, AndBehavior::All),
+///     Ok("negative infinity".to_string())
+/// );
+/// ```
+pub fn to_word_decimal(x: f64, and_behavior: AndBehavior) -> Result<String, &'static str> {
+    if x.is_nan() {
+        Ok("not a number".to_string())
+    } else if x.is_infinite() {
+        Ok(if x < 0.0 {
+            "negative infinity"
+        } else {
+            "infinity"
+        }
+        .to_string())
+    } else {
+        Decimal::parse(&x.to_string()).map(|d| d.to_words(and_behavior))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal() {
+        assert_eq!(
+            to_word_decimal(250.0, AndBehavior::All),
+            Ok(String::from("two-hundred and fifty"))
+        );
+        assert_eq!(
+            to_word_decimal(-0.0, AndBehavior::All),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            to_word_decimal(f64::NAN, AndBehavior::All),
+            Ok(String::from("not a number"))
+        );
+        assert_eq!(
+            to_word_decimal(f64::INFINITY, AndBehavior::All),
+            Ok(String::from("infinity"))
+        );
+        assert_eq!(
+            to_word_decimal(1e40, AndBehavior::All),
+            Err("Value too big.")
+        );
+        assert_eq!(
+            Decimal::parse(".05").map(|d| d.to_words(AndBehavior::All)),
+            Ok(String::from("zero point zero five"))
+        );
+        assert_eq!(
+            Decimal::parse("7.").map(|d| d.to_words(AndBehavior::All)),
+            Ok(String::from("seven"))
+        );
+        assert_eq!(
+            Decimal::parse("-.").map(|d| d.to_string()),
+            Err("No digits.")
+        );
+        assert_eq!(
+            Decimal::parse("-0.00").map(|d| d.to_string()),
+            Ok(String::from("0.00"))
+        );
+        assert_eq!(Decimal::parse("1e5"), Err("Invalid digit."));
+    }
+}
@@ -0,0 +1,160 @@
+/* src/digits.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+const PLAIN: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const NATO: [&str; 10] = [
+    "Zero", "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine",
+];
+
+const ICAO: [&str; 10] = [
+    "zero", "wun", "too", "tree", "fower", "fife", "six", "seven", "ait", "niner",
+];
+
+/// Signals which word is read for each digit when a number is spelled one digit at a time
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DigitAlphabet {
+    /// "four two seven" (default behavior)
+    Plain,
+
+    /// The ICAO/NATO digits, capitalized as in a read-back ("Four Two Seven"), using
+    /// "Fife" and "Niner" when `niner` is set
+    Nato { niner: bool },
+
+    /// The ICAO spellings of how the digits are pronounced over the radio ("fower too
+    /// seven")
+    Icao,
+}
+
+impl DigitAlphabet {
+    /// The word for `digit`, or `None` if it isn't an ASCII digit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::digits::DigitAlphabet;
+    /// assert_eq!(DigitAlphabet::Nato { niner: true }.word('9'), Some("Niner"));
+    /// assert_eq!(DigitAlphabet::Nato { niner: false }.word('9'), Some("Nine"));
+    /// assert_eq!(DigitAlphabet::Plain.word('x'), None);
+    /// ```
+    pub fn word(&self, digit: char) -> Option<&'static str> {
+        let d = digit.to_digit(10)? as usize;
+        Some(match self {
+            Self::Plain => PLAIN[d],
+            Self::Nato { niner: true } if d == 5 => "Fife",
+            Self::Nato { niner: true } if d == 9 => "Niner",
+            Self::Nato { .. } => NATO[d],
+            Self::Icao => ICAO[d],
+        })
+    }
+}
+
+/// Spells out a string of digits one at a time, keeping any leading zeros, for reading
+/// back phone numbers, codes, and the like
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::digits::{spell_digits, DigitAlphabet};
+/// assert_eq!(
+///     spell_digits("0427", DigitAlphabet::Plain),
+///     Ok("zero four two seven".to_string())
+/// );
+/// assert_eq!(
+///     spell_digits("427", DigitAlphabet::Icao),
+///     Ok("fower too seven".to_string())
+/// );
+/// assert_eq!(spell_digits("4-2", DigitAlphabet::Plain), Err("Invalid digit."));
+/// ```
+pub fn spell_digits(text: &str, alphabet: DigitAlphabet) -> Result<String, &'static str> {
+    spell_digits_with(text, alphabet, PLAIN[0])
+}
+
+/// Like `spell_digits`, but with `zero_word` (e.g. "oh") for 0 in the `Plain` alphabet;
+/// the radio alphabets keep their own word
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::digits::{spell_digits_with, DigitAlphabet};
+/// assert_eq!(
+///     spell_digits_with("105", DigitAlphabet::Plain, "oh"),
+///     Ok("one oh five".to_string())
+/// );
+/// assert_eq!(
+///     spell_digits_with("105", DigitAlphabet::Icao, "oh"),
+///     Ok("wun zero fife".to_string())
+/// );
+/// ```
+pub fn spell_digits_with(
+    text: &str,
+    alphabet: DigitAlphabet,
+    zero_word: &str,
+) -> Result<String, &'static str> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("No digits.");
+    }
+    Ok(text
+        .chars()
+        .map(|c| match (alphabet, c) {
+            (DigitAlphabet::Plain, '0') => Ok(zero_word),
+            _ => alphabet.word(c).ok_or("Invalid digit."),
+        })
+        .collect::<Result<Vec<&str>, &'static str>>()?
+        .join(" "))
+}
+
+/// Spells out a number one digit at a time
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::digits::{spell_number, DigitAlphabet};
+/// assert_eq!(
+///     spell_number(1959, DigitAlphabet::Nato { niner: true }),
+///     "One Niner Fife Niner".to_string()
+/// );
+/// ```
+pub fn spell_number(x: u64, alphabet: DigitAlphabet) -> String {
+    x.to_string()
+        .chars()
+        .filter_map(|c| alphabet.word(c))
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spell_digits() {
+        assert_eq!(
+            spell_digits(" 0123456789 ", DigitAlphabet::Icao),
+            Ok("zero wun too tree fower fife six seven ait niner".to_string())
+        );
+        assert_eq!(
+            spell_digits("0123456789", DigitAlphabet::Nato { niner: true }),
+            Ok("Zero One Two Three Four Fife Six Seven Eight Niner".to_string())
+        );
+        assert_eq!(spell_digits("", DigitAlphabet::Plain), Err("No digits."));
+        assert_eq!(
+            spell_digits("٤٢", DigitAlphabet::Plain),
+            Err("Invalid digit.")
+        );
+        assert_eq!(spell_number(0, DigitAlphabet::Plain), "zero".to_string());
+    }
+}
@@ -0,0 +1,232 @@
+/* src/dotted.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::AndBehavior;
+use super::digits::DigitAlphabet;
+use super::to_word;
+
+/// The word read aloud between the components of a dotted identifier
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Separator {
+    /// "point", as in version numbers ("one point two point three")
+    Point,
+
+    /// "dot", as in IP addresses ("one ninety-two dot one sixty-eight")
+    Dot,
+}
+
+impl Separator {
+    fn word(&self) -> &'static str {
+        match self {
+            Self::Point => " point ",
+            Self::Dot => " dot ",
+        }
+    }
+}
+
+/// Signals how each component of a dotted identifier is read
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ComponentReading {
+    /// Read each component as a whole number (e.g. "192" as "one-hundred ninety-two").
+    Cardinal,
+
+    /// Read each component in pairs of digits, the way the numbers are usually spoken
+    /// (e.g. "192" as "one ninety-two", "105" as "one oh five", "1234" as "twelve
+    /// thirty-four").
+    Paired,
+
+    /// Read each component one digit at a time (e.g. "192" as "one nine two").
+    DigitByDigit,
+}
+
+impl ComponentReading {
+    fn read(&self, component: &str) -> Result<String, &'static str> {
+        if component.is_empty() || !component.chars().all(|c| c.is_ascii_digit()) {
+            return Err("Invalid component.");
+        }
+        match self {
+            Self::DigitByDigit => Ok(digit_by_digit(component)),
+            Self::Cardinal => component
+                .parse::<u64>()
+                .map(|x| to_word(x, AndBehavior::None))
+                .map_err(|_| "Component too large."),
+            Self::Paired if component.len() > 1 && component.starts_with('0') => {
+                Ok(digit_by_digit(component))
+            }
+            Self::Paired => component
+                .parse::<u64>()
+                .map(paired)
+                .map_err(|_| "Component too large."),
+        }
+    }
+}
+
+fn digit_by_digit(digits: &str) -> String {
+    digits
+        .chars()
+        .filter_map(|c| DigitAlphabet::Plain.word(c))
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+fn paired(x: u64) -> String {
+    let (high, low) = match x {
+        100..=999 => (x / 100, x % 100),
+        1000..=9999 if x % 1000 != 0 => (x / 100, x % 100),
+        _ => return to_word(x, AndBehavior::None),
+    };
+    match low {
+        0 => format!("{} hundred", to_word(high, AndBehavior::None)),
+        1..=9 => format!(
+            "{} oh {}",
+            to_word(high, AndBehavior::None),
+            to_word(low, AndBehavior::None)
+        ),
+        _ => format!(
+            "{} {}",
+            to_word(high, AndBehavior::None),
+            to_word(low, AndBehavior::None)
+        ),
+    }
+}
+
+/// Reads a dotted identifier (version number, IP address, ...) component by component
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::dotted::{dotted_to_words, ComponentReading, Separator};
+/// assert_eq!(
+///     dotted_to_words("10.0.0.255", Separator::Dot, ComponentReading::Cardinal),
+///     Ok("ten dot zero dot zero dot two-hundred fifty-five".to_string())
+/// );
+/// assert_eq!(
+///     dotted_to_words("3.11.4", Separator::Point, ComponentReading::DigitByDigit),
+///     Ok("three point one one point four".to_string())
+/// );
+/// assert!(dotted_to_words("1..2", Separator::Point, ComponentReading::Cardinal).is_err());
+/// ```
+pub fn dotted_to_words(
+    text: &str,
+    separator: Separator,
+    reading: ComponentReading,
+) -> Result<String, &'static str> {
+    Ok(text
+        .trim()
+        .split('.')
+        .map(|c| reading.read(c))
+        .collect::<Result<Vec<String>, &'static str>>()?
+        .join(separator.word()))
+}
+
+/// Reads a version number the way it is usually announced
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::dotted::version_to_words;
+/// assert_eq!(
+///     version_to_words("1.2.3"),
+///     Ok("one point two point three".to_string())
+/// );
+/// assert_eq!(
+///     version_to_words("0.1.12"),
+///     Ok("zero point one point twelve".to_string())
+/// );
+/// ```
+pub fn version_to_words(text: &str) -> Result<String, &'static str> {
+    dotted_to_words(text, Separator::Point, ComponentReading::Cardinal)
+}
+
+/// Reads an IPv4 address the way it is usually spoken
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::dotted::ip_to_words;
+/// assert_eq!(
+///     ip_to_words("192.168.0.1"),
+///     Ok("one ninety-two dot one sixty-eight dot zero dot one".to_string())
+/// );
+/// assert!(ip_to_words("192.168.0.256").is_err());
+/// assert!(ip_to_words("192.168.0").is_err());
+/// ```
+pub fn ip_to_words(text: &str) -> Result<String, &'static str> {
+    let octets: Vec<&str> = text.trim().split('.').collect();
+    if octets.len() != 4 {
+        return Err("An IPv4 address has four components.");
+    }
+    if octets
+        .iter()
+        .any(|c| c.len() > 3 || c.parse::<u16>().map_or(true, |x| x > 255))
+    {
+        return Err("Invalid component.");
+    }
+    dotted_to_words(text, Separator::Dot, ComponentReading::Paired)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paired() {
+        assert_eq!(paired(0), String::from("zero"));
+        assert_eq!(paired(42), String::from("forty-two"));
+        assert_eq!(paired(100), String::from("one hundred"));
+        assert_eq!(paired(105), String::from("one oh five"));
+        assert_eq!(paired(192), String::from("one ninety-two"));
+        assert_eq!(paired(1234), String::from("twelve thirty-four"));
+        assert_eq!(paired(2000), String::from("two thousand"));
+        assert_eq!(
+            paired(12345),
+            String::from("twelve thousand, three-hundred forty-five")
+        );
+    }
+
+    #[test]
+    fn test_component_reading() {
+        assert_eq!(
+            ComponentReading::Paired.read("007"),
+            Ok(String::from("zero zero seven"))
+        );
+        assert_eq!(
+            ComponentReading::Cardinal.read("007"),
+            Ok(String::from("seven"))
+        );
+        assert_eq!(
+            ComponentReading::DigitByDigit.read("90"),
+            Ok(String::from("nine zero"))
+        );
+        assert!(ComponentReading::Cardinal.read("").is_err());
+        assert!(ComponentReading::Cardinal.read("1a").is_err());
+        assert!(ComponentReading::Cardinal
+            .read("100000000000000000000")
+            .is_err());
+    }
+
+    #[test]
+    fn test_dotted_to_words() {
+        assert_eq!(
+            dotted_to_words("192.168.0.1", Separator::Point, ComponentReading::Paired),
+            Ok(String::from(
+                "one ninety-two point one sixty-eight point zero point one"
+            ))
+        );
+        assert_eq!(
+            dotted_to_words("42", Separator::Dot, ComponentReading::Cardinal),
+            Ok(String::from("forty-two"))
+        );
+        assert!(dotted_to_words("1.2.", Separator::Dot, ComponentReading::Cardinal).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+/* src/fractions.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{to_ordinal_word, AndBehavior};
+use super::to_word;
+
+/// Words for a denominator, e.g. "half", "quarters", or "sixteenths"
+///
+/// A power of ten from a hundred up is written without "one" and hyphenated, as in "three
+/// thousandths" or "seven hundred-thousandths".
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::fractions::denominator_word;
+/// use numbers_into_words::AndBehavior;
+/// assert_eq!(denominator_word(2, true, AndBehavior::All), "halves".to_string());
+/// assert_eq!(denominator_word(16, false, AndBehavior::All), "sixteenth".to_string());
+/// assert_eq!(denominator_word(100, false, AndBehavior::All), "hundredth".to_string());
+/// assert_eq!(
+///     denominator_word(100_000, false, AndBehavior::All),
+///     "hundred-thousandth".to_string()
+/// );
+/// ```
+pub fn denominator_word(denominator: u64, plural: bool, and_behavior: AndBehavior) -> String {
+    let singular = match denominator {
+        1 => "whole".to_string(),
+        2 => "half".to_string(),
+        4 => "quarter".to_string(),
+        d if d >= 100 && 10_u64.pow(d.ilog10()) == d => {
+            let ordinal = to_ordinal_word(d, and_behavior);
+            ordinal
+                .strip_prefix("one-")
+                .or_else(|| ordinal.strip_prefix("one "))
+                .unwrap_or(&ordinal)
+                .replace(' ', "-")
+        }
+        d => to_ordinal_word(d, and_behavior),
+    };
+    match (plural, denominator) {
+        (false, _) => singular,
+        (true, 2) => "halves".to_string(),
+        (true, _) => format!("{}s", singular),
+    }
+}
+
+/// Convert a fraction to words, e.g. "three quarters" or "seven sixteenths"
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::fractions::fraction_to_words;
+/// use numbers_into_words::AndBehavior;
+/// assert_eq!(
+///     fraction_to_words(1, 2, AndBehavior::All),
+///     Ok("one half".to_string())
+/// );
+/// assert_eq!(
+///     fraction_to_words(7, 16, AndBehavior::All),
+///     Ok("seven sixteenths".to_string())
+/// );
+/// assert_eq!(fraction_to_words(1, 0, AndBehavior::All), Err("Denominator is zero."));
+/// ```
+pub fn fraction_to_words(
+    numerator: u64,
+    denominator: u64,
+    and_behavior: AndBehavior,
+) -> Result<String, &'static str> {
+    if denominator == 0 {
+        return Err("Denominator is zero.");
+    }
+    Ok(format!(
+        "{} {}",
+        to_word(numerator, and_behavior),
+        denominator_word(denominator, numerator != 1, and_behavior)
+    ))
+}
+
+/// Convert a fraction to words as a mixed number, e.g. "two and three-eighths"
+///
+/// The fractional part following a whole number is hyphenated when its numerator and
+/// denominator are each a single word; a fraction under one is written as by
+/// `fraction_to_words`.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::fractions::mixed_number_to_words;
+/// use numbers_into_words::AndBehavior;
+/// assert_eq!(
+///     mixed_number_to_words(19, 8, AndBehavior::All),
+///     Ok("two and three-eighths".to_string())
+/// );
+/// assert_eq!(
+///     mixed_number_to_words(3, 4, AndBehavior::All),
+///     Ok("three quarters".to_string())
+/// );
+/// assert_eq!(mixed_number_to_words(16, 8, AndBehavior::All), Ok("two".to_string()));
+/// ```
+pub fn mixed_number_to_words(
+    numerator: u64,
+    denominator: u64,
+    and_behavior: AndBehavior,
+) -> Result<String, &'static str> {
+    if denominator == 0 {
+        return Err("Denominator is zero.");
+    }
+    let whole = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0 {
+        return Ok(to_word(whole, and_behavior));
+    }
+    if whole == 0 {
+        return fraction_to_words(remainder, denominator, and_behavior);
+    }
+    let top = to_word(remainder, and_behavior);
+    let bottom = denominator_word(denominator, remainder != 1, and_behavior);
+    let single_words = !format!("{}{}", top, bottom).contains([' ', '-']);
+    Ok(format!(
+        "{} and {}{}{}",
+        to_word(whole, and_behavior),
+        top,
+        if single_words { "-" } else { " " },
+        bottom
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fractions() {
+        assert_eq!(
+            fraction_to_words(3, 4, AndBehavior::All),
+            Ok(String::from("three quarters"))
+        );
+        assert_eq!(
+            fraction_to_words(2, 3, AndBehavior::All),
+            Ok(String::from("two thirds"))
+        );
+        assert_eq!(
+            fraction_to_words(1, 5, AndBehavior::All),
+            Ok(String::from("one fifth"))
+        );
+        assert_eq!(
+            fraction_to_words(0, 2, AndBehavior::All),
+            Ok(String::from("zero halves"))
+        );
+        assert_eq!(
+            fraction_to_words(5, 1, AndBehavior::All),
+            Ok(String::from("five wholes"))
+        );
+        assert_eq!(
+            fraction_to_words(9, 20, AndBehavior::All),
+            Ok(String::from("nine twentieths"))
+        );
+        assert_eq!(
+            fraction_to_words(1, 102, AndBehavior::All),
+            Ok(String::from("one one-hundred and second"))
+        );
+        assert_eq!(
+            fraction_to_words(3, 1000, AndBehavior::All),
+            Ok(String::from("three thousandths"))
+        );
+        assert_eq!(
+            fraction_to_words(1, 100, AndBehavior::All),
+            Ok(String::from("one hundredth"))
+        );
+        assert_eq!(
+            fraction_to_words(7, 100_000, AndBehavior::All),
+            Ok(String::from("seven hundred-thousandths"))
+        );
+        assert_eq!(
+            fraction_to_words(1, 1_000_000, AndBehavior::All),
+            Ok(String::from("one millionth"))
+        );
+        assert_eq!(
+            fraction_to_words(3, 200, AndBehavior::All),
+            Ok(String::from("three two-hundredths"))
+        );
+        assert_eq!(
+            mixed_number_to_words(2_003, 1_000, AndBehavior::All),
+            Ok(String::from("two and three-thousandths"))
+        );
+        assert_eq!(
+            mixed_number_to_words(3, 2, AndBehavior::All),
+            Ok(String::from("one and one-half"))
+        );
+        assert_eq!(
+            mixed_number_to_words(45, 32, AndBehavior::All),
+            Ok(String::from("one and thirteen thirty-seconds"))
+        );
+        assert_eq!(
+            fraction_to_words(1, 10_000_000, AndBehavior::All),
+            Ok(String::from("one ten-millionth"))
+        );
+        assert_eq!(
+            mixed_number_to_words(3, 4, AndBehavior::All),
+            fraction_to_words(3, 4, AndBehavior::All)
+        );
+        assert_eq!(
+            mixed_number_to_words(0, 7, AndBehavior::All),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            mixed_number_to_words(1, 0, AndBehavior::All),
+            Err("Denominator is zero.")
+        );
+    }
+}
@@ -0,0 +1,155 @@
+/* src/histogram.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::AndBehavior;
+use super::to_word_u128;
+
+const MAX_BUCKETS: u128 = 10;
+const MAX_BAR_WIDTH: usize = 40;
+
+/// Finds the smallest power of ten that covers `min..=max` with at most `MAX_BUCKETS`
+/// buckets
+fn bucket_width(min: u128, max: u128) -> u128 {
+    let mut width: u128 = 1;
+    while max / width - min / width >= MAX_BUCKETS {
+        width *= 10;
+    }
+    width
+}
+
+fn label(low: u128, width: u128, and_behavior: AndBehavior) -> String {
+    if width == 1 {
+        to_word_u128(low, and_behavior)
+    } else {
+        format!(
+            "{} to {}",
+            to_word_u128(low, and_behavior),
+            to_word_u128(low.saturating_add(width), and_behavior)
+        )
+    }
+}
+
+/// Buckets the values and draws one bar per bucket, labeled with the spelled-out range
+///
+/// Each bucket includes its lower bound and excludes its upper bound.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::AndBehavior;
+/// use numbers_into_words::histogram::histogram_to_words;
+/// assert_eq!(
+///     histogram_to_words(&[1200, 1500, 3100], AndBehavior::All),
+///     "one thousand to two thousand:    ## two values\n\
+///      two thousand to three thousand:  zero values\n\
+///      three thousand to four thousand: # one value"
+///         .to_string()
+/// );
+/// assert_eq!(histogram_to_words(&[], AndBehavior::All), String::new());
+/// ```
+pub fn histogram_to_words(values: &[u128], and_behavior: AndBehavior) -> String {
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return String::new();
+    };
+    let width = bucket_width(*min, *max);
+    let first = *min / width;
+    let mut counts = vec![0_usize; (*max / width - first + 1) as usize];
+    for v in values {
+        counts[(*v / width - first) as usize] += 1;
+    }
+    let labels: Vec<String> = (0..counts.len())
+        .map(|k| {
+            format!(
+                "{}:",
+                label((first + k as u128) * width, width, and_behavior)
+            )
+        })
+        .collect();
+    let label_width = labels.iter().map(|x| x.len()).max().unwrap_or(0);
+    let max_count = counts.iter().copied().max().unwrap_or(0);
+
+    labels
+        .iter()
+        .zip(counts.iter())
+        .map(|(label, count)| {
+            let bar_length = if max_count <= MAX_BAR_WIDTH {
+                *count
+            } else {
+                (count * MAX_BAR_WIDTH).div_ceil(max_count)
+            };
+            format!(
+                "{:<label_width$} {}{}{} {}",
+                label,
+                "#".repeat(bar_length),
+                if bar_length > 0 { " " } else { "" },
+                to_word_u128(*count as u128, and_behavior),
+                if *count == 1 { "value" } else { "values" },
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_width() {
+        assert_eq!(bucket_width(5, 5), 1);
+        assert_eq!(bucket_width(0, 9), 1);
+        assert_eq!(bucket_width(0, 10), 10);
+        assert_eq!(bucket_width(12, 31), 10);
+        assert_eq!(bucket_width(0, 99), 10);
+        assert_eq!(bucket_width(0, 100), 100);
+        assert_eq!(bucket_width(1200, 3100), 1000);
+        assert_eq!(
+            bucket_width(0, u64::MAX as u128),
+            10_000_000_000_000_000_000
+        );
+        assert_eq!(bucket_width(0, u128::MAX), 10_u128.pow(38));
+    }
+
+    #[test]
+    fn test_histogram_to_words() {
+        assert_eq!(
+            histogram_to_words(&[7, 7, 7], AndBehavior::All),
+            String::from("seven: ### three values")
+        );
+        assert_eq!(
+            histogram_to_words(&[3, 4, 4], AndBehavior::All),
+            String::from("three: # one value\nfour:  ## two values")
+        );
+        let many = vec![10_u128; 80];
+        assert_eq!(
+            histogram_to_words(&many, AndBehavior::All),
+            format!("ten: {} eighty values", "#".repeat(40))
+        );
+        assert!(
+            histogram_to_words(&[0, u64::MAX as u128], AndBehavior::None)
+                .starts_with("zero to ten quintillion:")
+        );
+        assert!(
+            histogram_to_words(&[0, u128::MAX], AndBehavior::None).ends_with(
+                "three-hundred forty undecillion, two-hundred eighty-two \
+                        decillion, three-hundred sixty-six nonillion, nine-hundred \
+                        twenty octillion, nine-hundred thirty-eight septillion, \
+                        four-hundred sixty-three sextillion, four-hundred sixty-three \
+                        quintillion, three-hundred seventy-four quadrillion, six-hundred \
+                        seven trillion, four-hundred thirty-one billion, seven-hundred \
+                        sixty-eight million, two-hundred eleven thousand, four-hundred \
+                        fifty-five: # one value"
+            )
+        );
+    }
+}
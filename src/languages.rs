@@ -0,0 +1,4324 @@
+/* src/languages.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{indian_groups, scale_word, uncased_words, FormatOptions};
+use super::parse_words::from_words;
+use super::{to_word, to_word_u128, AndBehavior};
+use std::cmp::Reverse;
+use std::iter;
+
+/// The words and joining rules of a language, with a default algorithm built from them
+///
+/// A language supplies its lexicon (`zero`, `unit`, `teen`, `tens`, `scale_word`, and
+/// `negative_word`). The remaining methods describe how the words combine, following the
+/// pattern "hundreds, tens, units" for each group of three digits, and can be overridden
+/// where a language departs from it.
+pub trait Language {
+    /// The word for zero
+    fn zero(&self) -> String;
+
+    /// Words for 1 to 9
+    fn unit(&self, n: u64) -> String;
+
+    /// Words for 10 to 19
+    fn teen(&self, n: u64) -> String;
+
+    /// Words for 20, 30, ..., 90
+    fn tens(&self, n: u64) -> String;
+
+    /// The scale word for the group with index `group` (1 for the thousands, 2 for the
+    /// millions, ...), declined to agree with `count`, the value of the group
+    ///
+    /// Every group of a `u128` must be named.
+    fn scale_word(&self, group: usize, count: u64) -> String;
+
+    /// The word placed before a negative number
+    fn negative_word(&self) -> String;
+
+    /// Joins a multiple of ten (20 to 90) with a unit (1 to 9)
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{}-{}", self.tens(tens), self.unit(unit))
+    }
+
+    /// Words for 1 to 99
+    fn under_100(&self, n: u64) -> String {
+        match n {
+            1..=9 => self.unit(n),
+            10..=19 => self.teen(n),
+            _ if n % 10 == 0 => self.tens(n),
+            _ => self.join_tens(n - n % 10, n % 10),
+        }
+    }
+
+    /// Words for 100, 200, ..., 900
+    fn hundreds(&self, n: u64) -> String {
+        format!("{} hundred", self.unit(n / 100))
+    }
+
+    /// Joins a multiple of a hundred with the rest of the group (1 to 99)
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{} {}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    /// Words for 1 to 999
+    fn under_1000(&self, n: u64) -> String {
+        match (n - n % 100, n % 100) {
+            (0, rest) => self.under_100(rest),
+            (hundreds, 0) => self.hundreds(hundreds),
+            (hundreds, rest) => self.join_hundreds(hundreds, rest),
+        }
+    }
+
+    /// Number of digits in each group, e.g. 3 for thousands or 4 for myriads
+    ///
+    /// Languages with wider groups must also override `group_count`.
+    fn group_width(&self) -> u32 {
+        3
+    }
+
+    /// Words for the value of the group with index `group`; override to make the value
+    /// agree with the scale word, as in a feminine "one thousand"
+    fn group_count(&self, count: u64, _group: usize) -> String {
+        self.under_1000(count)
+    }
+
+    /// Combines the value of a group with its scale word, e.g. "five thousand"; override
+    /// to drop a leading "one" or to fuse the words
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        format!("{} {}", self.group_count(count, group), scale)
+    }
+
+    /// Joins the words for the nonzero groups, most significant first
+    fn join_groups(&self, groups: Vec<String>) -> String {
+        groups.join(" ")
+    }
+
+    /// Words for each nonzero group of `x` with its scale word, most significant first
+    fn named_groups(&self, x: u128) -> Vec<String> {
+        let base = 10_u128.pow(self.group_width());
+        let mut groups = Vec::new();
+        let mut rest = x;
+        let mut group = 0;
+        while rest > 0 {
+            let count = (rest % base) as u64;
+            if count != 0 {
+                groups.push(if group == 0 {
+                    self.group_count(count, 0)
+                } else {
+                    self.scaled_group(count, group, self.scale_word(group, count))
+                });
+            }
+            rest /= base;
+            group += 1;
+        }
+        groups.reverse();
+        groups
+    }
+
+    /// Words for `x`
+    fn cardinal(&self, x: u128) -> String {
+        if x == 0 {
+            return self.zero();
+        }
+        self.join_groups(self.named_groups(x))
+    }
+
+    /// Words for `x`, following `options` where the language supports them
+    ///
+    /// The default ignores `options`; the letter case is applied by `to_word_in`.
+    fn cardinal_with(&self, x: u128, _options: &FormatOptions) -> String {
+        self.cardinal(x)
+    }
+
+    /// Reads words for a number back into the number, ignoring letter case, spaces, and
+    /// hyphens
+    ///
+    /// The default splits the words into those `cardinal` writes for a single group with
+    /// its scale word (e.g. "trois cent mille"), and checks the result by writing it out
+    /// again; see `GroupReader`.
+    fn parse_cardinal(&self, text: &str) -> Result<u64, &'static str> {
+        self.parse_cardinal_with(text, &GroupReader::new(self))
+    }
+
+    /// The words `GroupReader` looks for: the letters (as in `parse_cardinal`, without
+    /// spaces or hyphens) of each value a group can add, with that value and the index of
+    /// the group, which falls from one group to the next
+    ///
+    /// The default writes out each value of a group of four digits for languages that
+    /// count in myriads, and of three otherwise.
+    fn group_words(&self) -> Vec<(String, u64, u32)> {
+        // groups of a million are read as two groups of a thousand
+        let width = if self.group_width() == 4 { 4 } else { 3 };
+        let base = 10_u64.pow(width);
+        let mut pieces = Vec::new();
+        let mut group = 0;
+        while let Some(scale) = base.checked_pow(group) {
+            for count in 1..base {
+                let Some(value) = scale.checked_mul(count) else {
+                    break;
+                };
+                let words = letters(&self.cardinal(value as u128));
+                // with groups of a million, "mil" can start a group whose scale word
+                // comes later, as in "mil doscientos millones"
+                if self.group_width() == 6 && group % 2 == 1 && group > 1 {
+                    let scale = letters(&self.scale_word(group as usize / 2, 2));
+                    if let Some(start) = words.strip_suffix(&scale) {
+                        pieces.push((start.to_string(), value, group));
+                    }
+                }
+                pieces.push((words, value, group));
+            }
+            group += 1;
+        }
+        pieces
+    }
+
+    /// Like `parse_cardinal`, but with the group words already built by
+    /// `GroupReader::new(self)`
+    ///
+    /// A language that overrides `parse_cardinal` without the group words overrides this
+    /// too.
+    fn parse_cardinal_with(&self, text: &str, reader: &GroupReader) -> Result<u64, &'static str> {
+        reader.read(self, text)
+    }
+}
+
+/// The letters of `text` in lowercase, without spaces, hyphens, or punctuation
+fn letters(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The words the default `parse_cardinal` looks for, built from a language's own
+/// `cardinal`: the words for each value of a group with its scale word, and the words
+/// joining groups
+///
+/// Building the table writes out thousands of numbers, so a program reading many numbers
+/// in one language builds it once and reads each with `Language::parse_cardinal_with`.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{GroupReader, Japanese, JapaneseScript, Language};
+/// let japanese = Japanese::new(JapaneseScript::Kanji);
+/// let reader = GroupReader::new(&japanese);
+/// assert_eq!(japanese.parse_cardinal_with("三百二十一万", &reader), Ok(3_210_000));
+/// assert_eq!(japanese.parse_cardinal_with("五", &reader), Ok(5));
+/// ```
+pub struct GroupReader {
+    /// The words for each value of a group times its power, longest first, with the
+    /// value and the index of the power
+    pieces: Vec<(String, u64, u32)>,
+
+    /// Words joining groups, such as "e" in Portuguese "mil e um"
+    joiners: Vec<String>,
+
+    /// The number of powers in `pieces`
+    powers: u32,
+}
+
+impl GroupReader {
+    /// Builds the table for `language` from its `group_words`
+    pub fn new<L: Language + ?Sized>(language: &L) -> Self {
+        let mut pieces = language.group_words();
+        pieces.sort_by_key(|(words, _, _)| Reverse(words.len()));
+        let powers = pieces
+            .iter()
+            .map(|(_, _, power)| power + 1)
+            .max()
+            .unwrap_or(0);
+        let base = if language.group_width() == 4 {
+            10_000
+        } else {
+            1000
+        };
+        let joiners = [(base, 1), (base, base / 10)]
+            .iter()
+            .filter_map(|(high, low)| {
+                let words = letters(&language.cardinal((high + low) as u128));
+                words
+                    .strip_prefix(&letters(&language.cardinal(*high as u128)))?
+                    .strip_suffix(&letters(&language.cardinal(*low as u128)))
+                    .filter(|joiner| !joiner.is_empty())
+                    .map(str::to_string)
+            })
+            .collect();
+        Self {
+            pieces,
+            joiners,
+            powers,
+        }
+    }
+
+    /// Reads words in `language`, the language the table was built for, back into the
+    /// number, checking the result by writing it out again
+    pub fn read<L: Language + ?Sized>(
+        &self,
+        language: &L,
+        text: &str,
+    ) -> Result<u64, &'static str> {
+        let target = letters(text);
+        if target.is_empty() {
+            return Err("No words.");
+        }
+        if target == letters(&language.zero()) {
+            return Ok(0);
+        }
+        let search = Search {
+            language,
+            target: &target,
+            reader: self,
+        };
+        search
+            .find(0, self.powers, 0)
+            .ok_or("Could not read the words.")
+    }
+}
+
+/// What `GroupReader::read` looks for in the words it reads
+struct Search<'a, L: Language + ?Sized> {
+    language: &'a L,
+    target: &'a str,
+    reader: &'a GroupReader,
+}
+
+impl<L: Language + ?Sized> Search<'_, L> {
+    /// Splits the rest of the words, from `pos`, into group words with powers below
+    /// `below`, returning the first total whose words are the ones read
+    fn find(&self, pos: usize, below: u32, total: u64) -> Option<u64> {
+        if pos == self.target.len() {
+            return (letters(&self.language.cardinal(total as u128)) == self.target)
+                .then_some(total);
+        }
+        let rest = &self.target[pos..];
+        let after_joiner = self
+            .reader
+            .joiners
+            .iter()
+            .filter(|_| pos > 0)
+            .filter_map(|joiner| rest.strip_prefix(joiner.as_str()));
+        iter::once(rest).chain(after_joiner).find_map(|rest| {
+            let pos = self.target.len() - rest.len();
+            self.reader
+                .pieces
+                .iter()
+                .filter(|(words, _, power)| *power < below && rest.starts_with(words.as_str()))
+                .find_map(|(words, value, power)| {
+                    self.find(pos + words.len(), *power, total.checked_add(*value)?)
+                })
+        })
+    }
+}
+
+/// English, the default language
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct English;
+
+impl Language for English {
+    fn zero(&self) -> String {
+        "zero".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        to_word(n, AndBehavior::None)
+    }
+
+    fn teen(&self, n: u64) -> String {
+        to_word(n, AndBehavior::None)
+    }
+
+    fn tens(&self, n: u64) -> String {
+        to_word(n, AndBehavior::None)
+    }
+
+    fn scale_word(&self, group: usize, _count: u64) -> String {
+        scale_word(group).trim_start().to_string()
+    }
+
+    fn negative_word(&self) -> String {
+        "negative".to_string()
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        format!("{}-hundred", self.unit(n / 100))
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{} and {}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    fn join_groups(&self, groups: Vec<String>) -> String {
+        groups.join(", ")
+    }
+
+    fn cardinal(&self, x: u128) -> String {
+        to_word_u128(x, AndBehavior::All)
+    }
+
+    fn cardinal_with(&self, x: u128, options: &FormatOptions) -> String {
+        uncased_words(x, options)
+    }
+
+    fn parse_cardinal(&self, text: &str) -> Result<u64, &'static str> {
+        from_words(&text.to_lowercase()).map_err(|e| e.kind.message())
+    }
+
+    fn parse_cardinal_with(&self, text: &str, _reader: &GroupReader) -> Result<u64, &'static str> {
+        self.parse_cardinal(text)
+    }
+}
+
+/// Esperanto, whose rules are fully regular, so it relies on the default methods of
+/// `Language` wherever it can
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Esperanto};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(345_612, &Esperanto, &FormatOptions::new()),
+///     "tricent kvardek kvin mil sescent dek du".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Esperanto;
+
+impl Language for Esperanto {
+    fn zero(&self) -> String {
+        "nul".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "unu", "du", "tri", "kvar", "kvin", "ses", "sep", "ok", "naŭ",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        match n {
+            10 => "dek".to_string(),
+            _ => format!("dek {}", self.unit(n - 10)),
+        }
+    }
+
+    fn tens(&self, n: u64) -> String {
+        format!("{}dek", self.unit(n / 10))
+    }
+
+    /// "mil", then the long-scale nouns, which take the plural "-oj"
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        if group == 1 {
+            return "mil".to_string();
+        }
+        let stem = [
+            "milion",
+            "miliard",
+            "bilion",
+            "biliard",
+            "trilion",
+            "triliard",
+            "kvadrilion",
+            "kvadriliard",
+            "kvintilion",
+            "kvintiliard",
+            "sekstilion",
+        ][group - 2];
+        match count {
+            1 => format!("{}o", stem),
+            _ => format!("{}oj", stem),
+        }
+    }
+
+    fn negative_word(&self) -> String {
+        "minus".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{} {}", self.tens(tens), self.unit(unit))
+    }
+
+    /// "cent" and "ducent", fused like the tens
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "cent".to_string(),
+            k => format!("{}cent", self.unit(k)),
+        }
+    }
+
+    /// "mil" rather than "unu mil"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match (group, count) {
+            (1, 1) => scale,
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+}
+
+/// Grammatical gender, for languages whose number words agree with the counted noun
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Gender {
+    /// As in Spanish "doscientos uno"
+    Masculine,
+
+    /// As in Spanish "doscientas una"
+    Feminine,
+}
+
+/// Spanish, in the given gender ("una" and "doscientas" for feminine nouns)
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Gender, Spanish};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(21_221, &Spanish::new(Gender::Masculine), &options),
+///     "veintiún mil doscientos veintiuno".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(21_221, &Spanish::new(Gender::Feminine), &options),
+///     "veintiuna mil doscientas veintiuna".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Spanish {
+    gender: Gender,
+}
+
+/// How Spanish writes a final "one": "uno", "una", or "un" before a noun
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum SpanishForm {
+    Masculine,
+    Feminine,
+    BeforeNoun,
+}
+
+impl Spanish {
+    /// Spanish in the given gender
+    pub fn new(gender: Gender) -> Self {
+        Self { gender }
+    }
+
+    fn form(&self) -> SpanishForm {
+        match self.gender {
+            Gender::Masculine => SpanishForm::Masculine,
+            Gender::Feminine => SpanishForm::Feminine,
+        }
+    }
+
+    fn one(form: SpanishForm) -> &'static str {
+        match form {
+            SpanishForm::Masculine => "uno",
+            SpanishForm::Feminine => "una",
+            SpanishForm::BeforeNoun => "ún",
+        }
+    }
+
+    fn below_100(&self, n: u64, form: SpanishForm) -> String {
+        match n {
+            1 => match form {
+                SpanishForm::BeforeNoun => "un".to_string(),
+                f => Self::one(f).to_string(),
+            },
+            2..=9 => self.unit(n),
+            10..=19 => self.teen(n),
+            21 => format!("veinti{}", Self::one(form)),
+            22..=29 => self.join_tens(20, n - 20),
+            _ if n % 10 == 0 => self.tens(n),
+            _ if n % 10 == 1 => format!("{} y {}", self.tens(n - 1), {
+                match form {
+                    SpanishForm::BeforeNoun => "un",
+                    f => Self::one(f),
+                }
+            }),
+            _ => self.join_tens(n - n % 10, n % 10),
+        }
+    }
+
+    fn below_1000(&self, n: u64, form: SpanishForm) -> String {
+        let (hundreds, rest) = (n / 100, n % 100);
+        let head = match (hundreds, rest) {
+            (0, _) => return self.below_100(rest, form),
+            (1, 0) => return "cien".to_string(),
+            (1, _) => "ciento".to_string(),
+            (h, _) => {
+                let stem = match h {
+                    5 => "quinient",
+                    7 => "setecient",
+                    9 => "novecient",
+                    h => return self.below_1000_regular(h, rest, form),
+                };
+                format!("{}{}", stem, Self::hundreds_ending(form))
+            }
+        };
+        match rest {
+            0 => head,
+            r => format!("{} {}", head, self.below_100(r, form)),
+        }
+    }
+
+    fn below_1000_regular(&self, h: u64, rest: u64, form: SpanishForm) -> String {
+        let head = format!("{}cient{}", self.unit(h), Self::hundreds_ending(form));
+        match rest {
+            0 => head,
+            r => format!("{} {}", head, self.below_100(r, form)),
+        }
+    }
+
+    fn hundreds_ending(form: SpanishForm) -> &'static str {
+        match form {
+            SpanishForm::Feminine => "as",
+            _ => "os",
+        }
+    }
+
+    /// Words for 1 to 999,999; `form` applies to the final "one" and to the thousands
+    fn below_million(&self, n: u64, form: SpanishForm) -> String {
+        let (thousands, rest) = (n / 1000, n % 1000);
+        let thousands_form = match form {
+            SpanishForm::Feminine => SpanishForm::Feminine,
+            _ => SpanishForm::BeforeNoun,
+        };
+        let head = match thousands {
+            0 => return self.below_1000(rest, form),
+            1 => "mil".to_string(),
+            t => format!("{} mil", self.below_1000(t, thousands_form)),
+        };
+        match rest {
+            0 => head,
+            r => format!("{} {}", head, self.below_1000(r, form)),
+        }
+    }
+}
+
+impl Language for Spanish {
+    fn zero(&self) -> String {
+        "cero".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "diez",
+            "once",
+            "doce",
+            "trece",
+            "catorce",
+            "quince",
+            "dieciséis",
+            "diecisiete",
+            "dieciocho",
+            "diecinueve",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "veinte",
+            "treinta",
+            "cuarenta",
+            "cincuenta",
+            "sesenta",
+            "setenta",
+            "ochenta",
+            "noventa",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// Spanish uses the long scale, so each group here is a power of a million
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        let stem = [
+            "", "mill", "bill", "trill", "cuatrill", "quintill", "sextill",
+        ][group];
+        if count == 1 {
+            format!("{}ón", stem)
+        } else {
+            format!("{}ones", stem)
+        }
+    }
+
+    fn negative_word(&self) -> String {
+        "menos".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        match (tens, unit) {
+            (20, 2) => "veintidós".to_string(),
+            (20, 3) => "veintitrés".to_string(),
+            (20, 6) => "veintiséis".to_string(),
+            (20, u) => format!("veinti{}", self.unit(u)),
+            (t, u) => format!("{} y {}", self.tens(t), self.unit(u)),
+        }
+    }
+
+    fn under_100(&self, n: u64) -> String {
+        self.below_100(n, self.form())
+    }
+
+    fn under_1000(&self, n: u64) -> String {
+        self.below_1000(n, self.form())
+    }
+
+    fn group_width(&self) -> u32 {
+        6
+    }
+
+    fn group_count(&self, count: u64, group: usize) -> String {
+        if group == 0 {
+            self.below_million(count, self.form())
+        } else {
+            // "millón" and the larger scale words are masculine nouns
+            self.below_million(count, SpanishForm::BeforeNoun)
+        }
+    }
+}
+
+/// French, with the long scale ("milliard" for 10^9) and the traditional spelling, which
+/// hyphenates only within the tens
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, French};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(280_071, &French, &options),
+///     "deux cent quatre-vingt mille soixante et onze".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(280_000_000, &French, &options),
+///     "deux cent quatre-vingts millions".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct French;
+
+impl French {
+    /// Words for 1 to 999, where a final "cents" or "quatre-vingts" takes its plural "s"
+    /// only if `plural` (it does not before "mille")
+    fn below_1000(&self, n: u64, plural: bool) -> String {
+        let words = self.under_1000(n);
+        if plural && (n % 100 == 80 || (n > 100 && n % 100 == 0)) {
+            format!("{}s", words)
+        } else {
+            words
+        }
+    }
+}
+
+impl Language for French {
+    fn zero(&self) -> String {
+        "zéro".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "dix", "onze", "douze", "treize", "quatorze", "quinze", "seize", "dix-sept",
+            "dix-huit", "dix-neuf",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    /// Without the plural "s" of "quatre-vingts", which `French` adds where it applies
+    fn tens(&self, n: u64) -> String {
+        [
+            "vingt",
+            "trente",
+            "quarante",
+            "cinquante",
+            "soixante",
+            "soixante-dix",
+            "quatre-vingt",
+            "quatre-vingt-dix",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// "mille" never takes a plural; the long-scale nouns do
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        if group == 1 {
+            return "mille".to_string();
+        }
+        let stem = [
+            "million",
+            "milliard",
+            "billion",
+            "billiard",
+            "trillion",
+            "trilliard",
+            "quadrillion",
+            "quadrilliard",
+            "quintillion",
+            "quintilliard",
+            "sextillion",
+        ][group - 2];
+        if count > 1 {
+            format!("{}s", stem)
+        } else {
+            stem.to_string()
+        }
+    }
+
+    fn negative_word(&self) -> String {
+        "moins".to_string()
+    }
+
+    /// "vingt et un", "soixante et onze", but "quatre-vingt-un"
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        match (tens, unit) {
+            (20..=60, 1) => format!("{} et un", self.tens(tens)),
+            (70, 1) => "soixante et onze".to_string(),
+            (70 | 90, u) => format!("{}-{}", self.tens(tens - 10), self.teen(u + 10)),
+            (t, u) => format!("{}-{}", self.tens(t), self.unit(u)),
+        }
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "cent".to_string(),
+            k => format!("{} cent", self.unit(k)),
+        }
+    }
+
+    fn group_count(&self, count: u64, group: usize) -> String {
+        self.below_1000(count, group != 1)
+    }
+
+    /// "mille" rather than "un mille"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        if group == 1 && count == 1 {
+            scale
+        } else {
+            format!("{} {}", self.group_count(count, group), scale)
+        }
+    }
+}
+
+/// German, which writes each number below a million as a single word and uses the long
+/// scale ("Milliarde" for 10^9)
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, German};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(345_612, &German, &options),
+///     "dreihundertfünfundvierzigtausendsechshundertzwölf".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(1_001_001, &German, &options),
+///     "eine Million eintausendeins".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct German;
+
+impl German {
+    /// "ein" rather than "eins" at the start of a compound, as in "einundzwanzig"
+    fn compound_unit(&self, n: u64) -> String {
+        match n {
+            1 => "ein".to_string(),
+            n => self.unit(n),
+        }
+    }
+}
+
+impl Language for German {
+    fn zero(&self) -> String {
+        "null".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "zehn",
+            "elf",
+            "zwölf",
+            "dreizehn",
+            "vierzehn",
+            "fünfzehn",
+            "sechzehn",
+            "siebzehn",
+            "achtzehn",
+            "neunzehn",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "zwanzig", "dreißig", "vierzig", "fünfzig", "sechzig", "siebzig", "achtzig", "neunzig",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// The long-scale nouns, which are capitalized and take a plural
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        if group == 1 {
+            return "tausend".to_string();
+        }
+        let stem = [
+            "Million",
+            "Milliarde",
+            "Billion",
+            "Billiarde",
+            "Trillion",
+            "Trilliarde",
+            "Quadrillion",
+            "Quadrilliarde",
+            "Quintillion",
+            "Quintilliarde",
+            "Sextillion",
+        ][group - 2];
+        match (count, group % 2 == 0) {
+            (1, _) => stem.to_string(),
+            (_, true) => format!("{}en", stem),
+            (_, false) => format!("{}n", stem),
+        }
+    }
+
+    fn negative_word(&self) -> String {
+        "minus".to_string()
+    }
+
+    /// Units before tens, as in "fünfundvierzig"
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{}und{}", self.compound_unit(unit), self.tens(tens))
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        format!("{}hundert", self.compound_unit(n / 100))
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{}{}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    /// "ein" before "tausend" and "eine" before the feminine "Million", ...
+    fn group_count(&self, count: u64, group: usize) -> String {
+        let words = self.under_1000(count);
+        match (group, words.strip_suffix("eins")) {
+            (0, _) | (_, None) => words,
+            (1, Some(stem)) => format!("{}ein", stem),
+            (_, Some(stem)) => format!("{}eine", stem),
+        }
+    }
+
+    /// Fuses the thousands into one word, as in "zweitausend"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match group {
+            1 => format!("{}{}", self.group_count(count, group), scale),
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+
+    /// Separates the scale nouns with spaces, but fuses the thousands with the rest of
+    /// the number below a million
+    fn join_groups(&self, groups: Vec<String>) -> String {
+        join_fused(groups, &["tausend"])
+    }
+}
+
+/// Hindi words for 1 to 99, which are largely irregular
+const HINDI_BELOW_100: [&str; 99] = [
+    "एक",
+    "दो",
+    "तीन",
+    "चार",
+    "पाँच",
+    "छह",
+    "सात",
+    "आठ",
+    "नौ",
+    "दस",
+    "ग्यारह",
+    "बारह",
+    "तेरह",
+    "चौदह",
+    "पंद्रह",
+    "सोलह",
+    "सत्रह",
+    "अठारह",
+    "उन्नीस",
+    "बीस",
+    "इक्कीस",
+    "बाईस",
+    "तेईस",
+    "चौबीस",
+    "पच्चीस",
+    "छब्बीस",
+    "सत्ताईस",
+    "अट्ठाईस",
+    "उनतीस",
+    "तीस",
+    "इकतीस",
+    "बत्तीस",
+    "तैंतीस",
+    "चौंतीस",
+    "पैंतीस",
+    "छत्तीस",
+    "सैंतीस",
+    "अड़तीस",
+    "उनतालीस",
+    "चालीस",
+    "इकतालीस",
+    "बयालीस",
+    "तैंतालीस",
+    "चवालीस",
+    "पैंतालीस",
+    "छियालीस",
+    "सैंतालीस",
+    "अड़तालीस",
+    "उनचास",
+    "पचास",
+    "इक्यावन",
+    "बावन",
+    "तिरेपन",
+    "चौवन",
+    "पचपन",
+    "छप्पन",
+    "सत्तावन",
+    "अट्ठावन",
+    "उनसठ",
+    "साठ",
+    "इकसठ",
+    "बासठ",
+    "तिरसठ",
+    "चौंसठ",
+    "पैंसठ",
+    "छियासठ",
+    "सड़सठ",
+    "अड़सठ",
+    "उनहत्तर",
+    "सत्तर",
+    "इकहत्तर",
+    "बहत्तर",
+    "तिहत्तर",
+    "चौहत्तर",
+    "पचहत्तर",
+    "छिहत्तर",
+    "सतहत्तर",
+    "अठहत्तर",
+    "उन्यासी",
+    "अस्सी",
+    "इक्यासी",
+    "बयासी",
+    "तिरासी",
+    "चौरासी",
+    "पचासी",
+    "छियासी",
+    "सतासी",
+    "अट्ठासी",
+    "नवासी",
+    "नब्बे",
+    "इक्यानबे",
+    "बानबे",
+    "तिरानबे",
+    "चौरानबे",
+    "पंचानबे",
+    "छियानबे",
+    "सत्तानबे",
+    "अट्ठानबे",
+    "निन्यानबे",
+];
+
+/// The Hindi suffixes for the units, thousand, lakh, and crore groups
+const HINDI_POWERS: [&str; 4] = ["", " हज़ार", " लाख", " करोड़"];
+
+/// Hindi, grouped by lakh and crore as in `Scale::Indian`
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Hindi};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(1_234_567, &Hindi, &FormatOptions::new()),
+///     "बारह लाख चौंतीस हज़ार पाँच सौ सड़सठ".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Hindi;
+
+impl Language for Hindi {
+    fn zero(&self) -> String {
+        "शून्य".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        self.under_100(n)
+    }
+
+    fn teen(&self, n: u64) -> String {
+        self.under_100(n)
+    }
+
+    fn tens(&self, n: u64) -> String {
+        self.under_100(n)
+    }
+
+    /// The group indices of `indian_groups`: 1 for the thousands, 2 for the lakhs, 3 for
+    /// the crores, and so on through the crores of crores
+    fn scale_word(&self, group: usize, _count: u64) -> String {
+        let crores = HINDI_POWERS[3].repeat(group / 3);
+        format!("{}{}", HINDI_POWERS[group % 3], crores)
+            .trim_start()
+            .to_string()
+    }
+
+    fn negative_word(&self) -> String {
+        "ऋण".to_string()
+    }
+
+    fn under_100(&self, n: u64) -> String {
+        HINDI_BELOW_100[n as usize - 1].to_string()
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        format!("{} सौ", self.unit(n / 100))
+    }
+
+    /// Names the groups of `indian_groups`, so that a crore count is itself grouped by
+    /// lakh and thousand
+    fn cardinal(&self, x: u128) -> String {
+        if x == 0 {
+            return self.zero();
+        }
+        let groups = indian_groups(x, 0, &HINDI_POWERS)
+            .into_iter()
+            .map(|(value, _, suffix)| format!("{}{}", self.under_1000(value), suffix))
+            .collect();
+        self.join_groups(groups)
+    }
+
+    /// The groups of `indian_groups`, where a thousand or lakh of crores is followed by
+    /// its crores only when it ends the crore count
+    fn group_words(&self) -> Vec<(String, u64, u32)> {
+        let mut pieces = Vec::new();
+        for group in 0..9 {
+            let (tier, place) = (group / 3, group % 3);
+            let Some(scale) = 10_u64.checked_pow(7 * tier + [0, 3, 5][place as usize]) else {
+                break;
+            };
+            for count in 1..if place == 0 { 1000 } else { 100 } {
+                let Some(value) = scale.checked_mul(count) else {
+                    break;
+                };
+                let words = format!("{}{}", self.under_1000(count), HINDI_POWERS[place as usize]);
+                let crores = HINDI_POWERS[3].repeat(tier as usize);
+                pieces.push((letters(&format!("{}{}", words, crores)), value, group));
+                if place != 0 && tier != 0 {
+                    pieces.push((letters(&words), value, group));
+                }
+            }
+        }
+        pieces
+    }
+}
+
+/// Italian, which writes each number below a million as a single word and uses the long
+/// scale ("miliardo" for 10^9)
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Italian};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(38_223, &Italian, &options),
+///     "trentottomiladuecentoventitré".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(21_000_000, &Italian, &options),
+///     "ventun milioni".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Italian;
+
+impl Language for Italian {
+    fn zero(&self) -> String {
+        "zero".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "uno", "due", "tre", "quattro", "cinque", "sei", "sette", "otto", "nove",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "dieci",
+            "undici",
+            "dodici",
+            "tredici",
+            "quattordici",
+            "quindici",
+            "sedici",
+            "diciassette",
+            "diciotto",
+            "diciannove",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "venti",
+            "trenta",
+            "quaranta",
+            "cinquanta",
+            "sessanta",
+            "settanta",
+            "ottanta",
+            "novanta",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// "mila" for the thousands (the single "mille" is handled by `scaled_group`) and
+    /// the long-scale nouns, which take a plural
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        if group == 1 {
+            return "mila".to_string();
+        }
+        let stem = [
+            "milion",
+            "miliard",
+            "bilion",
+            "biliard",
+            "trilion",
+            "triliard",
+            "quadrilion",
+            "quadriliard",
+            "quintilion",
+            "quintiliard",
+            "sestilion",
+        ][group - 2];
+        match (count, group % 2 == 0) {
+            (1, true) => format!("{}e", stem),
+            (1, false) => format!("{}o", stem),
+            _ => format!("{}i", stem),
+        }
+    }
+
+    fn negative_word(&self) -> String {
+        "meno".to_string()
+    }
+
+    /// Drops the final vowel of the tens before "uno" and "otto", as in "ventuno"
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        let tens_word = self.tens(tens);
+        match unit {
+            1 | 8 => format!("{}{}", &tens_word[..tens_word.len() - 1], self.unit(unit)),
+            _ => format!("{}{}", tens_word, self.unit(unit)),
+        }
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "cento".to_string(),
+            k => format!("{}cento", self.unit(k)),
+        }
+    }
+
+    /// Drops the final vowel of "cento" before "otto" and "ottanta", as in "centottanta"
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        let hundreds_word = self.hundreds(hundreds);
+        match rest {
+            8 | 80..=89 => format!(
+                "{}{}",
+                &hundreds_word[..hundreds_word.len() - 1],
+                self.under_100(rest)
+            ),
+            _ => format!("{}{}", hundreds_word, self.under_100(rest)),
+        }
+    }
+
+    /// Accents a final "tre" before the scale nouns ("trentatré milioni") and shortens a
+    /// final "uno" ("ventun milioni")
+    fn group_count(&self, count: u64, group: usize) -> String {
+        let words = self.under_1000(count);
+        if group < 2 {
+            words
+        } else if count > 3 && count % 10 == 3 && count % 100 != 13 {
+            format!("{}é", &words[..words.len() - 1])
+        } else {
+            match words.strip_suffix("uno") {
+                Some(stem) => format!("{}un", stem),
+                None => words,
+            }
+        }
+    }
+
+    /// "mille" rather than "unomila", and the thousands fused into one word
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match (group, count) {
+            (1, 1) => "mille".to_string(),
+            (1, _) => format!("{}{}", self.group_count(count, group), scale),
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+
+    /// Accents the "tre" ending a compound, as in "ventitré" or "milletré"
+    fn join_groups(&self, groups: Vec<String>) -> String {
+        let words = join_fused(groups, &["mila", "mille"]);
+        let last = words.rsplit(' ').next().unwrap_or_default();
+        if last.len() > 3 && last.ends_with("tre") {
+            format!("{}é", &words[..words.len() - 1])
+        } else {
+            words
+        }
+    }
+}
+
+/// The two written standards of Portuguese, which differ in spelling and in scale
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PortugueseVariant {
+    /// European Portuguese, with the long scale ("mil milhões" for 10^9)
+    Portugal,
+
+    /// Brazilian Portuguese, with the short scale ("bilhão" for 10^9)
+    Brazil,
+}
+
+/// Portuguese, in the given variant and gender ("duas" and "duzentas" for feminine
+/// nouns)
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Gender, Portuguese, PortugueseVariant};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// let brazil = Portuguese::new(PortugueseVariant::Brazil, Gender::Masculine);
+/// assert_eq!(
+///     to_word_in(2_000_123, &brazil, &options),
+///     "dois milhões cento e vinte e três".to_string()
+/// );
+/// let portugal = Portuguese::new(PortugueseVariant::Portugal, Gender::Feminine);
+/// assert_eq!(
+///     to_word_in(2_200_000_000, &portugal, &options),
+///     "dois mil e duzentos milhões".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Portuguese {
+    variant: PortugueseVariant,
+    gender: Gender,
+}
+
+impl Portuguese {
+    /// Portuguese in the given variant and gender
+    pub fn new(variant: PortugueseVariant, gender: Gender) -> Self {
+        Self { variant, gender }
+    }
+
+    fn below_1000(&self, n: u64, gender: Gender) -> String {
+        let feminine = gender == Gender::Feminine;
+        let unit = |k: u64| match (k, feminine) {
+            (1, true) => "uma".to_string(),
+            (2, true) => "duas".to_string(),
+            (k, _) => self.unit(k),
+        };
+        let below_100 = match n % 100 {
+            0 => String::new(),
+            k @ 1..=9 => unit(k),
+            k @ 10..=19 => self.teen(k),
+            k if k % 10 == 0 => self.tens(k),
+            k => format!("{} e {}", self.tens(k - k % 10), unit(k % 10)),
+        };
+        let hundreds = match n / 100 {
+            0 => return below_100,
+            1 if below_100.is_empty() => return "cem".to_string(),
+            1 => "cento".to_string(),
+            k => format!(
+                "{}{}",
+                [
+                    "duzent",
+                    "trezent",
+                    "quatrocent",
+                    "quinhent",
+                    "seiscent",
+                    "setecent",
+                    "oitocent",
+                    "novecent",
+                ][k as usize - 2],
+                if feminine { "as" } else { "os" }
+            ),
+        };
+        if below_100.is_empty() {
+            hundreds
+        } else {
+            format!("{} e {}", hundreds, below_100)
+        }
+    }
+
+    /// Words for 1 to 999,999, as used for each group of the long scale
+    fn below_million(&self, n: u64, gender: Gender) -> String {
+        let (thousands, rest) = (n / 1000, n % 1000);
+        let head = match thousands {
+            0 => return self.below_1000(rest, gender),
+            1 => "mil".to_string(),
+            k => format!("{} mil", self.below_1000(k, gender)),
+        };
+        match rest {
+            0 => head,
+            k if k < 100 || k % 100 == 0 => {
+                format!("{} e {}", head, self.below_1000(k, gender))
+            }
+            k => format!("{} {}", head, self.below_1000(k, gender)),
+        }
+    }
+}
+
+impl Language for Portuguese {
+    fn zero(&self) -> String {
+        "zero".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "um", "dois", "três", "quatro", "cinco", "seis", "sete", "oito", "nove",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        match (n, self.variant) {
+            (16, PortugueseVariant::Portugal) => "dezasseis".to_string(),
+            (17, PortugueseVariant::Portugal) => "dezassete".to_string(),
+            (19, PortugueseVariant::Portugal) => "dezanove".to_string(),
+            _ => [
+                "dez",
+                "onze",
+                "doze",
+                "treze",
+                "catorze",
+                "quinze",
+                "dezesseis",
+                "dezessete",
+                "dezoito",
+                "dezenove",
+            ][n as usize - 10]
+                .to_string(),
+        }
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "vinte",
+            "trinta",
+            "quarenta",
+            "cinquenta",
+            "sessenta",
+            "setenta",
+            "oitenta",
+            "noventa",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// In Portugal each group is a power of a million; in Brazil, of a thousand
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        let stem = match self.variant {
+            PortugueseVariant::Portugal => {
+                ["milh", "bili", "trili", "quatrili", "quintili", "sextili"][group - 1]
+            }
+            PortugueseVariant::Brazil if group == 1 => return "mil".to_string(),
+            PortugueseVariant::Brazil => [
+                "milh", "bilh", "trilh", "quatrilh", "quintilh", "sextilh", "septilh", "octilh",
+                "nonilh", "decilh", "undecilh",
+            ][group - 2],
+        };
+        if count == 1 {
+            format!("{}ão", stem)
+        } else {
+            format!("{}ões", stem)
+        }
+    }
+
+    fn negative_word(&self) -> String {
+        "menos".to_string()
+    }
+
+    fn under_1000(&self, n: u64) -> String {
+        self.below_1000(n, self.gender)
+    }
+
+    fn group_width(&self) -> u32 {
+        match self.variant {
+            PortugueseVariant::Portugal => 6,
+            PortugueseVariant::Brazil => 3,
+        }
+    }
+
+    /// "milhão" and the larger scale words are masculine nouns
+    fn group_count(&self, count: u64, group: usize) -> String {
+        match (self.variant, group) {
+            (PortugueseVariant::Portugal, 0) => self.below_million(count, self.gender),
+            (PortugueseVariant::Portugal, _) => self.below_million(count, Gender::Masculine),
+            (PortugueseVariant::Brazil, 0 | 1) => self.below_1000(count, self.gender),
+            (PortugueseVariant::Brazil, _) => self.below_1000(count, Gender::Masculine),
+        }
+    }
+
+    /// "mil" rather than "um mil"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        if self.variant == PortugueseVariant::Brazil && group == 1 && count == 1 {
+            scale
+        } else {
+            format!("{} {}", self.group_count(count, group), scale)
+        }
+    }
+
+    /// Joins the last group with "e" when it is a single group of three digits below a
+    /// hundred or a whole number of hundreds, as in "um milhão e quinhentos mil"
+    fn cardinal(&self, x: u128) -> String {
+        if x == 0 {
+            return self.zero();
+        }
+        let mut groups = self.named_groups(x);
+        let base = 10_u128.pow(self.group_width());
+        let mut last = x;
+        while last % base == 0 {
+            last /= base;
+        }
+        let mut last = last % base;
+        while last % 1000 == 0 {
+            last /= 1000;
+        }
+        match groups.pop() {
+            Some(final_group)
+                if !groups.is_empty() && last < 1000 && (last < 100 || last % 100 == 0) =>
+            {
+                format!("{} e {}", self.join_groups(groups), final_group)
+            }
+            Some(final_group) => {
+                groups.push(final_group);
+                self.join_groups(groups)
+            }
+            None => self.zero(),
+        }
+    }
+}
+
+/// How Japanese numbers are written
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum JapaneseScript {
+    /// Kanji numerals, as in "三百二十一万"
+    Kanji,
+
+    /// The reading in hiragana, as in "さんびゃくにじゅういちまん"
+    Kana,
+}
+
+/// Japanese, grouped by ten thousands (万, 億, 兆, ...) and written without spaces
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Japanese, JapaneseScript};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(3_210_000, &Japanese::new(JapaneseScript::Kanji), &options),
+///     "三百二十一万".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(3_210_000, &Japanese::new(JapaneseScript::Kana), &options),
+///     "さんびゃくにじゅういちまん".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Japanese {
+    script: JapaneseScript,
+}
+
+impl Japanese {
+    /// Japanese in the given script
+    pub fn new(script: JapaneseScript) -> Self {
+        Self { script }
+    }
+
+    /// Picks the kanji or the kana spelling
+    fn pick(&self, kanji: &str, kana: &str) -> String {
+        match self.script {
+            JapaneseScript::Kanji => kanji.to_string(),
+            JapaneseScript::Kana => kana.to_string(),
+        }
+    }
+
+    /// Words for 1,000, 2,000, ..., 9,000, with the kana sound changes "さんぜん" and
+    /// "はっせん"
+    fn thousands(&self, n: u64) -> String {
+        match (n / 1000, self.script) {
+            (1, _) => self.pick("千", "せん"),
+            (3, JapaneseScript::Kana) => "さんぜん".to_string(),
+            (8, JapaneseScript::Kana) => "はっせん".to_string(),
+            (k, _) => format!("{}{}", self.unit(k), self.pick("千", "せん")),
+        }
+    }
+}
+
+impl Language for Japanese {
+    fn zero(&self) -> String {
+        self.pick("零", "れい")
+    }
+
+    fn unit(&self, n: u64) -> String {
+        let i = n as usize;
+        match self.script {
+            JapaneseScript::Kanji => ["", "一", "二", "三", "四", "五", "六", "七", "八", "九"][i],
+            JapaneseScript::Kana => [
+                "",
+                "いち",
+                "に",
+                "さん",
+                "よん",
+                "ご",
+                "ろく",
+                "なな",
+                "はち",
+                "きゅう",
+            ][i],
+        }
+        .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        format!("{}{}", self.pick("十", "じゅう"), self.unit(n - 10))
+    }
+
+    fn tens(&self, n: u64) -> String {
+        format!("{}{}", self.unit(n / 10), self.pick("十", "じゅう"))
+    }
+
+    fn scale_word(&self, group: usize, _count: u64) -> String {
+        let i = group - 1;
+        match self.script {
+            JapaneseScript::Kanji => ["万", "億", "兆", "京", "垓", "𥝱", "穣", "溝", "澗"][i],
+            JapaneseScript::Kana => [
+                "まん",
+                "おく",
+                "ちょう",
+                "けい",
+                "がい",
+                "じょ",
+                "じょう",
+                "こう",
+                "かん",
+            ][i],
+        }
+        .to_string()
+    }
+
+    fn negative_word(&self) -> String {
+        "マイナス".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{}{}", self.tens(tens), self.unit(unit))
+    }
+
+    /// With the kana sound changes "さんびゃく", "ろっぴゃく", and "はっぴゃく"
+    fn hundreds(&self, n: u64) -> String {
+        match (n / 100, self.script) {
+            (1, _) => self.pick("百", "ひゃく"),
+            (3, JapaneseScript::Kana) => "さんびゃく".to_string(),
+            (6, JapaneseScript::Kana) => "ろっぴゃく".to_string(),
+            (8, JapaneseScript::Kana) => "はっぴゃく".to_string(),
+            (k, _) => format!("{}{}", self.unit(k), self.pick("百", "ひゃく")),
+        }
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{}{}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    fn group_width(&self) -> u32 {
+        4
+    }
+
+    fn group_count(&self, count: u64, _group: usize) -> String {
+        match (count - count % 1000, count % 1000) {
+            (0, rest) => self.under_1000(rest),
+            (thousands, 0) => self.thousands(thousands),
+            (thousands, rest) => {
+                format!("{}{}", self.thousands(thousands), self.under_1000(rest))
+            }
+        }
+    }
+
+    /// Always "一万" rather than "万", and in kana the sound changes before "ちょう" and
+    /// "けい", as in "いっちょう" and "はっけい"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        let words = self.group_count(count, group);
+        if self.script == JapaneseScript::Kanji || !(group == 3 || group == 4) {
+            return format!("{}{}", words, scale);
+        }
+        let endings: &[&str] = match group {
+            3 => &["いち", "はち", "じゅう"],
+            _ => &["いち", "ろく", "はち", "じゅう"],
+        };
+        if endings.iter().any(|ending| words.ends_with(ending)) {
+            let mut stem = words;
+            stem.pop();
+            format!("{}っ{}", stem, scale)
+        } else {
+            format!("{}{}", words, scale)
+        }
+    }
+
+    fn join_groups(&self, groups: Vec<String>) -> String {
+        groups.concat()
+    }
+}
+
+/// Arabic, in the given gender, with the dual forms of the scale words ("ألفان" for two
+/// thousand) and the groups joined by "و"
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Arabic, Gender};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(2_023, &Arabic::new(Gender::Masculine), &options),
+///     "ألفان وثلاثة وعشرون".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(13, &Arabic::new(Gender::Feminine), &options),
+///     "ثلاث عشرة".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Arabic {
+    gender: Gender,
+}
+
+impl Arabic {
+    /// Arabic in the given gender
+    pub fn new(gender: Gender) -> Self {
+        Self { gender }
+    }
+
+    /// Words for 1 to 99; from 3 to 10 the number takes the opposite gender to its noun
+    fn below_100(&self, n: u64, gender: Gender) -> String {
+        let feminine = gender == Gender::Feminine;
+        let unit = |k: u64| match (k, feminine) {
+            (1, true) => "واحدة".to_string(),
+            (2, true) => "اثنتان".to_string(),
+            (k, true) => ["ثلاث", "أربع", "خمس", "ست", "سبع", "ثماني", "تسع", "عشر"][k as usize - 3]
+                .to_string(),
+            (k, false) => self.unit(k),
+        };
+        match n {
+            1..=10 => unit(n),
+            11 if feminine => "إحدى عشرة".to_string(),
+            12 if feminine => "اثنتا عشرة".to_string(),
+            13..=19 if feminine => format!("{} عشرة", unit(n - 10)),
+            11..=19 => self.teen(n),
+            _ if n % 10 == 0 => self.tens(n),
+            _ => format!("{} و{}", unit(n % 10), self.tens(n - n % 10)),
+        }
+    }
+
+    fn below_1000(&self, n: u64, gender: Gender) -> String {
+        let rest = n % 100;
+        match (n / 100, rest) {
+            (0, _) => self.below_100(rest, gender),
+            (_, 0) => self.hundreds(n),
+            (_, _) => format!("{} و{}", self.hundreds(n), self.below_100(rest, gender)),
+        }
+    }
+}
+
+impl Language for Arabic {
+    fn zero(&self) -> String {
+        "صفر".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "",
+            "واحد",
+            "اثنان",
+            "ثلاثة",
+            "أربعة",
+            "خمسة",
+            "ستة",
+            "سبعة",
+            "ثمانية",
+            "تسعة",
+            "عشرة",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        match n {
+            10 => "عشرة".to_string(),
+            11 => "أحد عشر".to_string(),
+            12 => "اثنا عشر".to_string(),
+            _ => format!("{} عشر", self.unit(n - 10)),
+        }
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "عشرون",
+            "ثلاثون",
+            "أربعون",
+            "خمسون",
+            "ستون",
+            "سبعون",
+            "ثمانون",
+            "تسعون",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// The singular for one, the dual for two, the plural after 3 to 10, and the
+    /// accusative singular after 11 to 99
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        let (singular, dual, plural) = match group {
+            1 => ("ألف".to_string(), "ألفان".to_string(), "آلاف".to_string()),
+            2 => (
+                "مليون".to_string(),
+                "مليونان".to_string(),
+                "ملايين".to_string(),
+            ),
+            _ => {
+                let singular = [
+                    "مليار",
+                    "تريليون",
+                    "كوادريليون",
+                    "كوينتليون",
+                    "سكستليون",
+                    "سبتليون",
+                    "أوكتليون",
+                    "نونليون",
+                    "ديسليون",
+                    "أنديسليون",
+                ][group - 3];
+                (
+                    singular.to_string(),
+                    format!("{}ان", singular),
+                    format!("{}ات", singular),
+                )
+            }
+        };
+        match (count, count % 100) {
+            (2, _) => dual,
+            (_, 3..=10) => plural,
+            (_, 11..=99) => format!("{}ا", singular),
+            _ => singular,
+        }
+    }
+
+    fn negative_word(&self) -> String {
+        "سالب".to_string()
+    }
+
+    fn under_100(&self, n: u64) -> String {
+        self.below_100(n, Gender::Masculine)
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "مائة".to_string(),
+            2 => "مائتان".to_string(),
+            k => format!(
+                "{}مائة",
+                ["ثلاث", "أربع", "خمس", "ست", "سبع", "ثمان", "تسع"][k as usize - 3]
+            ),
+        }
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{} و{}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    /// The scale words are masculine nouns
+    fn group_count(&self, count: u64, group: usize) -> String {
+        match group {
+            0 => self.below_1000(count, self.gender),
+            _ => self.below_1000(count, Gender::Masculine),
+        }
+    }
+
+    /// The scale word alone for one and two, as in "ألف" and "ألفان"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match count {
+            1 | 2 => scale,
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+
+    fn join_groups(&self, groups: Vec<String>) -> String {
+        groups.join(" و")
+    }
+}
+
+/// Which characters Chinese numbers are written with
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ChineseNumerals {
+    /// The everyday characters, as in "一千零五"
+    Standard,
+
+    /// The financial characters (大写) used on cheques because they are hard to alter, as
+    /// in "壹仟零伍"
+    Financial,
+}
+
+/// Mandarin Chinese in simplified characters, grouped by ten thousands (万, 亿, ...)
+///
+/// A "零" marks each run of zeros inside the number.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Chinese, ChineseNumerals};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(1_005, &Chinese::new(ChineseNumerals::Standard), &options),
+///     "一千零五".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(1_005, &Chinese::new(ChineseNumerals::Financial), &options),
+///     "壹仟零伍".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Chinese {
+    numerals: ChineseNumerals,
+}
+
+impl Chinese {
+    /// Chinese with the given characters
+    pub fn new(numerals: ChineseNumerals) -> Self {
+        Self { numerals }
+    }
+
+    /// The characters for ten, a hundred, and a thousand
+    fn place(&self, position: usize) -> &'static str {
+        match self.numerals {
+            ChineseNumerals::Standard => ["", "十", "百", "千"][position],
+            ChineseNumerals::Financial => ["", "拾", "佰", "仟"][position],
+        }
+    }
+
+    /// Words for 1 to 9,999; `leading` drops the "一" of a "一十" that starts the number,
+    /// except in financial numerals
+    fn below_10000(&self, n: u64, leading: bool) -> String {
+        let mut words = String::new();
+        let mut zero = false;
+        for position in (0..4).rev() {
+            let digit = n / 10_u64.pow(position as u32) % 10;
+            if digit == 0 {
+                zero = !words.is_empty();
+                continue;
+            }
+            if zero {
+                words.push_str(&self.zero());
+                zero = false;
+            }
+            let omit_one = position == 1
+                && digit == 1
+                && leading
+                && words.is_empty()
+                && self.numerals == ChineseNumerals::Standard;
+            if !omit_one {
+                words.push_str(&self.unit(digit));
+            }
+            words.push_str(self.place(position));
+        }
+        words
+    }
+}
+
+impl Language for Chinese {
+    fn zero(&self) -> String {
+        "零".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        let i = n as usize;
+        match self.numerals {
+            ChineseNumerals::Standard => {
+                ["", "一", "二", "三", "四", "五", "六", "七", "八", "九"][i]
+            }
+            ChineseNumerals::Financial => {
+                ["", "壹", "贰", "叁", "肆", "伍", "陆", "柒", "捌", "玖"][i]
+            }
+        }
+        .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        self.below_10000(n, true)
+    }
+
+    fn tens(&self, n: u64) -> String {
+        self.below_10000(n, true)
+    }
+
+    fn scale_word(&self, group: usize, _count: u64) -> String {
+        ["万", "亿", "兆", "京", "垓", "秭", "穰", "沟", "涧"][group - 1].to_string()
+    }
+
+    fn negative_word(&self) -> String {
+        "负".to_string()
+    }
+
+    fn under_1000(&self, n: u64) -> String {
+        self.below_10000(n, true)
+    }
+
+    fn group_width(&self) -> u32 {
+        4
+    }
+
+    fn group_count(&self, count: u64, group: usize) -> String {
+        self.below_10000(count, group == 0)
+    }
+
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        format!("{}{}", self.group_count(count, group), scale)
+    }
+
+    /// Writes a "零" before a group that starts with a zero digit or follows a zero group,
+    /// as in "一亿零一" and "一万零五百"
+    fn cardinal(&self, x: u128) -> String {
+        if x == 0 {
+            return self.zero();
+        }
+        let mut groups = Vec::new();
+        let mut rest = x;
+        while rest > 0 {
+            groups.push((rest % 10_000) as u64);
+            rest /= 10_000;
+        }
+        let mut words = String::new();
+        let mut zero = false;
+        for (group, count) in groups.into_iter().enumerate().rev() {
+            if count == 0 {
+                zero = !words.is_empty();
+                continue;
+            }
+            if !words.is_empty() && (zero || count < 1000) {
+                words.push_str(&self.zero());
+            }
+            zero = false;
+            words.push_str(&self.below_10000(count, words.is_empty()));
+            if group > 0 {
+                words.push_str(&self.scale_word(group, count));
+            }
+        }
+        words
+    }
+}
+
+/// Which Korean numerals to use, which depends on the noun being counted
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum KoreanNumerals {
+    /// Sino-Korean numerals, as in "삼백이십일"
+    Sino,
+
+    /// Native Korean numerals for the last two digits, as in "스물하나", with
+    /// Sino-Korean numerals for the hundreds and above
+    Native,
+}
+
+/// Korean, grouped by ten thousands (만, 억, ...) with a space after each group
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Korean, KoreanNumerals};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(3_215_000, &Korean::new(KoreanNumerals::Sino), &options),
+///     "삼백이십일만 오천".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(123, &Korean::new(KoreanNumerals::Native), &options),
+///     "백스물셋".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Korean {
+    numerals: KoreanNumerals,
+}
+
+impl Korean {
+    /// Korean with the given numerals
+    pub fn new(numerals: KoreanNumerals) -> Self {
+        Self { numerals }
+    }
+
+    /// Sino-Korean words for 1 to 9,999, which drop the "일" before "십", "백", and "천"
+    fn sino(&self, n: u64) -> String {
+        let mut words = String::new();
+        for (position, place) in ["천", "백", "십", ""].iter().enumerate() {
+            let digit = n / 10_u64.pow(3 - position as u32) % 10;
+            if digit > 1 || (digit == 1 && place.is_empty()) {
+                words.push_str(
+                    ["", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"][digit as usize],
+                );
+            }
+            if digit > 0 {
+                words.push_str(place);
+            }
+        }
+        words
+    }
+
+    /// Native Korean words for 1 to 99
+    fn native(&self, n: u64) -> String {
+        let tens = [
+            "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+        ][n as usize / 10];
+        let unit = [
+            "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+        ][n as usize % 10];
+        format!("{}{}", tens, unit)
+    }
+}
+
+impl Language for Korean {
+    fn zero(&self) -> String {
+        "영".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        match self.numerals {
+            KoreanNumerals::Sino => self.sino(n),
+            KoreanNumerals::Native => self.native(n),
+        }
+    }
+
+    fn teen(&self, n: u64) -> String {
+        self.unit(n)
+    }
+
+    fn tens(&self, n: u64) -> String {
+        self.unit(n)
+    }
+
+    fn scale_word(&self, group: usize, _count: u64) -> String {
+        ["만", "억", "조", "경", "해", "자", "양", "구", "간"][group - 1].to_string()
+    }
+
+    fn negative_word(&self) -> String {
+        "마이너스".to_string()
+    }
+
+    fn under_100(&self, n: u64) -> String {
+        self.unit(n)
+    }
+
+    fn group_width(&self) -> u32 {
+        4
+    }
+
+    fn group_count(&self, count: u64, group: usize) -> String {
+        match (self.numerals, group) {
+            (KoreanNumerals::Native, 0) => {
+                format!(
+                    "{}{}",
+                    self.sino(count - count % 100),
+                    self.native(count % 100)
+                )
+            }
+            _ => self.sino(count),
+        }
+    }
+
+    /// "만" rather than "일만", but "일억" and above
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match (group, count) {
+            (1, 1) => scale,
+            _ => format!("{}{}", self.group_count(count, group), scale),
+        }
+    }
+}
+
+/// Dutch, with the long scale ("miljard" for 10^9) and a space after "duizend" and the
+/// scale words
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Dutch};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(83, &Dutch, &options),
+///     "drieëntachtig".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(2_447, &Dutch, &options),
+///     "tweeduizend vierhonderdzevenenveertig".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Dutch;
+
+impl Language for Dutch {
+    fn zero(&self) -> String {
+        "nul".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "tien",
+            "elf",
+            "twaalf",
+            "dertien",
+            "veertien",
+            "vijftien",
+            "zestien",
+            "zeventien",
+            "achttien",
+            "negentien",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "twintig", "dertig", "veertig", "vijftig", "zestig", "zeventig", "tachtig", "negentig",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// "duizend" and the long-scale nouns, which stay singular after a number
+    fn scale_word(&self, group: usize, _count: u64) -> String {
+        [
+            "duizend",
+            "miljoen",
+            "miljard",
+            "biljoen",
+            "biljard",
+            "triljoen",
+            "triljard",
+            "quadriljoen",
+            "quadriljard",
+            "quintiljoen",
+            "quintiljard",
+            "sextiljoen",
+        ][group - 1]
+            .to_string()
+    }
+
+    fn negative_word(&self) -> String {
+        "min".to_string()
+    }
+
+    /// Units before tens, joined by "en", or by "ën" after a final "e" as in
+    /// "tweeëntwintig"
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        let unit_word = self.unit(unit);
+        let joiner = if unit_word.ends_with('e') {
+            "ën"
+        } else {
+            "en"
+        };
+        format!("{}{}{}", unit_word, joiner, self.tens(tens))
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "honderd".to_string(),
+            k => format!("{}honderd", self.unit(k)),
+        }
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{}{}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    /// "duizend" rather than "eenduizend", and the thousands fused into one word
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match (group, count) {
+            (1, 1) => scale,
+            (1, _) => format!("{}{}", self.group_count(count, group), scale),
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+}
+
+/// Polish, with the long scale ("miliard" for 10^9)
+///
+/// The scale words agree in number with the group before them, as in "tysiąc", "dwa
+/// tysiące", and "pięć tysięcy".
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Polish};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(22_015, &Polish, &options),
+///     "dwadzieścia dwa tysiące piętnaście".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Polish;
+
+impl Language for Polish {
+    fn zero(&self) -> String {
+        "zero".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "",
+            "jeden",
+            "dwa",
+            "trzy",
+            "cztery",
+            "pięć",
+            "sześć",
+            "siedem",
+            "osiem",
+            "dziewięć",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "dziesięć",
+            "jedenaście",
+            "dwanaście",
+            "trzynaście",
+            "czternaście",
+            "piętnaście",
+            "szesnaście",
+            "siedemnaście",
+            "osiemnaście",
+            "dziewiętnaście",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "dwadzieścia",
+            "trzydzieści",
+            "czterdzieści",
+            "pięćdziesiąt",
+            "sześćdziesiąt",
+            "siedemdziesiąt",
+            "osiemdziesiąt",
+            "dziewięćdziesiąt",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// Only a lone 1 takes the singular; 2 to 4 (but not 12 to 14) at the end of the
+    /// group take the nominative plural, and the rest the genitive plural
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        let form = match (count, count % 10, count % 100) {
+            (1, _, _) => 0,
+            (_, _, 12..=14) => 2,
+            (_, 2..=4, _) => 1,
+            _ => 2,
+        };
+        if group == 1 {
+            return ["tysiąc", "tysiące", "tysięcy"][form].to_string();
+        }
+        let stem = [
+            "milion",
+            "miliard",
+            "bilion",
+            "biliard",
+            "trylion",
+            "tryliard",
+            "kwadrylion",
+            "kwadryliard",
+            "kwintylion",
+            "kwintyliard",
+            "sekstylion",
+        ][group - 2];
+        format!("{}{}", stem, ["", "y", "ów"][form])
+    }
+
+    fn negative_word(&self) -> String {
+        "minus".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{} {}", self.tens(tens), self.unit(unit))
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        [
+            "sto",
+            "dwieście",
+            "trzysta",
+            "czterysta",
+            "pięćset",
+            "sześćset",
+            "siedemset",
+            "osiemset",
+            "dziewięćset",
+        ][n as usize / 100 - 1]
+            .to_string()
+    }
+
+    /// "tysiąc" and "milion" rather than "jeden tysiąc" and "jeden milion"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match count {
+            1 => scale,
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+}
+
+/// The long-scale nouns of Norwegian and Danish, from 10^6 to 10^36
+const NORDIC_POWERS: [&str; 11] = [
+    "million",
+    "milliard",
+    "billion",
+    "billiard",
+    "trillion",
+    "trilliard",
+    "kvadrillion",
+    "kvadrilliard",
+    "kvintillion",
+    "kvintilliard",
+    "sekstillion",
+];
+
+/// The long-scale nouns of Swedish, from 10^6 to 10^36
+const SWEDISH_POWERS: [&str; 11] = [
+    "miljon",
+    "miljard",
+    "biljon",
+    "biljard",
+    "triljon",
+    "triljard",
+    "kvadriljon",
+    "kvadriljard",
+    "kvintiljon",
+    "kvintiljard",
+    "sextiljon",
+];
+
+/// The scale word of a Scandinavian language: `thousand` for the thousands, and the
+/// long-scale `powers` above them, which take the plural "-er"
+fn scandinavian_scale(group: usize, count: u64, thousand: &str, powers: &[&str; 11]) -> String {
+    match (group, count) {
+        (1, _) => thousand.to_string(),
+        (_, 1) => powers[group - 2].to_string(),
+        _ => format!("{}er", powers[group - 2]),
+    }
+}
+
+/// Words for `x` in Norwegian or Danish, which put "og" before a last group below a
+/// hundred, as in "tusen og en"
+fn scandinavian_cardinal(language: &dyn Language, x: u128) -> String {
+    if x == 0 {
+        return language.zero();
+    }
+    let mut groups = language.named_groups(x);
+    let last = groups.pop().unwrap_or_default();
+    if groups.is_empty() {
+        last
+    } else if x % 1000 != 0 && x % 1000 < 100 {
+        format!("{} og {}", language.join_groups(groups), last)
+    } else {
+        groups.push(last);
+        language.join_groups(groups)
+    }
+}
+
+/// Swedish, which writes each number below a million as a single word and uses the long
+/// scale ("miljard" for 10^9)
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Swedish};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(2_021_000, &Swedish, &FormatOptions::new()),
+///     "två miljoner tjugoettusen".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Swedish;
+
+impl Language for Swedish {
+    fn zero(&self) -> String {
+        "noll".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "ett", "två", "tre", "fyra", "fem", "sex", "sju", "åtta", "nio",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "tio", "elva", "tolv", "tretton", "fjorton", "femton", "sexton", "sjutton", "arton",
+            "nitton",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "tjugo", "trettio", "fyrtio", "femtio", "sextio", "sjuttio", "åttio", "nittio",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        scandinavian_scale(group, count, "tusen", &SWEDISH_POWERS)
+    }
+
+    fn negative_word(&self) -> String {
+        "minus".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{}{}", self.tens(tens), self.unit(unit))
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        format!("{}hundra", self.unit(n / 100))
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{}{}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    /// The common-gender "en" before "miljon" and the larger scale words
+    fn group_count(&self, count: u64, group: usize) -> String {
+        let words = self.under_1000(count);
+        match (group, words.strip_suffix("ett")) {
+            (2.., Some(stem)) => format!("{}en", stem),
+            _ => words,
+        }
+    }
+
+    /// Fuses the thousands into one word, merging the "t" of "ett" and "tusen"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        let words = self.group_count(count, group);
+        match group {
+            1 => match words.strip_suffix('t') {
+                Some(stem) if words.ends_with("ett") => format!("{}{}", stem, scale),
+                _ => format!("{}{}", words, scale),
+            },
+            _ => format!("{} {}", words, scale),
+        }
+    }
+
+    fn join_groups(&self, groups: Vec<String>) -> String {
+        join_fused(groups, &["tusen"])
+    }
+}
+
+/// Norwegian (Bokmål), with the long scale ("milliard" for 10^9)
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Norwegian};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(2_221, &Norwegian, &FormatOptions::new()),
+///     "to tusen to hundre og tjueen".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Norwegian;
+
+impl Language for Norwegian {
+    fn zero(&self) -> String {
+        "null".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "en", "to", "tre", "fire", "fem", "seks", "sju", "åtte", "ni",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "ti", "elleve", "tolv", "tretten", "fjorten", "femten", "seksten", "sytten", "atten",
+            "nitten",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "tjue", "tretti", "førti", "femti", "seksti", "sytti", "åtti", "nitti",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        scandinavian_scale(group, count, "tusen", &NORDIC_POWERS)
+    }
+
+    fn negative_word(&self) -> String {
+        "minus".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{}{}", self.tens(tens), self.unit(unit))
+    }
+
+    /// "hundre" rather than "en hundre"
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "hundre".to_string(),
+            k => format!("{} hundre", self.unit(k)),
+        }
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{} og {}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    /// "tusen" rather than "en tusen"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match (group, count) {
+            (1, 1) => scale,
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+
+    fn cardinal(&self, x: u128) -> String {
+        scandinavian_cardinal(self, x)
+    }
+}
+
+/// Danish, with the vigesimal tens from fifty ("halvtreds" for 50, "firs" for 80) and the
+/// long scale ("milliard" for 10^9)
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Danish};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(255, &Danish, &FormatOptions::new()),
+///     "to hundrede og femoghalvtreds".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Danish;
+
+impl Language for Danish {
+    fn zero(&self) -> String {
+        "nul".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "en", "to", "tre", "fire", "fem", "seks", "syv", "otte", "ni",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "ti", "elleve", "tolv", "tretten", "fjorten", "femten", "seksten", "sytten", "atten",
+            "nitten",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "tyve",
+            "tredive",
+            "fyrre",
+            "halvtreds",
+            "tres",
+            "halvfjerds",
+            "firs",
+            "halvfems",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        scandinavian_scale(group, count, "tusind", &NORDIC_POWERS)
+    }
+
+    fn negative_word(&self) -> String {
+        "minus".to_string()
+    }
+
+    /// Units before tens, joined by "og", as in "enogtyve"
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{}og{}", self.unit(unit), self.tens(tens))
+    }
+
+    /// "et hundrede", with the neuter "et"
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "et hundrede".to_string(),
+            k => format!("{} hundrede", self.unit(k)),
+        }
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{} og {}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    /// "et tusind", with the neuter "et"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match (group, count) {
+            (1, 1) => format!("et {}", scale),
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+
+    fn cardinal(&self, x: u128) -> String {
+        scandinavian_cardinal(self, x)
+    }
+}
+
+/// Turkish, whose words combine without any agreement
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Turkish};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(345_612, &Turkish, &FormatOptions::new()),
+///     "üç yüz kırk beş bin altı yüz on iki".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Turkish;
+
+impl Language for Turkish {
+    fn zero(&self) -> String {
+        "sıfır".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "bir", "iki", "üç", "dört", "beş", "altı", "yedi", "sekiz", "dokuz",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        match n {
+            10 => "on".to_string(),
+            _ => format!("on {}", self.unit(n - 10)),
+        }
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "yirmi", "otuz", "kırk", "elli", "altmış", "yetmiş", "seksen", "doksan",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    fn scale_word(&self, group: usize, _count: u64) -> String {
+        [
+            "bin",
+            "milyon",
+            "milyar",
+            "trilyon",
+            "katrilyon",
+            "kentilyon",
+            "seksilyon",
+            "septilyon",
+            "oktilyon",
+            "nonilyon",
+            "desilyon",
+            "undesilyon",
+        ][group - 1]
+            .to_string()
+    }
+
+    fn negative_word(&self) -> String {
+        "eksi".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{} {}", self.tens(tens), self.unit(unit))
+    }
+
+    /// "yüz" rather than "bir yüz"
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "yüz".to_string(),
+            k => format!("{} yüz", self.unit(k)),
+        }
+    }
+
+    /// "bin" rather than "bir bin"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match (group, count) {
+            (1, 1) => scale,
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+}
+
+/// Which of the three forms a Russian or Ukrainian noun takes after `count`: 0 for the
+/// nominative singular (1, 21, ...), 1 for the genitive singular (2 to 4, 22 to 24, ...),
+/// and 2 for the genitive plural (the rest, including 11 to 14)
+fn east_slavic_form(count: u64) -> usize {
+    match (count % 10, count % 100) {
+        (_, 11..=14) => 2,
+        (1, _) => 0,
+        (2..=4, _) => 1,
+        _ => 2,
+    }
+}
+
+/// Swaps a final "one" or "two" in `words`, the masculine words for `n`, for the
+/// feminine form given in `forms` (masculine and feminine pairs for one and two)
+fn east_slavic_gendered(words: String, n: u64, gender: Gender, forms: [(&str, &str); 2]) -> String {
+    if gender == Gender::Masculine || n % 100 / 10 == 1 || !(1..=2).contains(&(n % 10)) {
+        return words;
+    }
+    let (masculine, feminine) = forms[n as usize % 10 - 1];
+    format!(
+        "{}{}",
+        words.strip_suffix(masculine).unwrap_or_default(),
+        feminine
+    )
+}
+
+/// Russian, in the given gender ("одна" and "две" for feminine nouns)
+///
+/// The scale words agree in number with the group before them, and "тысяча" is feminine.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Gender, Russian};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(2_022_005, &Russian::new(Gender::Masculine), &options),
+///     "два миллиона двадцать две тысячи пять".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(1_001, &Russian::new(Gender::Feminine), &options),
+///     "одна тысяча одна".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Russian {
+    gender: Gender,
+}
+
+impl Russian {
+    /// Russian in the given gender
+    pub fn new(gender: Gender) -> Self {
+        Self { gender }
+    }
+
+    fn below_1000(&self, n: u64, gender: Gender) -> String {
+        east_slavic_gendered(
+            self.under_1000(n),
+            n,
+            gender,
+            [("один", "одна"), ("два", "две")],
+        )
+    }
+}
+
+impl Language for Russian {
+    fn zero(&self) -> String {
+        "ноль".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "",
+            "один",
+            "два",
+            "три",
+            "четыре",
+            "пять",
+            "шесть",
+            "семь",
+            "восемь",
+            "девять",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "десять",
+            "одиннадцать",
+            "двенадцать",
+            "тринадцать",
+            "четырнадцать",
+            "пятнадцать",
+            "шестнадцать",
+            "семнадцать",
+            "восемнадцать",
+            "девятнадцать",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "двадцать",
+            "тридцать",
+            "сорок",
+            "пятьдесят",
+            "шестьдесят",
+            "семьдесят",
+            "восемьдесят",
+            "девяносто",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        let form = east_slavic_form(count);
+        if group == 1 {
+            return ["тысяча", "тысячи", "тысяч"][form].to_string();
+        }
+        let stem = [
+            "миллион",
+            "миллиард",
+            "триллион",
+            "квадриллион",
+            "квинтиллион",
+            "секстиллион",
+            "септиллион",
+            "октиллион",
+            "нониллион",
+            "дециллион",
+            "ундециллион",
+        ][group - 2];
+        format!("{}{}", stem, ["", "а", "ов"][form])
+    }
+
+    fn negative_word(&self) -> String {
+        "минус".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{} {}", self.tens(tens), self.unit(unit))
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        [
+            "сто",
+            "двести",
+            "триста",
+            "четыреста",
+            "пятьсот",
+            "шестьсот",
+            "семьсот",
+            "восемьсот",
+            "девятьсот",
+        ][n as usize / 100 - 1]
+            .to_string()
+    }
+
+    /// "тысяча" is feminine and the larger scale words are masculine
+    fn group_count(&self, count: u64, group: usize) -> String {
+        match group {
+            0 => self.below_1000(count, self.gender),
+            1 => self.below_1000(count, Gender::Feminine),
+            _ => self.below_1000(count, Gender::Masculine),
+        }
+    }
+}
+
+/// Ukrainian, in the given gender ("одна" and "дві" for feminine nouns)
+///
+/// Like `Russian`, the scale words agree in number with the group before them, and
+/// "тисяча" is feminine.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Gender, Ukrainian};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(2_022_005, &Ukrainian::new(Gender::Masculine), &FormatOptions::new()),
+///     "два мільйони двадцять дві тисячі п'ять".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ukrainian {
+    gender: Gender,
+}
+
+impl Ukrainian {
+    /// Ukrainian in the given gender
+    pub fn new(gender: Gender) -> Self {
+        Self { gender }
+    }
+
+    fn below_1000(&self, n: u64, gender: Gender) -> String {
+        east_slavic_gendered(
+            self.under_1000(n),
+            n,
+            gender,
+            [("один", "одна"), ("два", "дві")],
+        )
+    }
+}
+
+impl Language for Ukrainian {
+    fn zero(&self) -> String {
+        "нуль".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "",
+            "один",
+            "два",
+            "три",
+            "чотири",
+            "п'ять",
+            "шість",
+            "сім",
+            "вісім",
+            "дев'ять",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        [
+            "десять",
+            "одинадцять",
+            "дванадцять",
+            "тринадцять",
+            "чотирнадцять",
+            "п'ятнадцять",
+            "шістнадцять",
+            "сімнадцять",
+            "вісімнадцять",
+            "дев'ятнадцять",
+        ][n as usize - 10]
+            .to_string()
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "двадцять",
+            "тридцять",
+            "сорок",
+            "п'ятдесят",
+            "шістдесят",
+            "сімдесят",
+            "вісімдесят",
+            "дев'яносто",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        let form = east_slavic_form(count);
+        if group == 1 {
+            return ["тисяча", "тисячі", "тисяч"][form].to_string();
+        }
+        let stem = [
+            "мільйон",
+            "мільярд",
+            "трильйон",
+            "квадрильйон",
+            "квінтильйон",
+            "секстильйон",
+            "септильйон",
+            "октильйон",
+            "нонільйон",
+            "децильйон",
+            "ундецильйон",
+        ][group - 2];
+        format!("{}{}", stem, ["", "и", "ів"][form])
+    }
+
+    fn negative_word(&self) -> String {
+        "мінус".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{} {}", self.tens(tens), self.unit(unit))
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        [
+            "сто",
+            "двісті",
+            "триста",
+            "чотириста",
+            "п'ятсот",
+            "шістсот",
+            "сімсот",
+            "вісімсот",
+            "дев'ятсот",
+        ][n as usize / 100 - 1]
+            .to_string()
+    }
+
+    /// "тисяча" is feminine and the larger scale words are masculine
+    fn group_count(&self, count: u64, group: usize) -> String {
+        match group {
+            0 => self.below_1000(count, self.gender),
+            1 => self.below_1000(count, Gender::Feminine),
+            _ => self.below_1000(count, Gender::Masculine),
+        }
+    }
+}
+
+/// Which Welsh counting system to use
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WelshSystem {
+    /// The decimal system taught in schools, as in "pedwar deg dau" for 42
+    Decimal,
+
+    /// The traditional vigesimal system, as in "dau a deugain" for 42
+    Vigesimal,
+}
+
+/// Applies the Welsh aspirate mutation that follows "a" ("and"), as in "a thrigain"
+fn welsh_aspirate(word: &str) -> String {
+    match word.chars().next() {
+        Some('c' | 'p' | 't') if word.chars().nth(1) != Some('h') => {
+            format!("{}h{}", &word[..1], &word[1..])
+        }
+        _ => word.to_string(),
+    }
+}
+
+/// Joins two Welsh words with "a", or with "ac" before a vowel
+fn welsh_and(first: &str, second: &str) -> String {
+    if second.starts_with(['a', 'e', 'i', 'o', 'u', 'w', 'y']) {
+        format!("{} ac {}", first, second)
+    } else {
+        format!("{} a {}", first, welsh_aspirate(second))
+    }
+}
+
+/// Welsh, in the decimal or the traditional vigesimal system
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Welsh, WelshSystem};
+/// use numbers_into_words::FormatOptions;
+/// let options = FormatOptions::new();
+/// assert_eq!(
+///     to_word_in(67, &Welsh::new(WelshSystem::Decimal), &options),
+///     "chwe deg saith".to_string()
+/// );
+/// assert_eq!(
+///     to_word_in(67, &Welsh::new(WelshSystem::Vigesimal), &options),
+///     "saith a thrigain".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Welsh {
+    system: WelshSystem,
+}
+
+impl Welsh {
+    /// Welsh in the given counting system
+    pub fn new(system: WelshSystem) -> Self {
+        Self { system }
+    }
+
+    /// The traditional words for 11 to 19
+    fn vigesimal_teen(&self, n: u64) -> String {
+        match n {
+            10 => "deg".to_string(),
+            11 => "un ar ddeg".to_string(),
+            12 => "deuddeg".to_string(),
+            13 | 14 => format!("{} ar ddeg", self.unit(n - 10)),
+            15 => "pymtheg".to_string(),
+            18 => "deunaw".to_string(),
+            _ => format!("{} ar bymtheg", self.unit(n - 15)),
+        }
+    }
+
+    /// The traditional words for 1 to 19
+    fn vigesimal_below_20(&self, n: u64) -> String {
+        match n {
+            1..=9 => self.unit(n),
+            _ => self.vigesimal_teen(n),
+        }
+    }
+
+    /// Counts the scores: "ugain", "deugain", "trigain", and "pedwar ugain", with "hanner
+    /// cant" for fifty
+    fn vigesimal_below_100(&self, n: u64) -> String {
+        let (score, rest) = match n {
+            0..=19 => return self.vigesimal_below_20(n),
+            20..=39 => ("ugain", n - 20),
+            40..=49 => ("deugain", n - 40),
+            50..=59 => ("hanner cant", n - 50),
+            60..=79 => ("trigain", n - 60),
+            _ => ("pedwar ugain", n - 80),
+        };
+        match (rest, score) {
+            (0, _) => score.to_string(),
+            (_, "ugain") => format!("{} ar hugain", self.vigesimal_below_20(rest)),
+            (_, "hanner cant") => format!("{} ar hanner cant", self.unit(rest)),
+            _ => welsh_and(&self.vigesimal_below_20(rest), score),
+        }
+    }
+
+    /// Shortens "pump" and "chwech" before a noun, as in "pum mil"
+    fn before_noun(words: String) -> String {
+        if let Some(stem) = words.strip_suffix("pump") {
+            format!("{}pum", stem)
+        } else if let Some(stem) = words.strip_suffix("chwech") {
+            format!("{}chwe", stem)
+        } else {
+            words
+        }
+    }
+}
+
+impl Language for Welsh {
+    fn zero(&self) -> String {
+        "dim".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "", "un", "dau", "tri", "pedwar", "pump", "chwech", "saith", "wyth", "naw",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        match (self.system, n) {
+            (WelshSystem::Decimal, 10) => "deg".to_string(),
+            (WelshSystem::Decimal, _) => format!("un deg {}", self.unit(n - 10)),
+            (WelshSystem::Vigesimal, _) => self.vigesimal_teen(n),
+        }
+    }
+
+    fn tens(&self, n: u64) -> String {
+        match self.system {
+            WelshSystem::Decimal => [
+                "dau ddeg",
+                "tri deg",
+                "pedwar deg",
+                "pum deg",
+                "chwe deg",
+                "saith deg",
+                "wyth deg",
+                "naw deg",
+            ][n as usize / 10 - 2]
+                .to_string(),
+            WelshSystem::Vigesimal => self.vigesimal_below_100(n),
+        }
+    }
+
+    /// "mil" is feminine, so two thousand is "dwy fil"; the larger scale words follow the
+    /// short scale
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        let word = [
+            "mil",
+            "miliwn",
+            "biliwn",
+            "triliwn",
+            "cwadriliwn",
+            "cwintiliwn",
+            "sextiliwn",
+            "septiliwn",
+            "octiliwn",
+            "noniliwn",
+            "deciliwn",
+            "undeciliwn",
+        ][group - 1];
+        match count {
+            // the soft mutation after "dau" and "dwy"
+            2 => format!("f{}", &word[1..]),
+            _ => word.to_string(),
+        }
+    }
+
+    fn negative_word(&self) -> String {
+        "minws".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        match self.system {
+            WelshSystem::Decimal => format!("{} {}", self.tens(tens), self.unit(unit)),
+            WelshSystem::Vigesimal => self.vigesimal_below_100(tens + unit),
+        }
+    }
+
+    fn under_100(&self, n: u64) -> String {
+        match self.system {
+            WelshSystem::Vigesimal => self.vigesimal_below_100(n),
+            WelshSystem::Decimal => match n {
+                1..=9 => self.unit(n),
+                10..=19 => self.teen(n),
+                _ if n % 10 == 0 => self.tens(n),
+                _ => self.join_tens(n - n % 10, n % 10),
+            },
+        }
+    }
+
+    fn hundreds(&self, n: u64) -> String {
+        [
+            "cant",
+            "dau gant",
+            "tri chant",
+            "pedwar cant",
+            "pum cant",
+            "chwe chant",
+            "saith cant",
+            "wyth cant",
+            "naw cant",
+        ][n as usize / 100 - 1]
+            .to_string()
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        welsh_and(&self.hundreds(hundreds), &self.under_100(rest))
+    }
+
+    /// "dwy", "tair", and "pedair" before the feminine "mil"
+    fn group_count(&self, count: u64, group: usize) -> String {
+        match (group, count) {
+            (1, 2) => "dwy".to_string(),
+            (1, 3) => "tair".to_string(),
+            (1, 4) => "pedair".to_string(),
+            (0, _) => self.under_1000(count),
+            _ => Self::before_noun(self.under_1000(count)),
+        }
+    }
+
+    /// "mil" and "miliwn" rather than "un mil" and "un miliwn"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match count {
+            1 => scale,
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+}
+
+/// Irish, using the counting forms ("a haon", "a dó", ...) and mutating the scale words
+/// after the numbers 2 to 10, as in "dhá mhíle"
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, Irish};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(2_021, &Irish, &FormatOptions::new()),
+///     "dhá mhíle fiche a haon".to_string()
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Irish;
+
+impl Irish {
+    /// The form of the numbers 2 to 10 used before a noun
+    fn before_noun(n: u64) -> &'static str {
+        [
+            "", "aon", "dhá", "trí", "ceithre", "cúig", "sé", "seacht", "ocht", "naoi", "deich",
+        ][n as usize]
+    }
+
+    /// Lenites `word` after 2 to 6, or eclipses it after 7 to 10
+    fn mutate(n: u64, word: &str) -> String {
+        let first = word.chars().next().unwrap_or_default();
+        match (n, first) {
+            (2..=6, 'b' | 'c' | 'd' | 'f' | 'g' | 'm' | 'p' | 't') => {
+                format!("{}h{}", first, &word[first.len_utf8()..])
+            }
+            (7..=10, 'b') => format!("m{}", word),
+            (7..=10, 'c') => format!("g{}", word),
+            (7..=10, 'd' | 'g') => format!("n{}", word),
+            (7..=10, 'p') => format!("b{}", word),
+            (7..=10, 't') => format!("d{}", word),
+            _ => word.to_string(),
+        }
+    }
+}
+
+impl Language for Irish {
+    fn zero(&self) -> String {
+        "náid".to_string()
+    }
+
+    fn unit(&self, n: u64) -> String {
+        [
+            "",
+            "a haon",
+            "a dó",
+            "a trí",
+            "a ceathair",
+            "a cúig",
+            "a sé",
+            "a seacht",
+            "a hocht",
+            "a naoi",
+        ][n as usize]
+            .to_string()
+    }
+
+    fn teen(&self, n: u64) -> String {
+        match n {
+            10 => "a deich".to_string(),
+            12 => "a dó dhéag".to_string(),
+            _ => format!("{} déag", self.unit(n - 10)),
+        }
+    }
+
+    fn tens(&self, n: u64) -> String {
+        [
+            "fiche", "tríocha", "daichead", "caoga", "seasca", "seachtó", "ochtó", "nócha",
+        ][n as usize / 10 - 2]
+            .to_string()
+    }
+
+    /// The short-scale nouns, mutated after 2 to 10
+    fn scale_word(&self, group: usize, count: u64) -> String {
+        let word = [
+            "míle",
+            "milliún",
+            "billiún",
+            "trilliún",
+            "cuaidrilliún",
+            "cuintilliún",
+            "seisilliún",
+            "seiptilliún",
+            "ochtilliún",
+            "nonilliún",
+            "deicilliún",
+            "undeicilliún",
+        ][group - 1];
+        Self::mutate(count, word)
+    }
+
+    fn negative_word(&self) -> String {
+        "míneas".to_string()
+    }
+
+    fn join_tens(&self, tens: u64, unit: u64) -> String {
+        format!("{} {}", self.tens(tens), self.unit(unit))
+    }
+
+    /// "céad", then the number before it with "céad" mutated, as in "trí chéad" and
+    /// "seacht gcéad"
+    fn hundreds(&self, n: u64) -> String {
+        match n / 100 {
+            1 => "céad".to_string(),
+            k => format!("{} {}", Self::before_noun(k), Self::mutate(k, "céad")),
+        }
+    }
+
+    fn join_hundreds(&self, hundreds: u64, rest: u64) -> String {
+        format!("{} agus {}", self.hundreds(hundreds), self.under_100(rest))
+    }
+
+    /// The forms used before a noun ("dhá", "trí", ...) for counts up to ten
+    fn group_count(&self, count: u64, group: usize) -> String {
+        match (group, count) {
+            (1.., 1..=10) => Self::before_noun(count).to_string(),
+            _ => self.under_1000(count),
+        }
+    }
+
+    /// "míle" and "milliún" rather than "aon mhíle" and "aon mhilliún"
+    fn scaled_group(&self, count: u64, group: usize, scale: String) -> String {
+        match count {
+            1 => scale,
+            _ => format!("{} {}", self.group_count(count, group), scale),
+        }
+    }
+}
+
+/// Joins groups with spaces, except after a thousands group ending in one of
+/// `thousands`, which is fused with the rest of the number below a million
+fn join_fused(groups: Vec<String>, thousands: &[&str]) -> String {
+    let mut words = String::new();
+    for group in groups {
+        if !words.is_empty() && !thousands.iter().any(|t| words.ends_with(t)) {
+            words.push(' ');
+        }
+        words.push_str(&group);
+    }
+    words
+}
+
+/// Every code `language_from_code` accepts, in alphabetical order
+pub const LANGUAGE_CODES: [&str; 29] = [
+    "ar",
+    "cy",
+    "cy-traditional",
+    "da",
+    "de",
+    "en",
+    "eo",
+    "es",
+    "fr",
+    "ga",
+    "hi",
+    "it",
+    "ja",
+    "ja-kana",
+    "ko",
+    "ko-native",
+    "nb",
+    "nl",
+    "no",
+    "pl",
+    "pt",
+    "pt-br",
+    "pt-pt",
+    "ru",
+    "sv",
+    "tr",
+    "uk",
+    "zh",
+    "zh-daxie",
+];
+
+/// Looks up a language by its ISO 639-1 code, e.g. "en"
+pub fn language_from_code(code: &str) -> Option<Box<dyn Language>> {
+    match code {
+        "en" => Some(Box::new(English)),
+        "eo" => Some(Box::new(Esperanto)),
+        "es" => Some(Box::new(Spanish::new(Gender::Masculine))),
+        "fr" => Some(Box::new(French)),
+        "ar" => Some(Box::new(Arabic::new(Gender::Masculine))),
+        "cy" => Some(Box::new(Welsh::new(WelshSystem::Decimal))),
+        "cy-traditional" => Some(Box::new(Welsh::new(WelshSystem::Vigesimal))),
+        "da" => Some(Box::new(Danish)),
+        "de" => Some(Box::new(German)),
+        "ga" => Some(Box::new(Irish)),
+        "hi" => Some(Box::new(Hindi)),
+        "it" => Some(Box::new(Italian)),
+        "ja" => Some(Box::new(Japanese::new(JapaneseScript::Kanji))),
+        "ja-kana" => Some(Box::new(Japanese::new(JapaneseScript::Kana))),
+        "ko" => Some(Box::new(Korean::new(KoreanNumerals::Sino))),
+        "ko-native" => Some(Box::new(Korean::new(KoreanNumerals::Native))),
+        "nb" | "no" => Some(Box::new(Norwegian)),
+        "nl" => Some(Box::new(Dutch)),
+        "pl" => Some(Box::new(Polish)),
+        "pt" | "pt-pt" => Some(Box::new(Portuguese::new(
+            PortugueseVariant::Portugal,
+            Gender::Masculine,
+        ))),
+        "pt-br" => Some(Box::new(Portuguese::new(
+            PortugueseVariant::Brazil,
+            Gender::Masculine,
+        ))),
+        "ru" => Some(Box::new(Russian::new(Gender::Masculine))),
+        "sv" => Some(Box::new(Swedish)),
+        "tr" => Some(Box::new(Turkish)),
+        "uk" => Some(Box::new(Ukrainian::new(Gender::Masculine))),
+        "zh" => Some(Box::new(Chinese::new(ChineseNumerals::Standard))),
+        "zh-daxie" => Some(Box::new(Chinese::new(ChineseNumerals::Financial))),
+        _ => None,
+    }
+}
+
+/// Convert an unsigned integer to words in the given language
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_in, English};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_in(2_859, &English, &FormatOptions::new()),
+///     "two thousand, eight-hundred and fifty-nine".to_string()
+/// );
+/// ```
+pub fn to_word_in(x: u128, language: &dyn Language, options: &FormatOptions) -> String {
+    options.apply_case(language.cardinal_with(x, options))
+}
+
+/// Convert a signed integer to words in the given language
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::languages::{to_word_signed_in, English};
+/// use numbers_into_words::FormatOptions;
+/// assert_eq!(
+///     to_word_signed_in(-12, &English, &FormatOptions::new()),
+///     "negative twelve".to_string()
+/// );
+/// ```
+pub fn to_word_signed_in(x: i128, language: &dyn Language, options: &FormatOptions) -> String {
+    let words = language.cardinal_with(x.unsigned_abs(), options);
+    options.apply_case(if x < 0 {
+        format!("{} {}", language.negative_word(), words)
+    } else {
+        words
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion_to_words::Case;
+
+    /// English words with every rule left at its default
+    struct Plain;
+
+    impl Language for Plain {
+        fn zero(&self) -> String {
+            English.zero()
+        }
+
+        fn unit(&self, n: u64) -> String {
+            English.unit(n)
+        }
+
+        fn teen(&self, n: u64) -> String {
+            English.teen(n)
+        }
+
+        fn tens(&self, n: u64) -> String {
+            English.tens(n)
+        }
+
+        fn scale_word(&self, group: usize, count: u64) -> String {
+            English.scale_word(group, count)
+        }
+
+        fn negative_word(&self) -> String {
+            "minus".to_string()
+        }
+    }
+
+    #[test]
+    fn test_default_algorithm() {
+        let options = FormatOptions::new();
+        assert_eq!(
+            to_word_in(345_612, &Plain, &options),
+            String::from("three hundred forty-five thousand six hundred twelve")
+        );
+        assert_eq!(to_word_in(0, &Plain, &options), String::from("zero"));
+        assert_eq!(
+            to_word_signed_in(-1_000_019, &Plain, &options),
+            String::from("minus one million nineteen")
+        );
+        assert_eq!(
+            to_word_in(u128::MAX, &Plain, &options),
+            String::from(
+                "three hundred forty undecillion two hundred eighty-two decillion \
+                 three hundred sixty-six nonillion nine hundred twenty octillion \
+                 nine hundred thirty-eight septillion four hundred sixty-three sextillion \
+                 four hundred sixty-three quintillion three hundred seventy-four \
+                 quadrillion six hundred seven trillion four hundred thirty-one billion \
+                 seven hundred sixty-eight million two hundred eleven thousand \
+                 four hundred fifty-five"
+            )
+        );
+    }
+
+    #[test]
+    fn test_english() {
+        for x in [
+            0,
+            7,
+            19,
+            100,
+            101,
+            999,
+            1_000,
+            305_112,
+            u64::MAX as u128,
+            u128::MAX,
+        ] {
+            assert_eq!(
+                to_word_in(x, &English, &FormatOptions::new()),
+                to_word_u128(x, AndBehavior::All)
+            );
+            assert_eq!(English.cardinal(x), to_word_u128(x, AndBehavior::All));
+        }
+        for n in [1, 13, 40, 99, 100, 345, 999] {
+            assert_eq!(English.under_1000(n), to_word(n, AndBehavior::All));
+        }
+        assert_eq!(
+            to_word_in(420, &English, &FormatOptions::new().case(Case::Title)),
+            String::from("Four-Hundred and Twenty")
+        );
+        assert!(language_from_code("en").is_some());
+        assert!(language_from_code("xx").is_none());
+        assert!(LANGUAGE_CODES
+            .iter()
+            .all(|code| language_from_code(code).is_some()));
+        assert!(LANGUAGE_CODES.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_french() {
+        let cases: [(u128, &str); 24] = [
+            (0, "zéro"),
+            (17, "dix-sept"),
+            (21, "vingt et un"),
+            (22, "vingt-deux"),
+            (61, "soixante et un"),
+            (70, "soixante-dix"),
+            (71, "soixante et onze"),
+            (77, "soixante-dix-sept"),
+            (80, "quatre-vingts"),
+            (81, "quatre-vingt-un"),
+            (91, "quatre-vingt-onze"),
+            (99, "quatre-vingt-dix-neuf"),
+            (100, "cent"),
+            (101, "cent un"),
+            (200, "deux cents"),
+            (201, "deux cent un"),
+            (1_000, "mille"),
+            (2_000, "deux mille"),
+            (80_000, "quatre-vingt mille"),
+            (300_000, "trois cent mille"),
+            (1_000_000, "un million"),
+            (200_000_000, "deux cents millions"),
+            (1_000_000_000, "un milliard"),
+            (3_000_000_000_000, "trois billions"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(French.cardinal(x), String::from(words));
+        }
+        assert!(French
+            .cardinal(u128::MAX)
+            .starts_with("trois cent quarante sextillions deux cent quatre-vingt-deux"));
+    }
+
+    #[test]
+    fn test_arabic() {
+        let ar = Arabic::new(Gender::Masculine);
+        let cases: [(u128, &str); 16] = [
+            (0, "صفر"),
+            (3, "ثلاثة"),
+            (11, "أحد عشر"),
+            (21, "واحد وعشرون"),
+            (100, "مائة"),
+            (200, "مائتان"),
+            (345, "ثلاثمائة وخمسة وأربعون"),
+            (1_000, "ألف"),
+            (2_000, "ألفان"),
+            (3_000, "ثلاثة آلاف"),
+            (11_000, "أحد عشر ألفا"),
+            (100_000, "مائة ألف"),
+            (1_000_001, "مليون وواحد"),
+            (2_000_000, "مليونان"),
+            (5_000_000_000, "خمسة مليارات"),
+            (2_000_000_000_000, "تريليونان"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(ar.cardinal(x), String::from(words));
+        }
+        let feminine = Arabic::new(Gender::Feminine);
+        assert_eq!(feminine.cardinal(2), String::from("اثنتان"));
+        assert_eq!(feminine.cardinal(12), String::from("اثنتا عشرة"));
+        assert_eq!(feminine.cardinal(3_003), String::from("ثلاثة آلاف وثلاث"));
+    }
+
+    #[test]
+    fn test_chinese() {
+        let standard = Chinese::new(ChineseNumerals::Standard);
+        let financial = Chinese::new(ChineseNumerals::Financial);
+        let cases: [(u128, &str, &str); 13] = [
+            (0, "零", "零"),
+            (10, "十", "壹拾"),
+            (15, "十五", "壹拾伍"),
+            (115, "一百一十五", "壹佰壹拾伍"),
+            (1_005, "一千零五", "壹仟零伍"),
+            (1_050, "一千零五十", "壹仟零伍拾"),
+            (10_500, "一万零五百", "壹万零伍佰"),
+            (101_000, "十万一千", "壹拾万壹仟"),
+            (100_000_001, "一亿零一", "壹亿零壹"),
+            (100_001_000, "一亿零一千", "壹亿零壹仟"),
+            (120_000_000, "一亿二千万", "壹亿贰仟万"),
+            (2_0000_0000_0000, "二兆", "贰兆"),
+            (30_0000_0015, "三十亿零一十五", "叁拾亿零壹拾伍"),
+        ];
+        for (x, standard_words, financial_words) in cases {
+            assert_eq!(standard.cardinal(x), String::from(standard_words));
+            assert_eq!(financial.cardinal(x), String::from(financial_words));
+        }
+    }
+
+    #[test]
+    fn test_dutch() {
+        let cases: [(u128, &str); 15] = [
+            (0, "nul"),
+            (1, "een"),
+            (21, "eenentwintig"),
+            (22, "tweeëntwintig"),
+            (83, "drieëntachtig"),
+            (88, "achtentachtig"),
+            (100, "honderd"),
+            (101, "honderdeen"),
+            (999, "negenhonderdnegenennegentig"),
+            (1_000, "duizend"),
+            (1_001, "duizend een"),
+            (100_000, "honderdduizend"),
+            (1_000_000, "een miljoen"),
+            (3_000_000_000, "drie miljard"),
+            (2_000_002_000, "twee miljard tweeduizend"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(Dutch.cardinal(x), String::from(words));
+        }
+    }
+
+    #[test]
+    fn test_german() {
+        let cases: [(u128, &str); 16] = [
+            (0, "null"),
+            (1, "eins"),
+            (12, "zwölf"),
+            (21, "einundzwanzig"),
+            (30, "dreißig"),
+            (101, "einhunderteins"),
+            (999, "neunhundertneunundneunzig"),
+            (1_000, "eintausend"),
+            (1_001, "eintausendeins"),
+            (21_000, "einundzwanzigtausend"),
+            (101_000, "einhunderteintausend"),
+            (1_000_000, "eine Million"),
+            (2_000_001, "zwei Millionen eins"),
+            (201_000_000, "zweihunderteine Millionen"),
+            (1_000_000_000, "eine Milliarde"),
+            (3_000_004_000_000, "drei Billionen vier Millionen"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(German.cardinal(x), String::from(words));
+        }
+        assert_eq!(
+            German.cardinal(7_000_020_000),
+            String::from("sieben Milliarden zwanzigtausend")
+        );
+    }
+
+    #[test]
+    fn test_hindi() {
+        let cases: [(u128, &str); 10] = [
+            (0, "शून्य"),
+            (19, "उन्नीस"),
+            (45, "पैंतालीस"),
+            (99, "निन्यानबे"),
+            (100, "एक सौ"),
+            (1_000, "एक हज़ार"),
+            (100_000, "एक लाख"),
+            (10_000_000, "एक करोड़"),
+            (250_007_000, "पच्चीस करोड़ सात हज़ार"),
+            (1_000_000_000_000, "एक लाख करोड़"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(Hindi.cardinal(x), String::from(words));
+        }
+        assert_eq!(Hindi.scale_word(5, 1), String::from("लाख करोड़"));
+    }
+
+    #[test]
+    fn test_italian() {
+        let cases: [(u128, &str); 19] = [
+            (3, "tre"),
+            (13, "tredici"),
+            (21, "ventuno"),
+            (23, "ventitré"),
+            (28, "ventotto"),
+            (38, "trentotto"),
+            (100, "cento"),
+            (103, "centotré"),
+            (108, "centotto"),
+            (180, "centottanta"),
+            (1_000, "mille"),
+            (1_003, "milletré"),
+            (2_000, "duemila"),
+            (23_000, "ventitremila"),
+            (1_000_000, "un milione"),
+            (2_000_000, "due milioni"),
+            (33_000_000, "trentatré milioni"),
+            (1_000_000_000, "un miliardo"),
+            (5_000_001_000, "cinque miliardi mille"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(Italian.cardinal(x), String::from(words));
+        }
+    }
+
+    #[test]
+    fn test_japanese() {
+        let kanji = Japanese::new(JapaneseScript::Kanji);
+        let kana = Japanese::new(JapaneseScript::Kana);
+        let cases: [(u128, &str, &str); 14] = [
+            (0, "零", "れい"),
+            (10, "十", "じゅう"),
+            (14, "十四", "じゅうよん"),
+            (47, "四十七", "よんじゅうなな"),
+            (300, "三百", "さんびゃく"),
+            (600, "六百", "ろっぴゃく"),
+            (800, "八百", "はっぴゃく"),
+            (3_000, "三千", "さんぜん"),
+            (8_000, "八千", "はっせん"),
+            (10_000, "一万", "いちまん"),
+            (10_000_000, "千万", "せんまん"),
+            (100_000_000, "一億", "いちおく"),
+            (1_000_000_000_000, "一兆", "いっちょう"),
+            (10_0000_0000_0000, "十兆", "じゅっちょう"),
+        ];
+        for (x, kanji_words, kana_words) in cases {
+            assert_eq!(kanji.cardinal(x), String::from(kanji_words));
+            assert_eq!(kana.cardinal(x), String::from(kana_words));
+        }
+        assert_eq!(
+            kana.cardinal(6_0000_0000_0000_0000),
+            String::from("ろっけい")
+        );
+        assert_eq!(
+            kanji.cardinal(12_3456_7890),
+            String::from("十二億三千四百五十六万七千八百九十")
+        );
+    }
+
+    #[test]
+    fn test_korean() {
+        let sino = Korean::new(KoreanNumerals::Sino);
+        let native = Korean::new(KoreanNumerals::Native);
+        let cases: [(u128, &str, &str); 9] = [
+            (0, "영", "영"),
+            (1, "일", "하나"),
+            (10, "십", "열"),
+            (21, "이십일", "스물하나"),
+            (99, "구십구", "아흔아홉"),
+            (110, "백십", "백열"),
+            (1_111, "천백십일", "천백열하나"),
+            (10_000, "만", "만"),
+            (100_020_000, "일억 이만", "일억 이만"),
+        ];
+        for (x, sino_words, native_words) in cases {
+            assert_eq!(sino.cardinal(x), String::from(sino_words));
+            assert_eq!(native.cardinal(x), String::from(native_words));
+        }
+    }
+
+    #[test]
+    fn test_polish() {
+        let cases: [(u128, &str); 15] = [
+            (0, "zero"),
+            (12, "dwanaście"),
+            (21, "dwadzieścia jeden"),
+            (200, "dwieście"),
+            (1_000, "tysiąc"),
+            (2_000, "dwa tysiące"),
+            (5_000, "pięć tysięcy"),
+            (12_000, "dwanaście tysięcy"),
+            (21_000, "dwadzieścia jeden tysięcy"),
+            (24_000, "dwadzieścia cztery tysiące"),
+            (1_000_000, "milion"),
+            (3_000_000, "trzy miliony"),
+            (15_000_000, "piętnaście milionów"),
+            (2_000_000_000, "dwa miliardy"),
+            (1_000_000_000_000, "bilion"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(Polish.cardinal(x), String::from(words));
+        }
+    }
+
+    #[test]
+    fn test_portuguese() {
+        let brazil = Portuguese::new(PortugueseVariant::Brazil, Gender::Masculine);
+        let portugal = Portuguese::new(PortugueseVariant::Portugal, Gender::Masculine);
+        let cases: [(u128, &str, &str); 14] = [
+            (16, "dezesseis", "dezasseis"),
+            (23, "vinte e três", "vinte e três"),
+            (100, "cem", "cem"),
+            (123, "cento e vinte e três", "cento e vinte e três"),
+            (1_000, "mil", "mil"),
+            (1_001, "mil e um", "mil e um"),
+            (1_100, "mil e cem", "mil e cem"),
+            (
+                1_234,
+                "mil duzentos e trinta e quatro",
+                "mil duzentos e trinta e quatro",
+            ),
+            (1_000_000, "um milhão", "um milhão"),
+            (1_000_100, "um milhão e cem", "um milhão e cem"),
+            (
+                1_500_000,
+                "um milhão e quinhentos mil",
+                "um milhão e quinhentos mil",
+            ),
+            (1_000_000_000, "um bilhão", "mil milhões"),
+            (2_000_000_000, "dois bilhões", "dois mil milhões"),
+            (1_000_000_000_000, "um trilhão", "um bilião"),
+        ];
+        for (x, br, pt) in cases {
+            assert_eq!(brazil.cardinal(x), String::from(br));
+            assert_eq!(portugal.cardinal(x), String::from(pt));
+        }
+        let feminine = Portuguese::new(PortugueseVariant::Brazil, Gender::Feminine);
+        assert_eq!(
+            feminine.cardinal(202_222),
+            String::from("duzentas e duas mil duzentas e vinte e duas")
+        );
+        assert_eq!(feminine.cardinal(2_000_000), String::from("dois milhões"));
+    }
+
+    #[test]
+    fn test_russian() {
+        let ru = Russian::new(Gender::Masculine);
+        let cases: [(u128, &str); 14] = [
+            (0, "ноль"),
+            (1, "один"),
+            (11, "одиннадцать"),
+            (42, "сорок два"),
+            (345, "триста сорок пять"),
+            (1_000, "одна тысяча"),
+            (2_000, "две тысячи"),
+            (5_000, "пять тысяч"),
+            (11_000, "одиннадцать тысяч"),
+            (21_000, "двадцать одна тысяча"),
+            (1_000_000, "один миллион"),
+            (3_000_000, "три миллиона"),
+            (12_000_000, "двенадцать миллионов"),
+            (22_000_000_000, "двадцать два миллиарда"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(ru.cardinal(x), String::from(words));
+        }
+        let feminine = Russian::new(Gender::Feminine);
+        assert_eq!(feminine.cardinal(2), String::from("две"));
+        assert_eq!(feminine.cardinal(12), String::from("двенадцать"));
+        assert_eq!(
+            feminine.cardinal(2_000_001),
+            String::from("два миллиона одна")
+        );
+    }
+
+    #[test]
+    fn test_scandinavian() {
+        let cases: [(u128, &str, &str, &str); 11] = [
+            (0, "noll", "null", "nul"),
+            (21, "tjugoett", "tjueen", "enogtyve"),
+            (55, "femtiofem", "femtifem", "femoghalvtreds"),
+            (90, "nittio", "nitti", "halvfems"),
+            (100, "etthundra", "hundre", "et hundrede"),
+            (
+                120,
+                "etthundratjugo",
+                "hundre og tjue",
+                "et hundrede og tyve",
+            ),
+            (1_000, "ettusen", "tusen", "et tusind"),
+            (1_001, "ettusenett", "tusen og en", "et tusind og en"),
+            (
+                2_500,
+                "tvåtusenfemhundra",
+                "to tusen fem hundre",
+                "to tusind fem hundrede",
+            ),
+            (1_000_000, "en miljon", "en million", "en million"),
+            (
+                3_000_000_000,
+                "tre miljarder",
+                "tre milliarder",
+                "tre milliarder",
+            ),
+        ];
+        for (x, sv, no, da) in cases {
+            assert_eq!(Swedish.cardinal(x), String::from(sv));
+            assert_eq!(Norwegian.cardinal(x), String::from(no));
+            assert_eq!(Danish.cardinal(x), String::from(da));
+        }
+    }
+
+    #[test]
+    fn test_turkish() {
+        let cases: [(u128, &str); 9] = [
+            (0, "sıfır"),
+            (10, "on"),
+            (19, "on dokuz"),
+            (100, "yüz"),
+            (101, "yüz bir"),
+            (1_000, "bin"),
+            (1_100, "bin yüz"),
+            (1_000_000, "bir milyon"),
+            (2_000_001_000, "iki milyar bin"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(Turkish.cardinal(x), String::from(words));
+        }
+    }
+
+    #[test]
+    fn test_ukrainian() {
+        let uk = Ukrainian::new(Gender::Masculine);
+        let cases: [(u128, &str); 10] = [
+            (0, "нуль"),
+            (11, "одинадцять"),
+            (200, "двісті"),
+            (1_000, "одна тисяча"),
+            (2_000, "дві тисячі"),
+            (5_000, "п'ять тисяч"),
+            (21_000, "двадцять одна тисяча"),
+            (1_000_000, "один мільйон"),
+            (4_000_000, "чотири мільйони"),
+            (11_000_000_000, "одинадцять мільярдів"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(uk.cardinal(x), String::from(words));
+        }
+        assert_eq!(
+            Ukrainian::new(Gender::Feminine).cardinal(32),
+            String::from("тридцять дві")
+        );
+    }
+
+    #[test]
+    fn test_welsh() {
+        let decimal = Welsh::new(WelshSystem::Decimal);
+        let vigesimal = Welsh::new(WelshSystem::Vigesimal);
+        let cases: [(u128, &str, &str); 16] = [
+            (0, "dim", "dim"),
+            (11, "un deg un", "un ar ddeg"),
+            (15, "un deg pump", "pymtheg"),
+            (17, "un deg saith", "dau ar bymtheg"),
+            (18, "un deg wyth", "deunaw"),
+            (20, "dau ddeg", "ugain"),
+            (31, "tri deg un", "un ar ddeg ar hugain"),
+            (42, "pedwar deg dau", "dau a deugain"),
+            (50, "pum deg", "hanner cant"),
+            (99, "naw deg naw", "pedwar ar bymtheg a phedwar ugain"),
+            (101, "cant ac un", "cant ac un"),
+            (300, "tri chant", "tri chant"),
+            (2_000, "dwy fil", "dwy fil"),
+            (5_000, "pum mil", "pum mil"),
+            (1_000_000, "miliwn", "miliwn"),
+            (2_000_000, "dau filiwn", "dau filiwn"),
+        ];
+        for (x, decimal_words, vigesimal_words) in cases {
+            assert_eq!(decimal.cardinal(x), String::from(decimal_words));
+            assert_eq!(vigesimal.cardinal(x), String::from(vigesimal_words));
+        }
+    }
+
+    #[test]
+    fn test_irish() {
+        let cases: [(u128, &str); 12] = [
+            (0, "náid"),
+            (1, "a haon"),
+            (12, "a dó dhéag"),
+            (13, "a trí déag"),
+            (21, "fiche a haon"),
+            (100, "céad"),
+            (300, "trí chéad"),
+            (800, "ocht gcéad"),
+            (105, "céad agus a cúig"),
+            (1_000, "míle"),
+            (3_000, "trí mhíle"),
+            (7_000_000_000, "seacht mbilliún"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(Irish.cardinal(x), String::from(words));
+        }
+    }
+
+    #[test]
+    fn test_esperanto() {
+        let cases: [(u128, &str); 12] = [
+            (0, "nul"),
+            (9, "naŭ"),
+            (10, "dek"),
+            (12, "dek du"),
+            (40, "kvardek"),
+            (99, "naŭdek naŭ"),
+            (100, "cent"),
+            (999, "naŭcent naŭdek naŭ"),
+            (1_000, "mil"),
+            (1_000_000, "unu miliono"),
+            (2_000_000_000, "du miliardoj"),
+            (3_000_000_001_000, "tri bilionoj mil"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(Esperanto.cardinal(x), String::from(words));
+        }
+        assert_eq!(
+            to_word_signed_in(-12, &Esperanto, &FormatOptions::new().case(Case::Upper)),
+            String::from("MINUS DEK DU")
+        );
+    }
+
+    #[test]
+    fn test_parse_cardinal() {
+        let languages: [Box<dyn Language>; 5] = [
+            Box::new(Spanish::new(Gender::Masculine)),
+            Box::new(French),
+            Box::new(German),
+            Box::new(Portuguese::new(
+                PortugueseVariant::Portugal,
+                Gender::Masculine,
+            )),
+            Box::new(Danish),
+        ];
+        for language in &languages {
+            for x in [0, 7, 21, 305, 1_001, 80_090, 2_500_000, 1_234_567_891] {
+                assert_eq!(
+                    language.parse_cardinal(&language.cardinal(x as u128)),
+                    Ok(x)
+                );
+            }
+        }
+        assert_eq!(German.parse_cardinal("Drei-Hundert Fünf"), Ok(305));
+        assert_eq!(
+            French.parse_cardinal("quatre-vingt-dix-sept mille"),
+            Ok(97_000)
+        );
+        assert_eq!(
+            French.parse_cardinal("cent cent"),
+            Err("Could not read the words.")
+        );
+        assert_eq!(German.parse_cardinal("--"), Err("No words."));
+        assert_eq!(English.parse_cardinal("Three-Hundred and Five"), Ok(305));
+        for code in LANGUAGE_CODES {
+            let language = language_from_code(code).unwrap();
+            let reader = GroupReader::new(&*language);
+            for x in [
+                0,
+                7,
+                21,
+                305,
+                1_001,
+                10_005,
+                80_090,
+                3_210_000,
+                1_234_567_891,
+            ] {
+                let words = language.cardinal(x as u128);
+                assert_eq!(
+                    language.parse_cardinal_with(&words, &reader),
+                    Ok(x),
+                    "{}: {}",
+                    code,
+                    words
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_spanish() {
+        let es = Spanish::new(Gender::Masculine);
+        let cases: [(u128, &str); 22] = [
+            (0, "cero"),
+            (1, "uno"),
+            (15, "quince"),
+            (16, "dieciséis"),
+            (21, "veintiuno"),
+            (22, "veintidós"),
+            (31, "treinta y uno"),
+            (45, "cuarenta y cinco"),
+            (100, "cien"),
+            (101, "ciento uno"),
+            (500, "quinientos"),
+            (777, "setecientos setenta y siete"),
+            (1_000, "mil"),
+            (1_001, "mil uno"),
+            (2_000, "dos mil"),
+            (31_000, "treinta y un mil"),
+            (100_000, "cien mil"),
+            (1_000_000, "un millón"),
+            (2_500_000, "dos millones quinientos mil"),
+            (1_000_000_000, "mil millones"),
+            (21_000_000_000_000, "veintiún billones"),
+            (1_000_000_000_000_000_000, "un trillón"),
+        ];
+        for (x, words) in cases {
+            assert_eq!(es.cardinal(x), String::from(words));
+        }
+        let fem = Spanish::new(Gender::Feminine);
+        assert_eq!(fem.cardinal(1), String::from("una"));
+        assert_eq!(fem.cardinal(201), String::from("doscientas una"));
+        assert_eq!(fem.cardinal(900_000), String::from("novecientas mil"));
+        assert_eq!(
+            fem.cardinal(200_000_000),
+            String::from("doscientos millones")
+        );
+        assert_eq!(
+            to_word_signed_in(-41, &fem, &FormatOptions::new()),
+            String::from("menos cuarenta y una")
+        );
+    }
+}
@@ -17,11 +17,33 @@ pub const COPYRIGHT_INFO: &str = "\
          https://chrisphan.com/\n\
          Licensed under MIT or APACHE 2.0";
 
+pub use conversion_to_words::ordinal_suffix;
+pub use conversion_to_words::to_ordinal_word;
 pub use conversion_to_words::to_word;
+pub use conversion_to_words::to_word_cow;
+pub use conversion_to_words::to_word_signed;
+pub use conversion_to_words::to_word_u128;
+pub use conversion_to_words::to_word_with;
+pub use conversion_to_words::write_word;
 pub use conversion_to_words::AndBehavior;
+pub use conversion_to_words::FormatOptions;
+pub use conversion_to_words::OrdinalWords;
+pub use conversion_to_words::SignedWords;
+pub use conversion_to_words::ToWords;
+pub use conversion_to_words::Words;
+pub use languages::to_word_in;
+pub use languages::Language;
+pub use numerals::parse_strict;
+pub use parse_words::from_words;
 pub use process_input::Config;
+pub use process_input::Status;
+pub use version_info::version;
+pub use version_info::VersionInfo;
 
 pub mod conversion_to_words {
+    use std::borrow::Cow;
+    use std::fmt;
+
     const AND_STR: &str = " and ";
 
     /// Signals when the word "and" should be used in an output
@@ -131,7 +153,7 @@ pub mod conversion_to_words {
     }
 
     impl AndBehavior {
-        fn insert_and(&self, group: usize, value: u64) -> &'static str {
+        fn insert_and(&self, group: usize, value: u128) -> &'static str {
             match (self, group, value) {
                 (Self::None, _, _) => " ",
                 (Self::LastGroup, 0, _) => AND_STR,
@@ -141,8 +163,396 @@ pub mod conversion_to_words {
                 (Self::All, _, _) => AND_STR,
             }
         }
+
+        /// Whether "and" goes before the tens and units of the group at index `group` of
+        /// `value`
+        pub(crate) fn uses_and(&self, group: usize, value: u128) -> bool {
+            self.insert_and(group, value) == AND_STR
+        }
+    }
+
+    /// Letter case of the output
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Case {
+        /// "four-hundred and twenty" (default behavior)
+        Lower,
+
+        /// "FOUR-HUNDRED AND TWENTY"
+        Upper,
+
+        /// "Four-Hundred and Twenty", capitalizing each part of a hyphenated word but leaving
+        /// "and" in lowercase
+        Title,
+
+        /// "Four-hundred and twenty"
+        Sentence,
+    }
+
+    impl Case {
+        fn apply(&self, words: String) -> String {
+            match self {
+                Self::Lower => words,
+                Self::Upper => words.to_uppercase(),
+                Self::Title => title_case(&words),
+                Self::Sentence => capitalize(&words),
+            }
+        }
+    }
+
+    /// Uppercases the first letter of `word`
+    fn capitalize(word: &str) -> String {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(c) => c.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+
+    /// Rebuilds `words`, passing each run of letters through `f` along with the text rebuilt
+    /// so far
+    fn map_words(words: &str, mut f: impl FnMut(&str, &str) -> String) -> String {
+        let mut out = String::with_capacity(words.len());
+        let mut rest = words;
+        while let Some(start) = rest.find(char::is_alphabetic) {
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let end = rest
+                .find(|c: char| !c.is_alphabetic())
+                .unwrap_or(rest.len());
+            let word = f(&out, &rest[..end]);
+            out.push_str(&word);
+            rest = &rest[end..];
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Capitalizes every run of letters except "and" (unless it comes first) and suffixes
+    /// following a numeral, as in "42nd"
+    fn title_case(words: &str) -> String {
+        map_words(words, |before, word| {
+            let after_numeral = before.ends_with(|c: char| c.is_ascii_digit());
+            if after_numeral || (word == "and" && !before.is_empty()) {
+                word.to_string()
+            } else {
+                capitalize(word)
+            }
+        })
+    }
+
+    /// Which hyphens to write within each group of three digits
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Hyphenation {
+        /// "three-hundred forty-seven" (default behavior)
+        All,
+
+        /// "three hundred forty-seven", as in most style guides
+        TensOnly,
+
+        /// "three hundred forty seven"
+        None,
+    }
+
+    /// Which numbers get their own scale words
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Scale {
+        /// Each power of a thousand has its own name, so 10^9 is "billion" (default behavior)
+        Short,
+
+        /// Each power of a million has its own name, so 10^9 is "thousand million" and 10^12
+        /// is "billion"; the thousands of a million run on into it, as in "one thousand
+        /// five-hundred million"
+        Long,
+
+        /// Like `Long`, but 10^9 is "milliard", 10^15 is "billiard", and so on
+        LongMilliard,
+
+        /// Groups of two digits above the thousands, as in 12,34,56,789: "twelve crore,
+        /// thirty-four lakh, fifty-six thousand, seven-hundred and eighty-nine"
+        Indian,
+
+        /// Groups of four digits named as in Japanese (man, oku, chō, ...), as in 1,2345,6789:
+        /// "one oku, two thousand, three-hundred and forty-five man, six thousand,
+        /// seven-hundred and eighty-nine"
+        Myriad,
+    }
+
+    /// Where the word for a negative number goes
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum NegativePlacement {
+        /// "negative five" (default behavior)
+        Before,
+
+        /// "five below zero"
+        After,
+    }
+
+    /// Controls the style of the words produced by `to_word_with`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::conversion_to_words::{to_word_with, Case, FormatOptions, Hyphenation};
+    /// use numbers_into_words::AndBehavior;
+    /// let options = FormatOptions::new()
+    ///     .and_behavior(AndBehavior::None)
+    ///     .hyphenation(Hyphenation::TensOnly)
+    ///     .commas(false)
+    ///     .case(Case::Upper);
+    /// assert_eq!(
+    ///     to_word_with(2_859, &options),
+    ///     "TWO THOUSAND EIGHT HUNDRED FIFTY-NINE".to_string()
+    /// );
+    /// ```
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct FormatOptions {
+        and_behavior: AndBehavior,
+        hyphenation: Hyphenation,
+        group_separator: String,
+        case: Case,
+        zero_word: String,
+        negative_word: String,
+        negative_placement: NegativePlacement,
+        article: bool,
+        scale: Scale,
+    }
+
+    impl Default for FormatOptions {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl FormatOptions {
+        /// The default style: "and" everywhere, "three-hundred", commas between groups, and
+        /// lowercase
+        pub fn new() -> Self {
+            Self {
+                and_behavior: AndBehavior::All,
+                hyphenation: Hyphenation::All,
+                group_separator: DEFAULT_GROUP_SEPARATOR.to_string(),
+                case: Case::Lower,
+                zero_word: DEFAULT_ZERO_WORD.to_string(),
+                negative_word: DEFAULT_NEGATIVE_WORD.to_string(),
+                negative_placement: NegativePlacement::Before,
+                article: false,
+                scale: Scale::Short,
+            }
+        }
+
+        /// Sets when the word "and" is used
+        pub fn and_behavior(mut self, and_behavior: AndBehavior) -> Self {
+            self.and_behavior = and_behavior;
+            self
+        }
+
+        /// Sets which hyphens are written, e.g. "three-hundred" or "three hundred"
+        pub fn hyphenation(mut self, hyphenation: Hyphenation) -> Self {
+            self.hyphenation = hyphenation;
+            self
+        }
+
+        /// Sets whether groups are joined by ", " (`true`) or by a space (`false`)
+        pub fn commas(self, commas: bool) -> Self {
+            self.group_separator(if commas { DEFAULT_GROUP_SEPARATOR } else { " " })
+        }
+
+        /// Sets the text placed between groups, e.g. " " for "two thousand eight-hundred" or
+        /// "\n" for one group per line
+        pub fn group_separator(mut self, separator: &str) -> Self {
+            self.group_separator = separator.to_string();
+            self
+        }
+
+        /// The text joining the groups, as set by `group_separator`
+        pub(crate) fn separator(&self) -> &str {
+            &self.group_separator
+        }
+
+        /// The word for zero, as set by `zero_word`
+        pub(crate) fn zero(&self) -> &str {
+            &self.zero_word
+        }
+
+        /// Sets the letter case of the output
+        pub fn case(mut self, case: Case) -> Self {
+            self.case = case;
+            self
+        }
+
+        /// Sets the word for zero, e.g. "nought", "nil", or "oh"
+        pub fn zero_word(mut self, zero_word: &str) -> Self {
+            self.zero_word = zero_word.to_string();
+            self
+        }
+
+        /// Sets the word marking a negative number, e.g. "minus" or "below zero"
+        pub fn negative_word(mut self, negative_word: &str) -> Self {
+            self.negative_word = negative_word.to_string();
+            self
+        }
+
+        /// Sets whether the negative word goes before or after the number
+        pub fn negative_placement(mut self, negative_placement: NegativePlacement) -> Self {
+            self.negative_placement = negative_placement;
+            self
+        }
+
+        /// Sets whether a leading "one" before "hundred" or a scale word becomes "a", as in
+        /// "a hundred and twenty" or "a thousand, five"
+        pub fn article(mut self, article: bool) -> Self {
+            self.article = article;
+            self
+        }
+
+        /// Sets which numbers get their own scale words
+        ///
+        /// The scale is only used by `to_word_with` and `to_word_signed_with`, since `restyle`
+        /// cannot regroup words that have already been written.
+        pub fn scale(mut self, scale: Scale) -> Self {
+            self.scale = scale;
+            self
+        }
+
+        fn use_article(&self, words: String) -> String {
+            if !self.article {
+                return words;
+            }
+            if let Some(rest) = words.strip_prefix("one-hundred") {
+                return format!("a hundred{}", rest);
+            }
+            match words.strip_prefix("one ") {
+                Some(rest)
+                    if POWERS_THOUSAND[1..]
+                        .iter()
+                        .chain(MILLIARD_POWERS_THOUSAND[1..].iter())
+                        .chain(INDIAN_POWERS[1..].iter())
+                        .any(|scale| rest.starts_with(scale.trim_start())) =>
+                {
+                    format!("a {}", rest)
+                }
+                _ => words,
+            }
+        }
+
+        /// Marks `words` as negative with the negative word, before or after them
+        pub(crate) fn negate(&self, words: String) -> String {
+            self.place_negative(format!("{} {}", DEFAULT_NEGATIVE_WORD, words))
+        }
+
+        fn place_negative(&self, words: String) -> String {
+            let Some(magnitude) = words
+                .strip_prefix(DEFAULT_NEGATIVE_WORD)
+                .and_then(|w| w.strip_prefix(' '))
+            else {
+                return words;
+            };
+            match self.negative_placement {
+                NegativePlacement::Before => format!("{} {}", self.negative_word, magnitude),
+                NegativePlacement::After => format!("{} {}", magnitude, self.negative_word),
+            }
+        }
+
+        fn hyphenate(&self, words: String) -> String {
+            match self.hyphenation {
+                Hyphenation::All => words,
+                Hyphenation::TensOnly => words.replace("-hundred", " hundred"),
+                Hyphenation::None => words.replace('-', " "),
+            }
+        }
+
+        /// Restyles words written in the default style, such as the output of `to_word`,
+        /// `to_ordinal_word`, or the digit-by-digit readings in `decimal` and `dotted`
+        ///
+        /// The "and" behavior is left as it was.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use numbers_into_words::conversion_to_words::{Case, FormatOptions};
+        /// use numbers_into_words::dotted::ip_to_words;
+        /// let options = FormatOptions::new().zero_word("oh").case(Case::Sentence);
+        /// assert_eq!(
+        ///     options.restyle(&ip_to_words("10.0.0.1").unwrap()),
+        ///     "Ten dot oh dot oh dot one".to_string()
+        /// );
+        /// ```
+        pub fn restyle(&self, words: &str) -> String {
+            self.apply_case(self.restyle_uncased(words))
+        }
+
+        /// Like `restyle`, but leaving the letter case as it was
+        pub(crate) fn restyle_uncased(&self, words: &str) -> String {
+            let words = self.hyphenate(self.use_article(words.to_string()));
+            let words = if self.group_separator == DEFAULT_GROUP_SEPARATOR {
+                words
+            } else {
+                words.replace(DEFAULT_GROUP_SEPARATOR, &self.group_separator)
+            };
+            let words = if self.zero_word == DEFAULT_ZERO_WORD {
+                words
+            } else {
+                map_words(&words, |_, word| match word {
+                    DEFAULT_ZERO_WORD => self.zero_word.clone(),
+                    w => w.to_string(),
+                })
+            };
+            self.place_negative(words)
+        }
+
+        /// Applies the letter case to words in lowercase
+        pub(crate) fn apply_case(&self, words: String) -> String {
+            self.case.apply(words)
+        }
+    }
+
+    const DEFAULT_GROUP_SEPARATOR: &str = ", ";
+
+    const DEFAULT_ZERO_WORD: &str = "zero";
+
+    const DEFAULT_NEGATIVE_WORD: &str = "negative";
+
+    /// Spellings of 0 to 999, generated by the build script
+    #[cfg(feature = "const-table")]
+    mod tables {
+        include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+    }
+
+    #[cfg(all(feature = "const-table", test))]
+    fn single_digit(x: u64) -> Result<String, &'static str> {
+        match x {
+            0..=9 => Ok(tables::UNDER_1000[x as usize].to_string()),
+            _ => Err("Value over 9."),
+        }
+    }
+
+    #[cfg(all(feature = "const-table", test))]
+    fn under_100(x: u64) -> Result<String, &'static str> {
+        match x {
+            0..=99 => Ok(tables::UNDER_1000[x as usize].to_string()),
+            _ => Err("Value over 99"),
+        }
+    }
+
+    #[cfg(feature = "const-table")]
+    fn under_1000(
+        x: u64,
+        group: usize,
+        and_behavior: AndBehavior,
+        full_value: u128,
+    ) -> Result<String, &'static str> {
+        let table = if and_behavior.insert_and(group, full_value) == AND_STR {
+            &tables::UNDER_1000_AND
+        } else {
+            &tables::UNDER_1000
+        };
+        match x {
+            0..=999 => Ok(table[x as usize].to_string()),
+            _ => Err("Value over 999."),
+        }
     }
 
+    #[cfg(not(feature = "const-table"))]
     fn single_digit(x: u64) -> Result<String, &'static str> {
         match x {
             0 => Ok("zero".to_string()),
@@ -159,6 +569,7 @@ pub mod conversion_to_words {
         }
     }
 
+    #[cfg(not(feature = "const-table"))]
     fn under_100(x: u64) -> Result<String, &'static str> {
         match x {
             0..=9 => single_digit(x),
@@ -184,11 +595,12 @@ pub mod conversion_to_words {
         }
     }
 
+    #[cfg(not(feature = "const-table"))]
     fn under_1000(
         x: u64,
         group: usize,
         and_behavior: AndBehavior,
-        full_value: u64,
+        full_value: u128,
     ) -> Result<String, &'static str> {
         match x {
             0..=99 => under_100(x),
@@ -206,14 +618,80 @@ pub mod conversion_to_words {
         }
     }
 
-    const POWERS_THOUSAND: [&str; 7] = [
+    /// Words for the digits
+    pub(crate) const UNITS: [&str; 10] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    /// Words for ten to nineteen
+    pub(crate) const TEENS: [&str; 10] = [
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+
+    /// Words for the multiples of ten, at the index of the tens digit
+    pub(crate) const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+
+    const POWERS_THOUSAND: [&str; 13] = [
+        "",
+        " thousand",
+        " million",
+        " billion",
+        " trillion",
+        " quadrillion",
+        " quintillion",
+        " sextillion",
+        " septillion",
+        " octillion",
+        " nonillion",
+        " decillion",
+        " undecillion",
+    ];
+
+    /// Names of the powers of a million in the long scale
+    const LONG_POWERS_MILLION: [&str; 7] = [
+        "",
+        " million",
+        " billion",
+        " trillion",
+        " quadrillion",
+        " quintillion",
+        " sextillion",
+    ];
+
+    /// Names of the powers of a thousand in the long scale with "milliard"
+    const MILLIARD_POWERS_THOUSAND: [&str; 13] = [
         "",
         " thousand",
         " million",
+        " milliard",
         " billion",
+        " billiard",
         " trillion",
+        " trilliard",
         " quadrillion",
+        " quadrilliard",
         " quintillion",
+        " quintilliard",
+        " sextillion",
+    ];
+
+    /// Names of the groups in the Indian numbering system; each crore starts the grouping over
+    const INDIAN_POWERS: [&str; 4] = ["", " thousand", " lakh", " crore"];
+
+    /// Names of the powers of ten thousand, romanized from Japanese
+    const MYRIAD_POWERS: [&str; 10] = [
+        "", " man", " oku", " chō", " kei", " gai", " jo", " jō", " kō", " kan",
     ];
 
     /// Convert a 64-bit unsigned integer to words
@@ -265,1100 +743,5793 @@ pub mod conversion_to_words {
     /// assert_eq!(to_word(0, AndBehavior::None), "zero".to_string());
     /// ```
     pub fn to_word(x: u64, and_behavior: AndBehavior) -> String {
-        if x == 0 {
-            single_digit(0).expect("under 10")
-        } else {
-            (0..7)
-                .map(|y| ((x / (10_u64).pow(3 * (6 - y as u32))) % 1000, 6 - y, x))
-                .filter(|(a, _, _)| *a != 0_u64)
-                .map(|(a, b, x)| {
-                    format!(
-                        "{}{}",
-                        under_1000(a, b, and_behavior, x).expect("under 1000"),
-                        POWERS_THOUSAND[b]
-                    )
-                })
-                .collect::<Vec<String>>()
-                .join(", ")
-        }
+        let mut words = String::new();
+        write_word(&mut words, x, and_behavior).expect("writing to a String cannot fail");
+        words
     }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-
-        #[test]
-        fn test_insert_and() {
-            let mut ab = AndBehavior::None;
-            assert_eq!(ab.insert_and(4, 5234234924233), " ");
-            assert_eq!(ab.insert_and(0, 1263953243921), " ");
-            assert_eq!(ab.insert_and(0, 432), " ");
-
-            ab = AndBehavior::LastGroup;
-            assert_eq!(ab.insert_and(4, 5234234924233), " ");
-            assert_eq!(ab.insert_and(0, 1263953243921), " and ");
-            assert_eq!(ab.insert_and(0, 432), " and ");
+    /// Words for 0 to 100, which `to_word_cow` borrows rather than composing
+    const SMALL_WORDS: [&str; 101] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+        "twenty",
+        "twenty-one",
+        "twenty-two",
+        "twenty-three",
+        "twenty-four",
+        "twenty-five",
+        "twenty-six",
+        "twenty-seven",
+        "twenty-eight",
+        "twenty-nine",
+        "thirty",
+        "thirty-one",
+        "thirty-two",
+        "thirty-three",
+        "thirty-four",
+        "thirty-five",
+        "thirty-six",
+        "thirty-seven",
+        "thirty-eight",
+        "thirty-nine",
+        "forty",
+        "forty-one",
+        "forty-two",
+        "forty-three",
+        "forty-four",
+        "forty-five",
+        "forty-six",
+        "forty-seven",
+        "forty-eight",
+        "forty-nine",
+        "fifty",
+        "fifty-one",
+        "fifty-two",
+        "fifty-three",
+        "fifty-four",
+        "fifty-five",
+        "fifty-six",
+        "fifty-seven",
+        "fifty-eight",
+        "fifty-nine",
+        "sixty",
+        "sixty-one",
+        "sixty-two",
+        "sixty-three",
+        "sixty-four",
+        "sixty-five",
+        "sixty-six",
+        "sixty-seven",
+        "sixty-eight",
+        "sixty-nine",
+        "seventy",
+        "seventy-one",
+        "seventy-two",
+        "seventy-three",
+        "seventy-four",
+        "seventy-five",
+        "seventy-six",
+        "seventy-seven",
+        "seventy-eight",
+        "seventy-nine",
+        "eighty",
+        "eighty-one",
+        "eighty-two",
+        "eighty-three",
+        "eighty-four",
+        "eighty-five",
+        "eighty-six",
+        "eighty-seven",
+        "eighty-eight",
+        "eighty-nine",
+        "ninety",
+        "ninety-one",
+        "ninety-two",
+        "ninety-three",
+        "ninety-four",
+        "ninety-five",
+        "ninety-six",
+        "ninety-seven",
+        "ninety-eight",
+        "ninety-nine",
+        "one-hundred",
+    ];
 
-            ab = AndBehavior::OnlyUnderThousand;
-            assert_eq!(ab.insert_and(4, 5234234924233), " ");
-            assert_eq!(ab.insert_and(0, 1263953243921), " ");
-            assert_eq!(ab.insert_and(0, 432), " and ");
+    /// Words for the powers of a thousand that fit in a `u64`, from 1000 up
+    const ROUND_WORDS: [&str; 6] = [
+        "one thousand",
+        "one million",
+        "one billion",
+        "one trillion",
+        "one quadrillion",
+        "one quintillion",
+    ];
 
-            ab = AndBehavior::All;
-            assert_eq!(ab.insert_and(4, 5234234924233), " and ");
-            assert_eq!(ab.insert_and(0, 1263953243921), " and ");
-            assert_eq!(ab.insert_and(0, 432), " and ");
+    /// Convert a 64-bit unsigned integer to words, borrowing them from a static table where
+    /// possible
+    ///
+    /// The words for 0 to 100 and for powers of a thousand (e.g. "one million") are
+    /// borrowed, as are those for anything under 1000 with the `const-table` feature; only
+    /// other values allocate. The words are the same as those from `to_word`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::{to_word_cow, AndBehavior};
+    /// use std::borrow::Cow;
+    /// assert_eq!(to_word_cow(42, AndBehavior::All), Cow::Borrowed("forty-two"));
+    /// assert_eq!(to_word_cow(1_000_000, AndBehavior::All), Cow::Borrowed("one million"));
+    /// assert_eq!(
+    ///     to_word_cow(1_001, AndBehavior::All),
+    ///     Cow::<str>::Owned("one thousand, one".to_string())
+    /// );
+    /// ```
+    pub fn to_word_cow(x: u64, and_behavior: AndBehavior) -> Cow<'static, str> {
+        if let Some(words) = usize::try_from(x).ok().and_then(|k| SMALL_WORDS.get(k)) {
+            return Cow::Borrowed(words);
         }
+        #[cfg(feature = "const-table")]
+        if x < 1000 {
+            let table = if and_behavior.uses_and(0, x as u128) {
+                &tables::UNDER_1000_AND
+            } else {
+                &tables::UNDER_1000
+            };
+            return Cow::Borrowed(table[x as usize]);
+        }
+        let (mut rest, mut power) = (x, 0);
+        while rest >= 1000 && rest % 1000 == 0 {
+            rest /= 1000;
+            power += 1;
+        }
+        match (rest, power) {
+            (1, 1..) => Cow::Borrowed(ROUND_WORDS[power - 1]),
+            _ => Cow::Owned(to_word(x, and_behavior)),
+        }
+    }
 
-        #[test]
-        fn test_single_digit() {
-            assert_eq!(single_digit(0).unwrap(), String::from("zero"));
-            assert_eq!(single_digit(5).unwrap(), String::from("five"));
-            assert!(single_digit(14).is_err());
+    /// Write the words for a 64-bit unsigned integer into `w`, without allocating
+    ///
+    /// The words are the same as those from `to_word`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::{write_word, AndBehavior};
+    /// use std::fmt::Write;
+    ///
+    /// let mut line = String::from("You have ");
+    /// write_word(&mut line, 2_859, AndBehavior::All).unwrap();
+    /// write!(line, " items").unwrap();
+    /// assert_eq!(
+    ///     line,
+    ///     "You have two thousand, eight-hundred and fifty-nine items".to_string()
+    /// );
+    /// ```
+    pub fn write_word<W: fmt::Write>(w: &mut W, x: u64, and_behavior: AndBehavior) -> fmt::Result {
+        if x == 0 {
+            return w.write_str(DEFAULT_ZERO_WORD);
+        }
+        let mut first = true;
+        // u64::MAX is in the quintillions, the group at index 6
+        for group in (0..=6).rev() {
+            let value = (x / 10_u64.pow(3 * group as u32)) % 1000;
+            if value == 0 {
+                continue;
+            }
+            if !first {
+                w.write_str(DEFAULT_GROUP_SEPARATOR)?;
+            }
+            first = false;
+            #[cfg(feature = "const-table")]
+            {
+                let table = if and_behavior.uses_and(group, x as u128) {
+                    &tables::UNDER_1000_AND
+                } else {
+                    &tables::UNDER_1000
+                };
+                w.write_str(table[value as usize])?;
+            }
+            #[cfg(not(feature = "const-table"))]
+            write_group(w, value, and_behavior.insert_and(group, x as u128))?;
+            w.write_str(POWERS_THOUSAND[group])?;
         }
+        Ok(())
+    }
 
-        #[test]
-        fn test_under_100() {
-            assert_eq!(under_100(3).unwrap(), String::from("three"));
-            assert_eq!(under_100(12).unwrap(), String::from("twelve"));
-            assert_eq!(under_100(19).unwrap(), String::from("nineteen"));
-            assert_eq!(under_100(20).unwrap(), String::from("twenty"));
-            assert_eq!(under_100(47).unwrap(), String::from("forty-seven"));
-            assert!(under_100(105).is_err());
+    /// Write the words for a group of three digits, from 1 to 999, putting `and` between the
+    /// hundreds and the rest
+    #[cfg(not(feature = "const-table"))]
+    fn write_group<W: fmt::Write>(w: &mut W, value: u64, and: &str) -> fmt::Result {
+        let (hundreds, rest) = (value / 100, value % 100);
+        if hundreds > 0 {
+            w.write_str(UNITS[hundreds as usize])?;
+            w.write_str("-hundred")?;
+            if rest > 0 {
+                w.write_str(and)?;
+            }
         }
+        match rest {
+            0 => {}
+            1..=9 => w.write_str(UNITS[rest as usize])?,
+            10..=19 => w.write_str(TEENS[rest as usize - 10])?,
+            _ => {
+                w.write_str(TENS[rest as usize / 10])?;
+                if rest % 10 != 0 {
+                    w.write_str("-")?;
+                    w.write_str(UNITS[rest as usize % 10])?;
+                }
+            }
+        }
+        Ok(())
+    }
 
-        #[test]
-        fn test_under_1000() {
-            assert_eq!(
-                under_1000(3, 0, AndBehavior::All, 3).unwrap(),
-                String::from("three")
-            );
-            assert_eq!(
-                under_1000(12, 0, AndBehavior::All, 12).unwrap(),
-                String::from("twelve")
+    /// Convert a 128-bit unsigned integer to words
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::{to_word_u128, AndBehavior};
+    /// assert_eq!(
+    ///     to_word_u128(7_000_000_000_000_000_000_000_012, AndBehavior::All),
+    ///     "seven septillion, twelve".to_string()
+    /// );
+    /// assert_eq!(
+    ///     to_word_u128(u128::MAX, AndBehavior::LastGroup),
+    ///     String::from(
+    ///         "three-hundred forty undecillion, two-hundred eighty-two decillion, \
+    ///          three-hundred sixty-six nonillion, nine-hundred twenty octillion, \
+    ///          nine-hundred thirty-eight septillion, four-hundred sixty-three sextillion, \
+    ///          four-hundred sixty-three quintillion, three-hundred seventy-four quadrillion, \
+    ///          six-hundred seven trillion, four-hundred thirty-one billion, \
+    ///          seven-hundred sixty-eight million, two-hundred eleven thousand, \
+    ///          four-hundred and fifty-five"
+    ///     )
+    /// );
+    /// ```
+    pub fn to_word_u128(x: u128, and_behavior: AndBehavior) -> String {
+        to_word_with(x, &FormatOptions::new().and_behavior(and_behavior))
+    }
+
+    /// Convert an unsigned integer to words in the style given by `options`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::conversion_to_words::{to_word_with, FormatOptions};
+    /// use numbers_into_words::conversion_to_words::Hyphenation;
+    /// assert_eq!(
+    ///     to_word_with(305_112, &FormatOptions::new().hyphenation(Hyphenation::TensOnly)),
+    ///     "three hundred and five thousand, one hundred and twelve".to_string()
+    /// );
+    /// assert_eq!(
+    ///     to_word_with(45, &FormatOptions::new().hyphenation(Hyphenation::None)),
+    ///     "forty five".to_string()
+    /// );
+    /// assert_eq!(
+    ///     to_word_with(305_112, &FormatOptions::new()),
+    ///     "three-hundred and five thousand, one-hundred and twelve".to_string()
+    /// );
+    /// ```
+    pub fn to_word_with(x: u128, options: &FormatOptions) -> String {
+        options.case.apply(uncased_words(x, options))
+    }
+
+    /// Words for `x` in the style given by `options`, except for the letter case
+    pub(crate) fn uncased_words(x: u128, options: &FormatOptions) -> String {
+        grouped_words(x, options, true)
+    }
+
+    /// Joins the groups of `x`, using the article (if enabled) only when `allow_article` is set
+    fn grouped_words(x: u128, options: &FormatOptions, allow_article: bool) -> String {
+        if x == 0 {
+            return options.zero_word.clone();
+        }
+        let mut words = String::new();
+        let mut named = true;
+        for (k, (a, b, suffix)) in scale_groups(x, options.scale).into_iter().enumerate() {
+            if k > 0 {
+                // The thousands of a larger unit run on into the rest of that unit, which
+                // names it ("one thousand five-hundred million"), with no separator between
+                words.push_str(if named { &options.group_separator } else { " " });
+            }
+            named = !(splits_unit(options.scale, b) && suffix == " thousand");
+            let group = format!("{}{}", group_words(a, b, options.and_behavior, x), suffix);
+            words.push_str(&options.hyphenate(if k == 0 && allow_article {
+                options.use_article(group)
+            } else {
+                group
+            }));
+        }
+        words
+    }
+
+    /// Whether the group with index `group` is the thousands of a power of a million in the
+    /// long scale
+    fn splits_unit(scale: Scale, group: usize) -> bool {
+        scale == Scale::Long && group % 2 == 1 && group > 1
+    }
+
+    /// Splits a number into its nonzero groups for the given scale, most significant first
+    ///
+    /// Each group is under 1000 and comes with its index (0 for the units, which is what
+    /// `AndBehavior::LastGroup` checks) and the scale words that follow it.
+    fn scale_groups(x: u128, scale: Scale) -> Vec<(u64, usize, String)> {
+        match scale {
+            Scale::Indian => indian_groups(x, 0, &INDIAN_POWERS),
+            Scale::Myriad => myriad_groups(x),
+            _ => nonzero_groups(x)
+                .into_iter()
+                .map(|(a, b)| (a, b, scale_suffix(x, b, scale)))
+                .collect(),
+        }
+    }
+
+    /// Groups in the Indian numbering system, with indices starting at `first_index`, named
+    /// with `powers` (the units, thousand, lakh, and crore suffixes)
+    pub(crate) fn indian_groups(
+        x: u128,
+        first_index: usize,
+        powers: &[&str; 4],
+    ) -> Vec<(u64, usize, String)> {
+        let crores = x / 10_000_000;
+        let mut groups = if crores == 0 {
+            Vec::new()
+        } else {
+            // The crore count is itself grouped, and named once after its last group; so is
+            // each tier of crores within it, so the group ending a tier is named twice
+            // ("one crore crore, five crore")
+            let mut high = indian_groups(crores, first_index + 3, powers);
+            let last = high.len() - 1;
+            for (k, group) in high.iter_mut().enumerate() {
+                if k == last || group.2.ends_with(powers[3]) {
+                    group.2.push_str(powers[3]);
+                }
+            }
+            high
+        };
+        let low = x % 10_000_000;
+        let lakhs = (low / 100_000) as u64;
+        let thousands = (low / 1000 % 100) as u64;
+        let units = (low % 1000) as u64;
+        for (value, k) in [(lakhs, 2), (thousands, 1), (units, 0)] {
+            if value != 0 {
+                groups.push((value, first_index + k, powers[k].to_string()));
+            }
+        }
+        groups
+    }
+
+    /// Groups of four digits, each split at the thousands so that the parts stay under 1000
+    fn myriad_groups(x: u128) -> Vec<(u64, usize, String)> {
+        (0..MYRIAD_POWERS.len())
+            .rev()
+            .flat_map(|m| {
+                let value = (x / 10_u128.pow(4 * m as u32) % 10_000) as u64;
+                let (thousands, units) = (value / 1000, value % 1000);
+                let mut parts = Vec::new();
+                if thousands != 0 {
+                    let name = if units == 0 { MYRIAD_POWERS[m] } else { "" };
+                    parts.push((thousands, 2 * m + 1, format!(" thousand{}", name)));
+                }
+                if units != 0 {
+                    parts.push((units, 2 * m, MYRIAD_POWERS[m].to_string()));
+                }
+                parts
+            })
+            .collect()
+    }
+
+    /// The scale words following the group of three digits with index `group` in `x`
+    fn scale_suffix(x: u128, group: usize, scale: Scale) -> String {
+        match scale {
+            Scale::Short => POWERS_THOUSAND[group].to_string(),
+            Scale::LongMilliard => MILLIARD_POWERS_THOUSAND[group].to_string(),
+            Scale::Indian | Scale::Myriad => {
+                unreachable!("Indian and myriad groups are not groups of three digits")
+            }
+            Scale::Long => {
+                let thousands = group % 2 == 1;
+                // A power of a million is named once, after the last nonzero group it covers
+                let last_in_million =
+                    !thousands || (x / 10_u128.pow(3 * (group as u32 - 1))) % 1000 == 0;
+                format!(
+                    "{}{}",
+                    if thousands { " thousand" } else { "" },
+                    if last_in_million {
+                        LONG_POWERS_MILLION[group / 2]
+                    } else {
+                        ""
+                    }
+                )
+            }
+        }
+    }
+
+    /// The word placed before the words for a negative number, a shorthand for
+    /// `FormatOptions::negative_word`
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum NegativePrefix {
+        /// "negative four-hundred and twelve"
+        Negative,
+
+        /// "minus four-hundred and twelve"
+        Minus,
+    }
+
+    impl NegativePrefix {
+        fn word(&self) -> &'static str {
+            match self {
+                Self::Negative => "negative",
+                Self::Minus => "minus",
+            }
+        }
+    }
+
+    /// Convert a 64-bit signed integer to words, using "negative" for values below zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::{to_word_signed, AndBehavior};
+    /// assert_eq!(
+    ///     to_word_signed(-412, AndBehavior::All),
+    ///     "negative four-hundred and twelve".to_string()
+    /// );
+    /// assert_eq!(
+    ///     to_word_signed(412, AndBehavior::All),
+    ///     "four-hundred and twelve".to_string()
+    /// );
+    /// assert_eq!(to_word_signed(0, AndBehavior::All), "zero".to_string());
+    /// ```
+    pub fn to_word_signed(x: i64, and_behavior: AndBehavior) -> String {
+        to_word_signed_prefixed(x, and_behavior, NegativePrefix::Negative)
+    }
+
+    /// Convert a 64-bit signed integer to words, using the given prefix for values below zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::conversion_to_words::{to_word_signed_prefixed, NegativePrefix};
+    /// use numbers_into_words::AndBehavior;
+    /// assert_eq!(
+    ///     to_word_signed_prefixed(-2_859, AndBehavior::None, NegativePrefix::Minus),
+    ///     "minus two thousand, eight-hundred fifty-nine".to_string()
+    /// );
+    /// assert_eq!(
+    ///     to_word_signed_prefixed(-2_859, AndBehavior::None, NegativePrefix::Negative),
+    ///     "negative two thousand, eight-hundred fifty-nine".to_string()
+    /// );
+    /// ```
+    pub fn to_word_signed_prefixed(
+        x: i64,
+        and_behavior: AndBehavior,
+        prefix: NegativePrefix,
+    ) -> String {
+        to_word_signed_with(
+            x,
+            &FormatOptions::new()
+                .and_behavior(and_behavior)
+                .negative_word(prefix.word()),
+        )
+    }
+
+    /// Conversion to words for the primitive integer types
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::{AndBehavior, ToWords};
+    /// assert_eq!(
+    ///     1234_u32.to_words(AndBehavior::All),
+    ///     "one thousand, two-hundred and thirty-four".to_string()
+    /// );
+    /// assert_eq!((-12_i8).to_words(AndBehavior::All), "negative twelve".to_string());
+    /// ```
+    pub trait ToWords {
+        /// Convert the value to words
+        fn to_words(&self, and_behavior: AndBehavior) -> String;
+    }
+
+    macro_rules! impl_to_words_unsigned {
+        ($($t:ty),*) => {
+            $(
+                impl ToWords for $t {
+                    fn to_words(&self, and_behavior: AndBehavior) -> String {
+                        to_word_u128(*self as u128, and_behavior)
+                    }
+                }
+            )*
+        };
+    }
+
+    macro_rules! impl_to_words_signed {
+        ($($t:ty),*) => {
+            $(
+                impl ToWords for $t {
+                    fn to_words(&self, and_behavior: AndBehavior) -> String {
+                        to_word_signed(*self as i64, and_behavior)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_to_words_unsigned!(u8, u16, u32, u64, usize, u128);
+    impl_to_words_signed!(i8, i16, i32, i64, isize);
+
+    impl ToWords for i128 {
+        fn to_words(&self, and_behavior: AndBehavior) -> String {
+            let words = to_word_u128(self.unsigned_abs(), and_behavior);
+            if *self < 0 {
+                format!("{} {}", DEFAULT_NEGATIVE_WORD, words)
+            } else {
+                words
+            }
+        }
+    }
+
+    /// Convert a 64-bit signed integer to words in the style given by `options`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::conversion_to_words::{
+    ///     to_word_signed_with, FormatOptions, NegativePlacement,
+    /// };
+    /// let options = FormatOptions::new()
+    ///     .negative_word("below zero")
+    ///     .negative_placement(NegativePlacement::After);
+    /// assert_eq!(
+    ///     to_word_signed_with(-5, &options),
+    ///     "five below zero".to_string()
+    /// );
+    /// assert_eq!(
+    ///     to_word_signed_with(-5, &FormatOptions::new().negative_word("minus")),
+    ///     "minus five".to_string()
+    /// );
+    /// ```
+    pub fn to_word_signed_with(x: i64, options: &FormatOptions) -> String {
+        options.case.apply(uncased_signed_words(x, options))
+    }
+
+    /// Words for `x` in the style given by `options`, except for the letter case
+    pub(crate) fn uncased_signed_words(x: i64, options: &FormatOptions) -> String {
+        // "negative a hundred" is not English, so the article is only used for positive values
+        let words = grouped_words(x.unsigned_abs() as u128, options, x >= 0);
+        if x < 0 {
+            options.place_negative(format!("{} {}", DEFAULT_NEGATIVE_WORD, words))
+        } else {
+            words
+        }
+    }
+
+    /// Splits a number into its nonzero groups of three digits, most significant first, each
+    /// paired with its group index (0 for the units, 1 for the thousands, ...)
+    pub(crate) fn nonzero_groups(x: u128) -> Vec<(u64, usize)> {
+        let top = POWERS_THOUSAND.len() - 1;
+        (0..=top)
+            .map(|y| {
+                (
+                    ((x / (10_u128).pow(3 * (top - y) as u32)) % 1000) as u64,
+                    top - y,
+                )
+            })
+            .filter(|(a, _)| *a != 0_u64)
+            .collect()
+    }
+
+    /// Words for a single group of three digits, without its scale word
+    pub(crate) fn group_words(
+        value: u64,
+        group: usize,
+        and_behavior: AndBehavior,
+        full_value: u128,
+    ) -> String {
+        under_1000(value, group, and_behavior, full_value).expect("under 1000")
+    }
+
+    /// Words for a single group of three digits, followed by its scale word (if any)
+    pub(crate) fn group_to_word(
+        value: u64,
+        group: usize,
+        and_behavior: AndBehavior,
+        full_value: u128,
+    ) -> String {
+        format!(
+            "{}{}",
+            group_words(value, group, and_behavior, full_value),
+            POWERS_THOUSAND[group]
+        )
+    }
+
+    /// The scale word for a group index, with a leading space (empty for the units group)
+    pub(crate) fn scale_word(group: usize) -> &'static str {
+        POWERS_THOUSAND[group]
+    }
+
+    /// Convert a 64-bit unsigned integer to ordinal words (e.g. "twenty-first")
+    ///
+    /// Only the last word changes, so compound numbers keep their cardinal head.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::{to_ordinal_word, AndBehavior};
+    /// assert_eq!(to_ordinal_word(42, AndBehavior::All), "forty-second".to_string());
+    /// assert_eq!(
+    ///     to_ordinal_word(102, AndBehavior::All),
+    ///     "one-hundred and second".to_string()
+    /// );
+    /// assert_eq!(
+    ///     to_ordinal_word(1_000_000, AndBehavior::All),
+    ///     "one millionth".to_string()
+    /// );
+    /// ```
+    pub fn to_ordinal_word(x: u64, and_behavior: AndBehavior) -> String {
+        ordinal_from_cardinal(&to_word(x, and_behavior))
+    }
+
+    /// Convert a 64-bit unsigned integer to an ordinal numeral (e.g. "42nd")
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::ordinal_suffix;
+    /// assert_eq!(ordinal_suffix(1), "1st".to_string());
+    /// assert_eq!(ordinal_suffix(103), "103rd".to_string());
+    /// assert_eq!(ordinal_suffix(112), "112th".to_string());
+    /// ```
+    pub fn ordinal_suffix(x: u64) -> String {
+        let numeral = x.to_string();
+        let suffix = numeral_suffix(&numeral);
+        numeral + suffix
+    }
+
+    /// The ordinal suffix for a string of digits: "st", "nd", "rd", or "th"
+    pub(crate) fn numeral_suffix(numeral: &str) -> &'static str {
+        let mut digits = numeral.bytes().rev();
+        match (digits.next(), digits.next()) {
+            (_, Some(b'1')) => "th",
+            (Some(b'1'), _) => "st",
+            (Some(b'2'), _) => "nd",
+            (Some(b'3'), _) => "rd",
+            _ => "th",
+        }
+    }
+
+    /// Turn cardinal words into ordinal words by changing only the last word
+    pub(crate) fn ordinal_from_cardinal(cardinal: &str) -> String {
+        let split = cardinal.rfind([' ', '-']).map_or(0, |k| k + 1);
+        let (head, last) = cardinal.split_at(split);
+        let last = match last {
+            "one" => "first".to_string(),
+            "two" => "second".to_string(),
+            "three" => "third".to_string(),
+            "five" => "fifth".to_string(),
+            "eight" => "eighth".to_string(),
+            "nine" => "ninth".to_string(),
+            "twelve" => "twelfth".to_string(),
+            x if x.ends_with('y') => format!("{}ieth", &x[..x.len() - 1]),
+            x => format!("{}th", x),
+        };
+        format!("{}{}", head, last)
+    }
+
+    /// Displays a 64-bit unsigned integer as words, without building a `String` first
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::{AndBehavior, Words};
+    /// assert_eq!(format!("you have {} items", Words(42)), "you have forty-two items");
+    /// assert_eq!(
+    ///     format!("{}", Words(305).and_behavior(AndBehavior::None)),
+    ///     "three-hundred five"
+    /// );
+    /// ```
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct Words(pub u64);
+
+    /// Displays a 64-bit signed integer as words (e.g. "negative five")
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::SignedWords;
+    /// use numbers_into_words::FormatOptions;
+    /// assert_eq!(format!("{}", SignedWords(-5)), "negative five");
+    /// assert_eq!(
+    ///     format!("{}", SignedWords(-5).with(FormatOptions::new().negative_word("minus"))),
+    ///     "minus five"
+    /// );
+    /// ```
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct SignedWords(pub i64);
+
+    /// Displays a 64-bit unsigned integer as ordinal words (e.g. "forty-second")
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::conversion_to_words::Case;
+    /// use numbers_into_words::{FormatOptions, OrdinalWords};
+    /// assert_eq!(format!("the {} time", OrdinalWords(42)), "the forty-second time");
+    /// assert_eq!(
+    ///     format!("{}", OrdinalWords(101).with(FormatOptions::new().case(Case::Title))),
+    ///     "One-Hundred and First"
+    /// );
+    /// ```
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct OrdinalWords(pub u64);
+
+    /// One of `Words`, `SignedWords`, or `OrdinalWords`, displayed in the style given by a
+    /// `FormatOptions`
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Styled<T> {
+        inner: T,
+        options: FormatOptions,
+    }
+
+    macro_rules! impl_display_words {
+        ($($t:ty),*) => {
+            $(
+                impl $t {
+                    /// Displays the words in the style given by `options`
+                    pub fn with(self, options: FormatOptions) -> Styled<Self> {
+                        Styled {
+                            inner: self,
+                            options,
+                        }
+                    }
+
+                    /// Displays the words using "and" as given by `and_behavior`
+                    pub fn and_behavior(self, and_behavior: AndBehavior) -> Styled<Self> {
+                        self.with(FormatOptions::new().and_behavior(and_behavior))
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_display_words!(Words, SignedWords, OrdinalWords);
+
+    impl fmt::Display for Words {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            // padding needs the whole text, so only then are the words built first
+            if f.width().is_some() {
+                return f.pad(&to_word(self.0, AndBehavior::All));
+            }
+            write_word(f, self.0, AndBehavior::All)
+        }
+    }
+
+    impl fmt::Display for SignedWords {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            if f.width().is_some() {
+                return f.pad(&to_word_signed(self.0, AndBehavior::All));
+            }
+            if self.0 < 0 {
+                write!(f, "{} ", DEFAULT_NEGATIVE_WORD)?;
+            }
+            write_word(f, self.0.unsigned_abs(), AndBehavior::All)
+        }
+    }
+
+    impl fmt::Display for OrdinalWords {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.pad(&to_ordinal_word(self.0, AndBehavior::All))
+        }
+    }
+
+    impl fmt::Display for Styled<Words> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.pad(&to_word_with(self.inner.0 as u128, &self.options))
+        }
+    }
+
+    impl fmt::Display for Styled<SignedWords> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.pad(&to_word_signed_with(self.inner.0, &self.options))
+        }
+    }
+
+    impl fmt::Display for Styled<OrdinalWords> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let cardinal = uncased_words(self.inner.0 as u128, &self.options);
+            f.pad(&self.options.case.apply(ordinal_from_cardinal(&cardinal)))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_to_word_with() {
+            assert_eq!(
+                to_word_with(0, &FormatOptions::new().case(Case::Upper)),
+                String::from("ZERO")
             );
             assert_eq!(
-                under_1000(19, 0, AndBehavior::All, 19).unwrap(),
-                String::from("nineteen")
+                to_word_with(
+                    900_000_047,
+                    &FormatOptions::new()
+                        .and_behavior(AndBehavior::LastGroup)
+                        .hyphenation(Hyphenation::TensOnly)
+                ),
+                String::from("nine hundred million, forty-seven")
             );
             assert_eq!(
-                under_1000(20, 0, AndBehavior::All, 20).unwrap(),
-                String::from("twenty")
+                to_word_with(1_200_300, &FormatOptions::new().commas(false)),
+                String::from("one million two-hundred thousand three-hundred")
             );
             assert_eq!(
-                under_1000(47, 0, AndBehavior::All, 47).unwrap(),
-                String::from("forty-seven")
+                to_word_with(1_200_300, &FormatOptions::new().group_separator("\n")),
+                String::from("one million\ntwo-hundred thousand\nthree-hundred")
             );
             assert_eq!(
-                under_1000(120, 0, AndBehavior::All, 120).unwrap(),
-                String::from("one-hundred and twenty")
+                FormatOptions::new()
+                    .group_separator(" / ")
+                    .restyle(&to_ordinal_word(2_021, AndBehavior::None)),
+                String::from("two thousand / twenty-first")
             );
             assert_eq!(
-                under_1000(247, 0, AndBehavior::All, 247).unwrap(),
-                String::from("two-hundred and forty-seven")
+                to_word_with(
+                    721_021,
+                    &FormatOptions::new().hyphenation(Hyphenation::None)
+                ),
+                String::from("seven hundred and twenty one thousand, twenty one")
             );
             assert_eq!(
-                under_1000(403, 0, AndBehavior::All, 403).unwrap(),
-                String::from("four-hundred and three")
+                FormatOptions::new()
+                    .hyphenation(Hyphenation::TensOnly)
+                    .restyle(&to_ordinal_word(200, AndBehavior::All)),
+                String::from("two hundredth")
             );
             assert_eq!(
-                under_1000(612, 0, AndBehavior::All, 612).unwrap(),
-                String::from("six-hundred and twelve")
+                to_word_with(u64::MAX as u128, &FormatOptions::default()),
+                to_word(u64::MAX, AndBehavior::All)
             );
+        }
+
+        #[test]
+        fn test_case() {
             assert_eq!(
-                under_1000(919, 0, AndBehavior::All, 919).unwrap(),
-                String::from("nine-hundred and nineteen")
+                to_word_with(420, &FormatOptions::new().case(Case::Title)),
+                String::from("Four-Hundred and Twenty")
             );
             assert_eq!(
-                under_1000(612, 0, AndBehavior::LastGroup, 234612).unwrap(),
-                String::from("six-hundred and twelve")
+                to_word_with(
+                    2_021,
+                    &FormatOptions::new()
+                        .hyphenation(Hyphenation::TensOnly)
+                        .case(Case::Title)
+                ),
+                String::from("Two Thousand, Twenty-One")
             );
             assert_eq!(
-                under_1000(919, 0, AndBehavior::LastGroup, 239919).unwrap(),
-                String::from("nine-hundred and nineteen")
+                to_word_with(420, &FormatOptions::new().case(Case::Sentence)),
+                String::from("Four-hundred and twenty")
             );
             assert_eq!(
-                under_1000(120, 1, AndBehavior::LastGroup, 120330).unwrap(),
-                String::from("one-hundred twenty")
+                to_word_with(420, &FormatOptions::new().case(Case::Upper)),
+                String::from("FOUR-HUNDRED AND TWENTY")
             );
+            assert_eq!(title_case("and so on"), String::from("And So On"));
+            assert_eq!(title_case("42nd"), String::from("42nd"));
+            assert_eq!(capitalize(""), String::new());
+        }
+
+        #[test]
+        fn test_zero_word() {
             assert_eq!(
-                under_1000(247, 1, AndBehavior::LastGroup, 247123).unwrap(),
-                String::from("two-hundred forty-seven")
+                to_word_with(0, &FormatOptions::new().zero_word("nought")),
+                String::from("nought")
             );
             assert_eq!(
-                under_1000(612, 0, AndBehavior::OnlyUnderThousand, 612).unwrap(),
-                String::from("six-hundred and twelve")
+                to_word_with(0, &FormatOptions::new().zero_word("nil").case(Case::Title)),
+                String::from("Nil")
             );
             assert_eq!(
-                under_1000(919, 0, AndBehavior::OnlyUnderThousand, 919).unwrap(),
-                String::from("nine-hundred and nineteen")
+                FormatOptions::new()
+                    .zero_word("oh")
+                    .restyle("zero point zero five, zeroth"),
+                String::from("oh point oh five, zeroth")
             );
+        }
+
+        #[test]
+        fn test_negative_word() {
+            let options = FormatOptions::new()
+                .negative_word("minus")
+                .case(Case::Title)
+                .zero_word("nought");
             assert_eq!(
-                under_1000(120, 1, AndBehavior::OnlyUnderThousand, 120330).unwrap(),
-                String::from("one-hundred twenty")
+                to_word_signed_with(-1_000, &options),
+                String::from("Minus One Thousand")
             );
+            assert_eq!(to_word_signed_with(0, &options), String::from("Nought"));
+            assert_eq!(to_word_signed_with(7, &options), String::from("Seven"));
             assert_eq!(
-                under_1000(3, 0, AndBehavior::None, 3).unwrap(),
-                String::from("three")
+                FormatOptions::new()
+                    .negative_word("in the red")
+                    .negative_placement(NegativePlacement::After)
+                    .restyle("negative zero point five"),
+                String::from("zero point five in the red")
             );
             assert_eq!(
-                under_1000(12, 0, AndBehavior::None, 12).unwrap(),
-                String::from("twelve")
+                FormatOptions::new()
+                    .negative_word("minus")
+                    .restyle("negatively"),
+                String::from("negatively")
             );
+        }
+
+        #[test]
+        fn test_article() {
+            let options = FormatOptions::new().article(true);
             assert_eq!(
-                under_1000(19, 0, AndBehavior::None, 19).unwrap(),
-                String::from("nineteen")
+                to_word_with(120, &options),
+                String::from("a hundred and twenty")
             );
             assert_eq!(
-                under_1000(20, 0, AndBehavior::None, 20).unwrap(),
-                String::from("twenty")
+                to_word_with(1_005, &options),
+                String::from("a thousand, five")
             );
             assert_eq!(
-                under_1000(47, 0, AndBehavior::None, 47).unwrap(),
-                String::from("forty-seven")
+                to_word_with(101_000_000, &options),
+                String::from("a hundred and one million")
             );
             assert_eq!(
-                under_1000(120, 0, AndBehavior::None, 120).unwrap(),
-                String::from("one-hundred twenty")
+                to_word_with(1_100, &options),
+                String::from("a thousand, one-hundred")
             );
+            assert_eq!(to_word_with(1, &options), String::from("one"));
             assert_eq!(
-                under_1000(247, 0, AndBehavior::None, 247).unwrap(),
-                String::from("two-hundred forty-seven")
+                to_word_with(21_000, &options),
+                String::from("twenty-one thousand")
             );
             assert_eq!(
-                under_1000(403, 0, AndBehavior::None, 403).unwrap(),
-                String::from("four-hundred three")
+                to_word_signed_with(-100, &options),
+                String::from("negative one-hundred")
             );
             assert_eq!(
-                under_1000(612, 0, AndBehavior::None, 612).unwrap(),
-                String::from("six-hundred twelve")
+                options.restyle("one point five"),
+                String::from("one point five")
             );
             assert_eq!(
-                under_1000(919, 0, AndBehavior::None, 919).unwrap(),
-                String::from("nine-hundred nineteen")
+                FormatOptions::new()
+                    .article(true)
+                    .hyphenation(Hyphenation::TensOnly)
+                    .case(Case::Sentence)
+                    .restyle(&to_ordinal_word(1_000, AndBehavior::All)),
+                String::from("A thousandth")
             );
-            assert!(under_1000(2105, 0, AndBehavior::All, 2105).is_err());
-            assert!(under_1000(2105, 0, AndBehavior::None, 2105).is_err());
-            assert!(under_1000(2105, 0, AndBehavior::LastGroup, 2105).is_err());
-            assert!(under_1000(2105, 0, AndBehavior::OnlyUnderThousand, 2105).is_err());
         }
 
         #[test]
-        fn test_to_word() {
-            assert_eq!(to_word(0, AndBehavior::None), String::from("zero"));
-            assert_eq!(to_word(3, AndBehavior::None), String::from("three"));
-            assert_eq!(to_word(12, AndBehavior::None), String::from("twelve"));
-            assert_eq!(to_word(19, AndBehavior::None), String::from("nineteen"));
-            assert_eq!(to_word(20, AndBehavior::None), String::from("twenty"));
-            assert_eq!(to_word(47, AndBehavior::None), String::from("forty-seven"));
-            assert_eq!(
-                to_word(120, AndBehavior::None),
-                String::from("one-hundred twenty")
-            );
+        fn test_scale() {
+            let long = FormatOptions::new()
+                .scale(Scale::Long)
+                .and_behavior(AndBehavior::None);
             assert_eq!(
-                to_word(247, AndBehavior::None),
-                String::from("two-hundred forty-seven")
-            );
-            assert_eq!(
-                to_word(403, AndBehavior::None),
-                String::from("four-hundred three")
+                to_word_with(1_000_000_000, &long),
+                String::from("one thousand million")
             );
             assert_eq!(
-                to_word(612, AndBehavior::None),
-                String::from("six-hundred twelve")
+                to_word_with(1_500_000_000, &long),
+                String::from("one thousand five-hundred million")
             );
             assert_eq!(
-                to_word(919, AndBehavior::None),
-                String::from("nine-hundred nineteen")
+                to_word_with(1_001_000_000, &long),
+                String::from("one thousand one million")
             );
             assert_eq!(
-                to_word(2105, AndBehavior::None),
-                String::from("two thousand, one-hundred five")
+                to_word_with(1_001_000_001, &long),
+                String::from("one thousand one million, one")
             );
             assert_eq!(
-                to_word(200_105, AndBehavior::None),
-                String::from("two-hundred thousand, one-hundred five")
+                to_word_with(2_000_300_000_004, &long),
+                String::from("two billion, three-hundred million, four")
             );
             assert_eq!(
-                to_word(530_175_000, AndBehavior::None),
-                String::from("five-hundred thirty million, one-hundred seventy-five thousand")
+                to_word_with(7_000_000_000_000_000, &long.clone().article(true)),
+                String::from("seven thousand billion")
             );
             assert_eq!(
-                to_word(530_175_999, AndBehavior::None),
-                String::from(
-                    "five-hundred thirty million, one-hundred \
-                         seventy-five thousand, nine-hundred ninety-nine"
-                )
+                to_word_with(1_000_000_000, &long.clone().article(true)),
+                String::from("a thousand million")
             );
             assert_eq!(
-                to_word(4_530_175_999, AndBehavior::None),
+                to_word_with(u128::MAX, &long),
                 String::from(
-                    "four billion, five-hundred thirty million, one-hundred \
-                         seventy-five thousand, nine-hundred ninety-nine"
+                    "three-hundred forty sextillion, two-hundred eighty-two thousand \
+                     three-hundred sixty-six quintillion, nine-hundred twenty thousand \
+                     nine-hundred thirty-eight quadrillion, four-hundred sixty-three thousand \
+                     four-hundred sixty-three trillion, three-hundred seventy-four thousand \
+                     six-hundred seven billion, four-hundred thirty-one thousand \
+                     seven-hundred sixty-eight million, two-hundred eleven thousand, \
+                     four-hundred fifty-five"
                 )
             );
+            let milliard = FormatOptions::new().scale(Scale::LongMilliard);
             assert_eq!(
-                to_word(4_000_175_999, AndBehavior::None),
-                String::from(
-                    "four billion, one-hundred \
-                         seventy-five thousand, nine-hundred ninety-nine"
-                )
+                to_word_with(1_500_000_000, &milliard),
+                String::from("one milliard, five-hundred million")
             );
             assert_eq!(
-                to_word(14_000_001_019, AndBehavior::None),
-                String::from("fourteen billion, one thousand, nineteen")
+                to_word_with(3_000_000_000_000_000, &milliard.clone().article(true)),
+                String::from("three billiard")
             );
             assert_eq!(
-                to_word(123_456_789_012_345, AndBehavior::None),
-                String::from(
-                    "one-hundred twenty-three trillion, four-hundred fifty-six billion, \
-                      seven-hundred eighty-nine million, twelve thousand, three-hundred \
-                      forty-five"
-                )
+                to_word_with(1_000_000_000, &milliard.article(true)),
+                String::from("a milliard")
             );
             assert_eq!(
-                to_word(17_654_123_456_789_012_345, AndBehavior::None),
-                String::from(
-                    "seventeen quintillion, six-hundred fifty-four quadrillion, \
-                    one-hundred twenty-three trillion, four-hundred fifty-six billion, \
-                      seven-hundred eighty-nine million, twelve thousand, three-hundred \
-                      forty-five"
-                )
+                to_word_signed_with(-1_000_000_000_000, &long.case(Case::Title)),
+                String::from("Negative One Billion")
             );
+        }
+
+        #[test]
+        fn test_indian_scale() {
+            let indian = FormatOptions::new().scale(Scale::Indian);
             assert_eq!(
-                to_word(u64::MAX, AndBehavior::None),
+                to_word_with(123_456_789, &indian),
                 String::from(
-                    "eighteen quintillion, four-hundred forty-six quadrillion, \
-                 seven-hundred forty-four trillion, seventy-three billion, \
-                 seven-hundred nine million, five-hundred fifty-one thousand, \
-                 six-hundred fifteen"
+                    "twelve crore, thirty-four lakh, fifty-six thousand, \
+                     seven-hundred and eighty-nine"
                 )
             );
-
-            assert_eq!(to_word(0, AndBehavior::All), String::from("zero"));
-            assert_eq!(to_word(3, AndBehavior::All), String::from("three"));
-            assert_eq!(to_word(12, AndBehavior::All), String::from("twelve"));
-            assert_eq!(to_word(19, AndBehavior::All), String::from("nineteen"));
-            assert_eq!(to_word(20, AndBehavior::All), String::from("twenty"));
-            assert_eq!(to_word(47, AndBehavior::All), String::from("forty-seven"));
-            assert_eq!(
-                to_word(120, AndBehavior::All),
-                String::from("one-hundred and twenty")
-            );
-            assert_eq!(
-                to_word(247, AndBehavior::All),
-                String::from("two-hundred and forty-seven")
-            );
-            assert_eq!(
-                to_word(403, AndBehavior::All),
-                String::from("four-hundred and three")
-            );
             assert_eq!(
-                to_word(612, AndBehavior::All),
-                String::from("six-hundred and twelve")
+                to_word_with(
+                    123_456_789,
+                    &indian.clone().and_behavior(AndBehavior::LastGroup)
+                ),
+                to_word_with(123_456_789, &indian)
             );
             assert_eq!(
-                to_word(919, AndBehavior::All),
-                String::from("nine-hundred and nineteen")
+                to_word_with(100_000, &indian.clone().article(true)),
+                String::from("a lakh")
             );
             assert_eq!(
-                to_word(2105, AndBehavior::All),
-                String::from("two thousand, one-hundred and five")
+                to_word_with(12_345_600_000_000, &indian),
+                String::from("twelve lakh, thirty-four thousand, five-hundred and sixty crore")
             );
             assert_eq!(
-                to_word(200_105, AndBehavior::All),
-                String::from("two-hundred thousand, one-hundred and five")
+                to_word_with(
+                    100_000_000_000_005,
+                    &indian.clone().and_behavior(AndBehavior::LastGroup)
+                ),
+                String::from("one crore crore, five")
             );
             assert_eq!(
-                to_word(530_175_000, AndBehavior::All),
-                String::from(
-                    "five-hundred and thirty million, one-hundred and seventy-five thousand"
-                )
+                to_word_with(100_000_050_000_000, &indian),
+                String::from("one crore crore, five crore")
             );
             assert_eq!(
-                to_word(530_175_999, AndBehavior::All),
+                to_word_with(u128::MAX, &indian),
                 String::from(
-                    "five-hundred and thirty million, one-hundred and \
-                         seventy-five thousand, nine-hundred and ninety-nine"
+                    "three thousand, four-hundred and two crore crore crore crore crore, \
+                     eighty-two lakh, thirty-six thousand, six-hundred and ninety-two crore \
+                     crore crore crore, nine lakh, thirty-eight thousand, four-hundred and \
+                     sixty-three crore crore crore, forty-six lakh, thirty-three thousand, \
+                     seven-hundred and forty-six crore crore, seven lakh, forty-three \
+                     thousand, one-hundred and seventy-six crore, eighty-two lakh, eleven \
+                     thousand, four-hundred and fifty-five"
                 )
             );
             assert_eq!(
-                to_word(4_530_175_999, AndBehavior::All),
-                String::from(
-                    "four billion, five-hundred and thirty million, one-hundred and \
-                         seventy-five thousand, nine-hundred and ninety-nine"
-                )
+                to_word_with(
+                    500_000_000,
+                    &indian.and_behavior(AndBehavior::OnlyUnderThousand)
+                ),
+                String::from("fifty crore")
             );
+        }
+
+        #[test]
+        fn test_myriad_scale() {
+            let myriad = FormatOptions::new().scale(Scale::Myriad);
             assert_eq!(
-                to_word(4_000_175_999, AndBehavior::All),
+                to_word_with(123_456_789, &myriad),
                 String::from(
-                    "four billion, one-hundred and \
-                         seventy-five thousand, nine-hundred and ninety-nine"
+                    "one oku, two thousand, three-hundred and forty-five man, \
+                     six thousand, seven-hundred and eighty-nine"
                 )
             );
             assert_eq!(
-                to_word(14_000_001_019, AndBehavior::All),
-                String::from("fourteen billion, one thousand, nineteen")
+                to_word_with(10_000, &myriad.clone().article(true)),
+                String::from("one man")
             );
             assert_eq!(
-                to_word(123_456_789_012_345, AndBehavior::All),
-                String::from(
-                    "one-hundred and twenty-three trillion, four-hundred and fifty-six billion, \
-                      seven-hundred and eighty-nine million, twelve thousand, three-hundred and \
-                      forty-five"
-                )
+                to_word_with(300_000_000_000, &myriad),
+                String::from("three thousand oku")
             );
             assert_eq!(
-                to_word(17_654_123_456_789_012_345, AndBehavior::All),
-                String::from(
-                    "seventeen quintillion, six-hundred and fifty-four quadrillion, \
-                    one-hundred and twenty-three trillion, four-hundred and fifty-six billion, \
-                      seven-hundred and eighty-nine million, twelve thousand, three-hundred and \
-                      forty-five"
-                )
+                to_word_with(
+                    1_0000_0005,
+                    &myriad.clone().and_behavior(AndBehavior::LastGroup)
+                ),
+                String::from("one oku, five")
             );
             assert_eq!(
-                to_word(u64::MAX, AndBehavior::All),
+                to_word_with(u128::MAX, &myriad.and_behavior(AndBehavior::None)),
                 String::from(
-                    "eighteen quintillion, four-hundred and forty-six quadrillion, \
-                 seven-hundred and forty-four trillion, seventy-three billion, \
-                 seven-hundred and nine million, five-hundred and fifty-one thousand, \
-                 six-hundred and fifteen"
+                    "three-hundred forty kan, two thousand, eight-hundred twenty-three kō, \
+                     six thousand, six-hundred ninety-two jō, nine-hundred thirty-eight jo, \
+                     four thousand, six-hundred thirty-four gai, six thousand, \
+                     three-hundred thirty-seven kei, four thousand, six-hundred seven chō, \
+                     four thousand, three-hundred seventeen oku, six thousand, \
+                     eight-hundred twenty-one man, one thousand, four-hundred fifty-five"
                 )
             );
+        }
 
+        #[test]
+        fn test_to_words_trait() {
             assert_eq!(
-                to_word(0, AndBehavior::OnlyUnderThousand),
-                String::from("zero")
+                255_u8.to_words(AndBehavior::None),
+                to_word(255, AndBehavior::None)
             );
             assert_eq!(
-                to_word(3, AndBehavior::OnlyUnderThousand),
-                String::from("three")
+                u16::MAX.to_words(AndBehavior::All),
+                to_word(65_535, AndBehavior::All)
             );
             assert_eq!(
-                to_word(12, AndBehavior::OnlyUnderThousand),
-                String::from("twelve")
+                usize::MAX.to_words(AndBehavior::All),
+                to_word(usize::MAX as u64, AndBehavior::All)
             );
             assert_eq!(
-                to_word(19, AndBehavior::OnlyUnderThousand),
-                String::from("nineteen")
+                u128::MAX.to_words(AndBehavior::All),
+                to_word_u128(u128::MAX, AndBehavior::All)
             );
             assert_eq!(
-                to_word(20, AndBehavior::OnlyUnderThousand),
-                String::from("twenty")
+                i16::MIN.to_words(AndBehavior::LastGroup),
+                to_word_signed(-32_768, AndBehavior::LastGroup)
             );
             assert_eq!(
-                to_word(47, AndBehavior::OnlyUnderThousand),
-                String::from("forty-seven")
+                (-5_isize).to_words(AndBehavior::All),
+                String::from("negative five")
             );
             assert_eq!(
-                to_word(120, AndBehavior::OnlyUnderThousand),
-                String::from("one-hundred and twenty")
+                i64::MIN.to_words(AndBehavior::None),
+                to_word_signed(i64::MIN, AndBehavior::None)
             );
             assert_eq!(
-                to_word(247, AndBehavior::OnlyUnderThousand),
-                String::from("two-hundred and forty-seven")
+                i128::MIN.to_words(AndBehavior::None),
+                format!(
+                    "negative {}",
+                    to_word_u128(i128::MIN.unsigned_abs(), AndBehavior::None)
+                )
             );
+            assert_eq!(0_i128.to_words(AndBehavior::None), String::from("zero"));
+        }
+
+        #[test]
+        fn test_to_word_signed() {
             assert_eq!(
-                to_word(403, AndBehavior::OnlyUnderThousand),
-                String::from("four-hundred and three")
+                to_word_signed(-1, AndBehavior::All),
+                String::from("negative one")
             );
             assert_eq!(
-                to_word(612, AndBehavior::OnlyUnderThousand),
-                String::from("six-hundred and twelve")
+                to_word_signed(-1_005, AndBehavior::OnlyUnderThousand),
+                String::from("negative one thousand, five")
             );
             assert_eq!(
-                to_word(919, AndBehavior::OnlyUnderThousand),
-                String::from("nine-hundred and nineteen")
+                to_word_signed(-305, AndBehavior::OnlyUnderThousand),
+                String::from("negative three-hundred and five")
             );
             assert_eq!(
-                to_word(2105, AndBehavior::OnlyUnderThousand),
-                String::from("two thousand, one-hundred five")
+                to_word_signed(i64::MAX, AndBehavior::None),
+                String::from(
+                    "nine quintillion, two-hundred twenty-three quadrillion, \
+                     three-hundred seventy-two trillion, thirty-six billion, \
+                     eight-hundred fifty-four million, seven-hundred seventy-five thousand, \
+                     eight-hundred seven"
+                )
             );
             assert_eq!(
-                to_word(200_105, AndBehavior::OnlyUnderThousand),
-                String::from("two-hundred thousand, one-hundred five")
+                to_word_signed_prefixed(i64::MIN, AndBehavior::None, NegativePrefix::Minus),
+                String::from(
+                    "minus nine quintillion, two-hundred twenty-three quadrillion, \
+                     three-hundred seventy-two trillion, thirty-six billion, \
+                     eight-hundred fifty-four million, seven-hundred seventy-five thousand, \
+                     eight-hundred eight"
+                )
             );
+        }
+
+        #[test]
+        fn test_ordinal_from_cardinal() {
+            assert_eq!(ordinal_from_cardinal("zero"), String::from("zeroth"));
+            assert_eq!(ordinal_from_cardinal("three"), String::from("third"));
+            assert_eq!(ordinal_from_cardinal("twelve"), String::from("twelfth"));
+            assert_eq!(ordinal_from_cardinal("twenty"), String::from("twentieth"));
             assert_eq!(
-                to_word(530_175_000, AndBehavior::OnlyUnderThousand),
-                String::from("five-hundred thirty million, one-hundred seventy-five thousand")
+                ordinal_from_cardinal("twenty-one"),
+                String::from("twenty-first")
             );
             assert_eq!(
-                to_word(530_175_999, AndBehavior::OnlyUnderThousand),
-                String::from(
-                    "five-hundred thirty million, one-hundred \
-                         seventy-five thousand, nine-hundred ninety-nine"
-                )
+                ordinal_from_cardinal("one-hundred"),
+                String::from("one-hundredth")
             );
             assert_eq!(
-                to_word(4_530_175_999, AndBehavior::OnlyUnderThousand),
-                String::from(
-                    "four billion, five-hundred thirty million, one-hundred \
-                         seventy-five thousand, nine-hundred ninety-nine"
-                )
+                ordinal_from_cardinal("two thousand, one-hundred and eight"),
+                String::from("two thousand, one-hundred and eighth")
             );
+        }
+
+        #[test]
+        fn test_to_ordinal_word() {
+            assert_eq!(to_ordinal_word(1, AndBehavior::All), String::from("first"));
+            assert_eq!(to_ordinal_word(2, AndBehavior::All), String::from("second"));
+            assert_eq!(to_ordinal_word(5, AndBehavior::All), String::from("fifth"));
+            assert_eq!(to_ordinal_word(8, AndBehavior::All), String::from("eighth"));
+            assert_eq!(to_ordinal_word(9, AndBehavior::All), String::from("ninth"));
             assert_eq!(
-                to_word(4_000_175_999, AndBehavior::OnlyUnderThousand),
-                String::from(
-                    "four billion, one-hundred \
-                         seventy-five thousand, nine-hundred ninety-nine"
-                )
+                to_ordinal_word(11, AndBehavior::All),
+                String::from("eleventh")
             );
             assert_eq!(
-                to_word(14_000_001_019, AndBehavior::OnlyUnderThousand),
-                String::from("fourteen billion, one thousand, nineteen")
+                to_ordinal_word(12, AndBehavior::All),
+                String::from("twelfth")
             );
             assert_eq!(
-                to_word(123_456_789_012_345, AndBehavior::OnlyUnderThousand),
-                String::from(
-                    "one-hundred twenty-three trillion, four-hundred fifty-six billion, \
-                      seven-hundred eighty-nine million, twelve thousand, three-hundred \
-                      forty-five"
-                )
+                to_ordinal_word(90, AndBehavior::All),
+                String::from("ninetieth")
             );
             assert_eq!(
-                to_word(17_654_123_456_789_012_345, AndBehavior::OnlyUnderThousand),
-                String::from(
-                    "seventeen quintillion, six-hundred fifty-four quadrillion, \
-                    one-hundred twenty-three trillion, four-hundred fifty-six billion, \
-                      seven-hundred eighty-nine million, twelve thousand, three-hundred \
-                      forty-five"
-                )
+                to_ordinal_word(2_021, AndBehavior::None),
+                String::from("two thousand, twenty-first")
             );
             assert_eq!(
-                to_word(u64::MAX, AndBehavior::OnlyUnderThousand),
-                String::from(
-                    "eighteen quintillion, four-hundred forty-six quadrillion, \
-                 seven-hundred forty-four trillion, seventy-three billion, \
-                 seven-hundred nine million, five-hundred fifty-one thousand, \
-                 six-hundred fifteen"
-                )
+                to_ordinal_word(305_000, AndBehavior::All),
+                String::from("three-hundred and five thousandth")
             );
-            assert_eq!(to_word(0, AndBehavior::LastGroup), String::from("zero"));
-            assert_eq!(to_word(3, AndBehavior::LastGroup), String::from("three"));
-            assert_eq!(to_word(12, AndBehavior::LastGroup), String::from("twelve"));
+        }
+
+        #[test]
+        fn test_ordinal_suffix() {
+            assert_eq!(ordinal_suffix(0), String::from("0th"));
+            assert_eq!(ordinal_suffix(2), String::from("2nd"));
+            assert_eq!(ordinal_suffix(3), String::from("3rd"));
+            assert_eq!(ordinal_suffix(4), String::from("4th"));
+            assert_eq!(ordinal_suffix(11), String::from("11th"));
+            assert_eq!(ordinal_suffix(12), String::from("12th"));
+            assert_eq!(ordinal_suffix(13), String::from("13th"));
+            assert_eq!(ordinal_suffix(21), String::from("21st"));
+            assert_eq!(ordinal_suffix(42), String::from("42nd"));
+            assert_eq!(ordinal_suffix(1_011), String::from("1011th"));
+            assert_eq!(ordinal_suffix(1_001), String::from("1001st"));
+            assert_eq!(numeral_suffix("-3"), "rd");
+        }
+
+        #[test]
+        fn test_insert_and() {
+            let mut ab = AndBehavior::None;
+            assert_eq!(ab.insert_and(4, 5234234924233), " ");
+            assert_eq!(ab.insert_and(0, 1263953243921), " ");
+            assert_eq!(ab.insert_and(0, 432), " ");
+
+            ab = AndBehavior::LastGroup;
+            assert_eq!(ab.insert_and(4, 5234234924233), " ");
+            assert_eq!(ab.insert_and(0, 1263953243921), " and ");
+            assert_eq!(ab.insert_and(0, 432), " and ");
+
+            ab = AndBehavior::OnlyUnderThousand;
+            assert_eq!(ab.insert_and(4, 5234234924233), " ");
+            assert_eq!(ab.insert_and(0, 1263953243921), " ");
+            assert_eq!(ab.insert_and(0, 432), " and ");
+
+            ab = AndBehavior::All;
+            assert_eq!(ab.insert_and(4, 5234234924233), " and ");
+            assert_eq!(ab.insert_and(0, 1263953243921), " and ");
+            assert_eq!(ab.insert_and(0, 432), " and ");
+        }
+
+        #[test]
+        fn test_single_digit() {
+            assert_eq!(single_digit(0).unwrap(), String::from("zero"));
+            assert_eq!(single_digit(5).unwrap(), String::from("five"));
+            assert!(single_digit(14).is_err());
+        }
+
+        #[test]
+        fn test_under_100() {
+            assert_eq!(under_100(3).unwrap(), String::from("three"));
+            assert_eq!(under_100(12).unwrap(), String::from("twelve"));
+            assert_eq!(under_100(19).unwrap(), String::from("nineteen"));
+            assert_eq!(under_100(20).unwrap(), String::from("twenty"));
+            assert_eq!(under_100(47).unwrap(), String::from("forty-seven"));
+            assert!(under_100(105).is_err());
+        }
+
+        #[test]
+        fn test_under_1000() {
             assert_eq!(
-                to_word(19, AndBehavior::LastGroup),
+                under_1000(3, 0, AndBehavior::All, 3).unwrap(),
+                String::from("three")
+            );
+            assert_eq!(
+                under_1000(12, 0, AndBehavior::All, 12).unwrap(),
+                String::from("twelve")
+            );
+            assert_eq!(
+                under_1000(19, 0, AndBehavior::All, 19).unwrap(),
                 String::from("nineteen")
             );
-            assert_eq!(to_word(20, AndBehavior::LastGroup), String::from("twenty"));
             assert_eq!(
-                to_word(47, AndBehavior::LastGroup),
+                under_1000(20, 0, AndBehavior::All, 20).unwrap(),
+                String::from("twenty")
+            );
+            assert_eq!(
+                under_1000(47, 0, AndBehavior::All, 47).unwrap(),
                 String::from("forty-seven")
             );
             assert_eq!(
-                to_word(120, AndBehavior::LastGroup),
+                under_1000(120, 0, AndBehavior::All, 120).unwrap(),
                 String::from("one-hundred and twenty")
             );
             assert_eq!(
-                to_word(247, AndBehavior::LastGroup),
+                under_1000(247, 0, AndBehavior::All, 247).unwrap(),
                 String::from("two-hundred and forty-seven")
             );
             assert_eq!(
-                to_word(403, AndBehavior::LastGroup),
+                under_1000(403, 0, AndBehavior::All, 403).unwrap(),
                 String::from("four-hundred and three")
             );
             assert_eq!(
-                to_word(612, AndBehavior::LastGroup),
+                under_1000(612, 0, AndBehavior::All, 612).unwrap(),
                 String::from("six-hundred and twelve")
             );
             assert_eq!(
-                to_word(919, AndBehavior::LastGroup),
+                under_1000(919, 0, AndBehavior::All, 919).unwrap(),
                 String::from("nine-hundred and nineteen")
             );
             assert_eq!(
-                to_word(2105, AndBehavior::LastGroup),
-                String::from("two thousand, one-hundred and five")
+                under_1000(612, 0, AndBehavior::LastGroup, 234612).unwrap(),
+                String::from("six-hundred and twelve")
             );
             assert_eq!(
-                to_word(200_105, AndBehavior::LastGroup),
-                String::from("two-hundred thousand, one-hundred and five")
+                under_1000(919, 0, AndBehavior::LastGroup, 239919).unwrap(),
+                String::from("nine-hundred and nineteen")
             );
             assert_eq!(
-                to_word(530_175_000, AndBehavior::LastGroup),
+                under_1000(120, 1, AndBehavior::LastGroup, 120330).unwrap(),
+                String::from("one-hundred twenty")
+            );
+            assert_eq!(
+                under_1000(247, 1, AndBehavior::LastGroup, 247123).unwrap(),
+                String::from("two-hundred forty-seven")
+            );
+            assert_eq!(
+                under_1000(612, 0, AndBehavior::OnlyUnderThousand, 612).unwrap(),
+                String::from("six-hundred and twelve")
+            );
+            assert_eq!(
+                under_1000(919, 0, AndBehavior::OnlyUnderThousand, 919).unwrap(),
+                String::from("nine-hundred and nineteen")
+            );
+            assert_eq!(
+                under_1000(120, 1, AndBehavior::OnlyUnderThousand, 120330).unwrap(),
+                String::from("one-hundred twenty")
+            );
+            assert_eq!(
+                under_1000(3, 0, AndBehavior::None, 3).unwrap(),
+                String::from("three")
+            );
+            assert_eq!(
+                under_1000(12, 0, AndBehavior::None, 12).unwrap(),
+                String::from("twelve")
+            );
+            assert_eq!(
+                under_1000(19, 0, AndBehavior::None, 19).unwrap(),
+                String::from("nineteen")
+            );
+            assert_eq!(
+                under_1000(20, 0, AndBehavior::None, 20).unwrap(),
+                String::from("twenty")
+            );
+            assert_eq!(
+                under_1000(47, 0, AndBehavior::None, 47).unwrap(),
+                String::from("forty-seven")
+            );
+            assert_eq!(
+                under_1000(120, 0, AndBehavior::None, 120).unwrap(),
+                String::from("one-hundred twenty")
+            );
+            assert_eq!(
+                under_1000(247, 0, AndBehavior::None, 247).unwrap(),
+                String::from("two-hundred forty-seven")
+            );
+            assert_eq!(
+                under_1000(403, 0, AndBehavior::None, 403).unwrap(),
+                String::from("four-hundred three")
+            );
+            assert_eq!(
+                under_1000(612, 0, AndBehavior::None, 612).unwrap(),
+                String::from("six-hundred twelve")
+            );
+            assert_eq!(
+                under_1000(919, 0, AndBehavior::None, 919).unwrap(),
+                String::from("nine-hundred nineteen")
+            );
+            assert!(under_1000(2105, 0, AndBehavior::All, 2105).is_err());
+            assert!(under_1000(2105, 0, AndBehavior::None, 2105).is_err());
+            assert!(under_1000(2105, 0, AndBehavior::LastGroup, 2105).is_err());
+            assert!(under_1000(2105, 0, AndBehavior::OnlyUnderThousand, 2105).is_err());
+        }
+
+        #[test]
+        fn test_display_words() {
+            for x in [0, 7, 2_859, u64::MAX] {
+                assert_eq!(Words(x).to_string(), to_word(x, AndBehavior::All));
+                assert_eq!(
+                    Words(x).and_behavior(AndBehavior::None).to_string(),
+                    to_word(x, AndBehavior::None)
+                );
+                assert_eq!(
+                    OrdinalWords(x).to_string(),
+                    to_ordinal_word(x, AndBehavior::All)
+                );
+            }
+            for x in [i64::MIN, -2_859, 0, i64::MAX] {
+                assert_eq!(
+                    SignedWords(x).to_string(),
+                    to_word_signed(x, AndBehavior::All)
+                );
+            }
+            assert_eq!(format!("[{:>6}]", Words(5)), "[  five]");
+            assert_eq!(format!("[{:<7}]", SignedWords(-1)), "[negative one]");
+            assert_eq!(format!("[{:^7}]", OrdinalWords(2)), "[second ]");
+            let upper = FormatOptions::new().case(Case::Upper);
+            assert_eq!(
+                format!("{:>12}", Words(12).with(upper.clone())),
+                "      TWELVE"
+            );
+            assert_eq!(
+                SignedWords(-12).with(upper.clone()).to_string(),
+                "NEGATIVE TWELVE"
+            );
+            assert_eq!(OrdinalWords(12).with(upper).to_string(), "TWELFTH");
+        }
+
+        #[test]
+        fn test_to_word_cow() {
+            let values = (0..2_000).chain((0..7).map(|k| 1000_u64.pow(k))).chain([
+                2_000_000,
+                1_000_001,
+                u64::MAX,
+            ]);
+            for x in values {
+                for b in [AndBehavior::None, AndBehavior::All] {
+                    assert_eq!(to_word_cow(x, b), to_word(x, b));
+                }
+            }
+            let borrowed = |x| matches!(to_word_cow(x, AndBehavior::All), Cow::Borrowed(_));
+            assert!((0..=100).all(borrowed));
+            assert!((1..7).all(|k| borrowed(1000_u64.pow(k))));
+            assert_eq!(borrowed(101), cfg!(feature = "const-table"));
+            assert!(!borrowed(2_000));
+            assert!(!borrowed(u64::MAX));
+        }
+
+        #[test]
+        fn test_write_word() {
+            /// A fixed buffer, to write without a `String`
+            struct Buffer {
+                bytes: [u8; 256],
+                len: usize,
+            }
+
+            impl fmt::Write for Buffer {
+                fn write_str(&mut self, s: &str) -> fmt::Result {
+                    let end = self.len + s.len();
+                    self.bytes
+                        .get_mut(self.len..end)
+                        .ok_or(fmt::Error)?
+                        .copy_from_slice(s.as_bytes());
+                    self.len = end;
+                    Ok(())
+                }
+            }
+
+            let values = (0..2_000)
+                .chain([10_010, 1_000_000, 330_759_736, u64::MAX])
+                .chain((0..64).map(|k| 0x9E37_79B9_7F4A_7C15_u64 >> k));
+            for x in values {
+                for b in [
+                    AndBehavior::None,
+                    AndBehavior::LastGroup,
+                    AndBehavior::OnlyUnderThousand,
+                    AndBehavior::All,
+                ] {
+                    let mut buffer = Buffer {
+                        bytes: [0; 256],
+                        len: 0,
+                    };
+                    write_word(&mut buffer, x, b).unwrap();
+                    assert_eq!(
+                        std::str::from_utf8(&buffer.bytes[..buffer.len]),
+                        Ok(to_word_u128(x as u128, b).as_str())
+                    );
+                }
+            }
+            let mut small = Buffer {
+                bytes: [0; 256],
+                len: 250,
+            };
+            assert_eq!(
+                write_word(&mut small, 77, AndBehavior::All),
+                Err(fmt::Error)
+            );
+        }
+
+        #[test]
+        fn test_to_word() {
+            assert_eq!(to_word(0, AndBehavior::None), String::from("zero"));
+            assert_eq!(to_word(3, AndBehavior::None), String::from("three"));
+            assert_eq!(to_word(12, AndBehavior::None), String::from("twelve"));
+            assert_eq!(to_word(19, AndBehavior::None), String::from("nineteen"));
+            assert_eq!(to_word(20, AndBehavior::None), String::from("twenty"));
+            assert_eq!(to_word(47, AndBehavior::None), String::from("forty-seven"));
+            assert_eq!(
+                to_word(120, AndBehavior::None),
+                String::from("one-hundred twenty")
+            );
+            assert_eq!(
+                to_word(247, AndBehavior::None),
+                String::from("two-hundred forty-seven")
+            );
+            assert_eq!(
+                to_word(403, AndBehavior::None),
+                String::from("four-hundred three")
+            );
+            assert_eq!(
+                to_word(612, AndBehavior::None),
+                String::from("six-hundred twelve")
+            );
+            assert_eq!(
+                to_word(919, AndBehavior::None),
+                String::from("nine-hundred nineteen")
+            );
+            assert_eq!(
+                to_word(2105, AndBehavior::None),
+                String::from("two thousand, one-hundred five")
+            );
+            assert_eq!(
+                to_word(200_105, AndBehavior::None),
+                String::from("two-hundred thousand, one-hundred five")
+            );
+            assert_eq!(
+                to_word(530_175_000, AndBehavior::None),
                 String::from("five-hundred thirty million, one-hundred seventy-five thousand")
             );
             assert_eq!(
-                to_word(530_175_999, AndBehavior::LastGroup),
+                to_word(530_175_999, AndBehavior::None),
                 String::from(
                     "five-hundred thirty million, one-hundred \
-                         seventy-five thousand, nine-hundred and ninety-nine"
+                         seventy-five thousand, nine-hundred ninety-nine"
                 )
             );
             assert_eq!(
-                to_word(4_530_175_999, AndBehavior::LastGroup),
+                to_word(4_530_175_999, AndBehavior::None),
                 String::from(
                     "four billion, five-hundred thirty million, one-hundred \
-                         seventy-five thousand, nine-hundred and ninety-nine"
+                         seventy-five thousand, nine-hundred ninety-nine"
                 )
             );
             assert_eq!(
-                to_word(4_000_175_999, AndBehavior::LastGroup),
+                to_word(4_000_175_999, AndBehavior::None),
                 String::from(
                     "four billion, one-hundred \
-                         seventy-five thousand, nine-hundred and ninety-nine"
+                         seventy-five thousand, nine-hundred ninety-nine"
                 )
             );
             assert_eq!(
-                to_word(14_000_001_019, AndBehavior::LastGroup),
+                to_word(14_000_001_019, AndBehavior::None),
                 String::from("fourteen billion, one thousand, nineteen")
             );
             assert_eq!(
-                to_word(123_456_789_012_345, AndBehavior::LastGroup),
+                to_word(123_456_789_012_345, AndBehavior::None),
                 String::from(
                     "one-hundred twenty-three trillion, four-hundred fifty-six billion, \
                       seven-hundred eighty-nine million, twelve thousand, three-hundred \
-                      and forty-five"
+                      forty-five"
                 )
             );
             assert_eq!(
-                to_word(17_654_123_456_789_012_345, AndBehavior::LastGroup),
+                to_word(17_654_123_456_789_012_345, AndBehavior::None),
                 String::from(
                     "seventeen quintillion, six-hundred fifty-four quadrillion, \
                     one-hundred twenty-three trillion, four-hundred fifty-six billion, \
                       seven-hundred eighty-nine million, twelve thousand, three-hundred \
-                      and forty-five"
+                      forty-five"
                 )
             );
             assert_eq!(
-                to_word(u64::MAX, AndBehavior::LastGroup),
+                to_word(u64::MAX, AndBehavior::None),
                 String::from(
                     "eighteen quintillion, four-hundred forty-six quadrillion, \
                  seven-hundred forty-four trillion, seventy-three billion, \
                  seven-hundred nine million, five-hundred fifty-one thousand, \
-                 six-hundred and fifteen"
+                 six-hundred fifteen"
                 )
             );
-        }
-    }
-}
-
-pub mod process_input {
-    use super::conversion_to_words::AndBehavior;
-    use super::to_word;
-    use super::COPYRIGHT_INFO;
-
-    #[derive(Clone, Debug, PartialEq, Eq)]
-    enum InputComponent {
-        ToConvert(u64),
-        Error(String),
-        Help,
-        AndHelp,
-        MinimalOutput,
-        AndOption(AndBehavior),
-    }
 
-    #[derive(Clone, Debug, PartialEq, Eq)]
+            assert_eq!(to_word(0, AndBehavior::All), String::from("zero"));
+            assert_eq!(to_word(3, AndBehavior::All), String::from("three"));
+            assert_eq!(to_word(12, AndBehavior::All), String::from("twelve"));
+            assert_eq!(to_word(19, AndBehavior::All), String::from("nineteen"));
+            assert_eq!(to_word(20, AndBehavior::All), String::from("twenty"));
+            assert_eq!(to_word(47, AndBehavior::All), String::from("forty-seven"));
+            assert_eq!(
+                to_word(120, AndBehavior::All),
+                String::from("one-hundred and twenty")
+            );
+            assert_eq!(
+                to_word(247, AndBehavior::All),
+                String::from("two-hundred and forty-seven")
+            );
+            assert_eq!(
+                to_word(403, AndBehavior::All),
+                String::from("four-hundred and three")
+            );
+            assert_eq!(
+                to_word(612, AndBehavior::All),
+                String::from("six-hundred and twelve")
+            );
+            assert_eq!(
+                to_word(919, AndBehavior::All),
+                String::from("nine-hundred and nineteen")
+            );
+            assert_eq!(
+                to_word(2105, AndBehavior::All),
+                String::from("two thousand, one-hundred and five")
+            );
+            assert_eq!(
+                to_word(200_105, AndBehavior::All),
+                String::from("two-hundred thousand, one-hundred and five")
+            );
+            assert_eq!(
+                to_word(530_175_000, AndBehavior::All),
+                String::from(
+                    "five-hundred and thirty million, one-hundred and seventy-five thousand"
+                )
+            );
+            assert_eq!(
+                to_word(530_175_999, AndBehavior::All),
+                String::from(
+                    "five-hundred and thirty million, one-hundred and \
+                         seventy-five thousand, nine-hundred and ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(4_530_175_999, AndBehavior::All),
+                String::from(
+                    "four billion, five-hundred and thirty million, one-hundred and \
+                         seventy-five thousand, nine-hundred and ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(4_000_175_999, AndBehavior::All),
+                String::from(
+                    "four billion, one-hundred and \
+                         seventy-five thousand, nine-hundred and ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(14_000_001_019, AndBehavior::All),
+                String::from("fourteen billion, one thousand, nineteen")
+            );
+            assert_eq!(
+                to_word(123_456_789_012_345, AndBehavior::All),
+                String::from(
+                    "one-hundred and twenty-three trillion, four-hundred and fifty-six billion, \
+                      seven-hundred and eighty-nine million, twelve thousand, three-hundred and \
+                      forty-five"
+                )
+            );
+            assert_eq!(
+                to_word(17_654_123_456_789_012_345, AndBehavior::All),
+                String::from(
+                    "seventeen quintillion, six-hundred and fifty-four quadrillion, \
+                    one-hundred and twenty-three trillion, four-hundred and fifty-six billion, \
+                      seven-hundred and eighty-nine million, twelve thousand, three-hundred and \
+                      forty-five"
+                )
+            );
+            assert_eq!(
+                to_word(u64::MAX, AndBehavior::All),
+                String::from(
+                    "eighteen quintillion, four-hundred and forty-six quadrillion, \
+                 seven-hundred and forty-four trillion, seventy-three billion, \
+                 seven-hundred and nine million, five-hundred and fifty-one thousand, \
+                 six-hundred and fifteen"
+                )
+            );
+
+            assert_eq!(
+                to_word(0, AndBehavior::OnlyUnderThousand),
+                String::from("zero")
+            );
+            assert_eq!(
+                to_word(3, AndBehavior::OnlyUnderThousand),
+                String::from("three")
+            );
+            assert_eq!(
+                to_word(12, AndBehavior::OnlyUnderThousand),
+                String::from("twelve")
+            );
+            assert_eq!(
+                to_word(19, AndBehavior::OnlyUnderThousand),
+                String::from("nineteen")
+            );
+            assert_eq!(
+                to_word(20, AndBehavior::OnlyUnderThousand),
+                String::from("twenty")
+            );
+            assert_eq!(
+                to_word(47, AndBehavior::OnlyUnderThousand),
+                String::from("forty-seven")
+            );
+            assert_eq!(
+                to_word(120, AndBehavior::OnlyUnderThousand),
+                String::from("one-hundred and twenty")
+            );
+            assert_eq!(
+                to_word(247, AndBehavior::OnlyUnderThousand),
+                String::from("two-hundred and forty-seven")
+            );
+            assert_eq!(
+                to_word(403, AndBehavior::OnlyUnderThousand),
+                String::from("four-hundred and three")
+            );
+            assert_eq!(
+                to_word(612, AndBehavior::OnlyUnderThousand),
+                String::from("six-hundred and twelve")
+            );
+            assert_eq!(
+                to_word(919, AndBehavior::OnlyUnderThousand),
+                String::from("nine-hundred and nineteen")
+            );
+            assert_eq!(
+                to_word(2105, AndBehavior::OnlyUnderThousand),
+                String::from("two thousand, one-hundred five")
+            );
+            assert_eq!(
+                to_word(200_105, AndBehavior::OnlyUnderThousand),
+                String::from("two-hundred thousand, one-hundred five")
+            );
+            assert_eq!(
+                to_word(530_175_000, AndBehavior::OnlyUnderThousand),
+                String::from("five-hundred thirty million, one-hundred seventy-five thousand")
+            );
+            assert_eq!(
+                to_word(530_175_999, AndBehavior::OnlyUnderThousand),
+                String::from(
+                    "five-hundred thirty million, one-hundred \
+                         seventy-five thousand, nine-hundred ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(4_530_175_999, AndBehavior::OnlyUnderThousand),
+                String::from(
+                    "four billion, five-hundred thirty million, one-hundred \
+                         seventy-five thousand, nine-hundred ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(4_000_175_999, AndBehavior::OnlyUnderThousand),
+                String::from(
+                    "four billion, one-hundred \
+                         seventy-five thousand, nine-hundred ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(14_000_001_019, AndBehavior::OnlyUnderThousand),
+                String::from("fourteen billion, one thousand, nineteen")
+            );
+            assert_eq!(
+                to_word(123_456_789_012_345, AndBehavior::OnlyUnderThousand),
+                String::from(
+                    "one-hundred twenty-three trillion, four-hundred fifty-six billion, \
+                      seven-hundred eighty-nine million, twelve thousand, three-hundred \
+                      forty-five"
+                )
+            );
+            assert_eq!(
+                to_word(17_654_123_456_789_012_345, AndBehavior::OnlyUnderThousand),
+                String::from(
+                    "seventeen quintillion, six-hundred fifty-four quadrillion, \
+                    one-hundred twenty-three trillion, four-hundred fifty-six billion, \
+                      seven-hundred eighty-nine million, twelve thousand, three-hundred \
+                      forty-five"
+                )
+            );
+            assert_eq!(
+                to_word(u64::MAX, AndBehavior::OnlyUnderThousand),
+                String::from(
+                    "eighteen quintillion, four-hundred forty-six quadrillion, \
+                 seven-hundred forty-four trillion, seventy-three billion, \
+                 seven-hundred nine million, five-hundred fifty-one thousand, \
+                 six-hundred fifteen"
+                )
+            );
+            assert_eq!(to_word(0, AndBehavior::LastGroup), String::from("zero"));
+            assert_eq!(to_word(3, AndBehavior::LastGroup), String::from("three"));
+            assert_eq!(to_word(12, AndBehavior::LastGroup), String::from("twelve"));
+            assert_eq!(
+                to_word(19, AndBehavior::LastGroup),
+                String::from("nineteen")
+            );
+            assert_eq!(to_word(20, AndBehavior::LastGroup), String::from("twenty"));
+            assert_eq!(
+                to_word(47, AndBehavior::LastGroup),
+                String::from("forty-seven")
+            );
+            assert_eq!(
+                to_word(120, AndBehavior::LastGroup),
+                String::from("one-hundred and twenty")
+            );
+            assert_eq!(
+                to_word(247, AndBehavior::LastGroup),
+                String::from("two-hundred and forty-seven")
+            );
+            assert_eq!(
+                to_word(403, AndBehavior::LastGroup),
+                String::from("four-hundred and three")
+            );
+            assert_eq!(
+                to_word(612, AndBehavior::LastGroup),
+                String::from("six-hundred and twelve")
+            );
+            assert_eq!(
+                to_word(919, AndBehavior::LastGroup),
+                String::from("nine-hundred and nineteen")
+            );
+            assert_eq!(
+                to_word(2105, AndBehavior::LastGroup),
+                String::from("two thousand, one-hundred and five")
+            );
+            assert_eq!(
+                to_word(200_105, AndBehavior::LastGroup),
+                String::from("two-hundred thousand, one-hundred and five")
+            );
+            assert_eq!(
+                to_word(530_175_000, AndBehavior::LastGroup),
+                String::from("five-hundred thirty million, one-hundred seventy-five thousand")
+            );
+            assert_eq!(
+                to_word(530_175_999, AndBehavior::LastGroup),
+                String::from(
+                    "five-hundred thirty million, one-hundred \
+                         seventy-five thousand, nine-hundred and ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(4_530_175_999, AndBehavior::LastGroup),
+                String::from(
+                    "four billion, five-hundred thirty million, one-hundred \
+                         seventy-five thousand, nine-hundred and ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(4_000_175_999, AndBehavior::LastGroup),
+                String::from(
+                    "four billion, one-hundred \
+                         seventy-five thousand, nine-hundred and ninety-nine"
+                )
+            );
+            assert_eq!(
+                to_word(14_000_001_019, AndBehavior::LastGroup),
+                String::from("fourteen billion, one thousand, nineteen")
+            );
+            assert_eq!(
+                to_word(123_456_789_012_345, AndBehavior::LastGroup),
+                String::from(
+                    "one-hundred twenty-three trillion, four-hundred fifty-six billion, \
+                      seven-hundred eighty-nine million, twelve thousand, three-hundred \
+                      and forty-five"
+                )
+            );
+            assert_eq!(
+                to_word(17_654_123_456_789_012_345, AndBehavior::LastGroup),
+                String::from(
+                    "seventeen quintillion, six-hundred fifty-four quadrillion, \
+                    one-hundred twenty-three trillion, four-hundred fifty-six billion, \
+                      seven-hundred eighty-nine million, twelve thousand, three-hundred \
+                      and forty-five"
+                )
+            );
+            assert_eq!(
+                to_word(u64::MAX, AndBehavior::LastGroup),
+                String::from(
+                    "eighteen quintillion, four-hundred forty-six quadrillion, \
+                 seven-hundred forty-four trillion, seventy-three billion, \
+                 seven-hundred nine million, five-hundred fifty-one thousand, \
+                 six-hundred and fifteen"
+                )
+            );
+        }
+    }
+}
+
+pub mod approximate;
+pub mod benchmark;
+#[cfg(feature = "bigint")]
+pub mod bigint;
+pub mod chunked;
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
+pub mod currency;
+pub mod decimal;
+pub mod digits;
+pub mod dotted;
+pub mod fractions;
+pub mod histogram;
+pub mod languages;
+pub mod lists;
+pub mod normalize;
+pub mod numerals;
+pub mod parse_words;
+pub mod powers;
+pub mod random;
+pub mod ranges;
+pub mod results;
+#[cfg(feature = "proptest")]
+pub mod round_trip;
+pub mod ssml;
+pub mod temperature;
+pub mod timestamps;
+pub mod tokens;
+pub mod version_info;
+
+pub mod process_input {
+    use super::benchmark;
+    #[cfg(feature = "bigint")]
+    use super::bigint::to_word_big;
+    use super::conversion_to_words::{
+        numeral_suffix, ordinal_from_cardinal, uncased_signed_words, uncased_words, AndBehavior,
+        Case, FormatOptions, Hyphenation, NegativePlacement, Scale,
+    };
+    use super::currency::{
+        parse_amount, parse_amount_rounded, split_currency, to_cheque_words,
+        to_currency_words_with, to_legal_words, to_negative_currency_words, Currency,
+        NegativeAmount, RoundingMode,
+    };
+    use super::decimal::Decimal;
+    use super::digits::{spell_digits_with, DigitAlphabet};
+    use super::histogram::histogram_to_words;
+    use super::languages::{language_from_code, GroupReader, Language, LANGUAGE_CODES};
+    use super::lists::{join_list, ListOptions};
+    use super::normalize::{Direction, Markup, Normalizer};
+    use super::numerals::{expand_scientific, parse_strict};
+    use super::parse_words::from_words;
+    use super::random::{UniformNumbers, DEFAULT_MAX, MAX_COUNT};
+    use super::ranges::{NumberRange, MAX_RANGE_LEN};
+    use super::results::{csv_row, json_string, CSV_HEADER};
+    use super::ssml::{fragment, speak, SsmlStyle};
+    use super::tokens::{identifier, IdentifierStyle};
+    use super::version_info::version;
+    use super::COPYRIGHT_INFO;
+    use std::rc::Rc;
+    use std::{fs, io};
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum InputComponent {
+        ToConvert(u128),
+        ToConvertNegative(i64),
+        #[cfg(feature = "bigint")]
+        ToConvertBig(String),
+        ToConvertDecimal(Decimal),
+        ToConvertMoney(String),
+        ToParse(String),
+        ToSpell(String, DigitAlphabet),
+        EndOfOptions,
+        Error(String),
+        Help,
+        AndHelp,
+        MinimalOutput,
+        AndOption(AndBehavior),
+        Copy,
+        Paste,
+        File(String),
+        Output(String),
+        Append,
+        Null,
+        Quiet,
+        ErrorsOnly,
+        Column(usize),
+        Template(String),
+        Version,
+        Strict,
+        Signed,
+        RangeOption(NumberRange),
+        StepOption(u128),
+        RandomOption(u64),
+        MinOption(u64),
+        MaxOption(u64),
+        SeedOption(u64),
+        Histogram,
+        List,
+        Csv,
+        Jsonl,
+        Follow,
+        Reverse,
+        EasternNumerals,
+        ChequeOption(bool),
+        Legal,
+        SsmlOption(SsmlStyle),
+        CurrencyOption(Currency),
+        RoundingOption(RoundingMode),
+        NegativeMoneyOption(NegativeAmount),
+        OrdinalOption(OrdinalStyle),
+        HyphenOption(Hyphenation),
+        GroupSeparator(String),
+        CaseOption(Case),
+        IdentifierOption(IdentifierStyle),
+        DigitsOption(DigitAlphabet),
+        ZeroWord(String),
+        NegativeWord(String),
+        NegativePlacementOption(NegativePlacement),
+        Article,
+        ScaleOption(Scale),
+        LanguageOption(String),
+    }
+
+    /// How ordinals are written with `--ordinal=`
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    enum OrdinalStyle {
+        Suffix,
+        Words,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
     enum OutputComponent {
         ToConvert {
-            value: u64,
+            value: u128,
+            and_behavior: AndBehavior,
+        },
+        ToConvertNegative {
+            value: i64,
+            and_behavior: AndBehavior,
+        },
+        #[cfg(feature = "bigint")]
+        ToConvertBig {
+            digits: String,
+            and_behavior: AndBehavior,
+        },
+        ToConvertDecimal {
+            value: Decimal,
+            and_behavior: AndBehavior,
+        },
+        ToConvertMoney {
+            amount: String,
             and_behavior: AndBehavior,
         },
+        ToParse(String),
+        ToSpell {
+            text: String,
+            alphabet: DigitAlphabet,
+        },
         Error(String),
     }
 
-    /// Helper for command-line mode
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use numbers_into_words::Config;
-    /// use std::env;
-    ///
-    /// /* Essentially the entire main function for the command-line program */
-    /// let args: Vec<String> = env::args().collect();
-    /// let config = Config::parse(args);
-    /// println!("{}", config.process());
-    /// ```
-    ///
-    /// ```
-    /// use numbers_into_words::Config;
-    ///
-    /// let args: Vec<String> = vec!["program_name".to_string(), "42".to_string()];
-    /// assert_eq!(
-    ///     Config::parse(args).process(),
-    ///     String::from("42: forty-two"));
-    /// ```
-    pub struct Config {
-        output_components: Result<Vec<OutputComponent>, String>,
-        help: bool,
-        and_help: bool,
-        prog_name: String,
-        minimal_output: bool,
-    }
+    impl OutputComponent {
+        /// The conversion for an input, or `None` for an option
+        fn from_input(x: &InputComponent, and_behavior: AndBehavior) -> Option<Self> {
+            match x {
+                InputComponent::ToConvert(k) => Some(Self::ToConvert {
+                    value: *k,
+                    and_behavior,
+                }),
+                InputComponent::ToConvertNegative(k) => Some(Self::ToConvertNegative {
+                    value: *k,
+                    and_behavior,
+                }),
+                #[cfg(feature = "bigint")]
+                InputComponent::ToConvertBig(k) => Some(Self::ToConvertBig {
+                    digits: k.clone(),
+                    and_behavior,
+                }),
+                InputComponent::ToConvertDecimal(k) => Some(Self::ToConvertDecimal {
+                    value: k.clone(),
+                    and_behavior,
+                }),
+                InputComponent::ToConvertMoney(k) => Some(Self::ToConvertMoney {
+                    amount: k.clone(),
+                    and_behavior,
+                }),
+                InputComponent::ToParse(k) => Some(Self::ToParse(k.clone())),
+                InputComponent::ToSpell(k, alphabet) => Some(Self::ToSpell {
+                    text: k.clone(),
+                    alphabet: *alphabet,
+                }),
+                InputComponent::Error(k) => Some(Self::Error(k.clone())),
+                _ => None,
+            }
+        }
+    }
+
+    /// The options that shape the words for one number, as they stood where it was given, so
+    /// that an option applies only to the numbers after it (e.g. `--case=title 112
+    /// --case=upper 113`)
+    #[derive(Clone)]
+    struct Style {
+        eastern_numerals: bool,
+        cheque: Option<bool>,
+        legal: bool,
+        identifier: Option<IdentifierStyle>,
+        currency: Option<Currency>,
+        rounding: Option<RoundingMode>,
+        negative_amount: NegativeAmount,
+        ordinal: Option<OrdinalStyle>,
+        format: FormatOptions,
+        language: Option<Rc<dyn Language>>,
+        /// The words of `language` for `--reverse`, built once rather than for every phrase
+        reader: Option<Rc<GroupReader>>,
+    }
+
+    impl Style {
+        fn new() -> Self {
+            Self {
+                eastern_numerals: false,
+                cheque: None,
+                legal: false,
+                identifier: None,
+                currency: None,
+                rounding: None,
+                negative_amount: NegativeAmount::Word,
+                ordinal: None,
+                format: FormatOptions::new(),
+                language: None,
+                reader: None,
+            }
+        }
+
+        /// Turns the words for a number into an ordinal, if `--ordinal=` was given
+        fn apply_ordinal(&self, numeral: &str, words: String) -> String {
+            match self.ordinal {
+                None => words,
+                Some(OrdinalStyle::Words) => ordinal_from_cardinal(&words),
+                Some(OrdinalStyle::Suffix) => format!("{}{}", numeral, numeral_suffix(numeral)),
+            }
+        }
+
+        /// Writes the numeral before each conversion in Eastern Arabic digits if
+        /// `--eastern-numerals` was given
+        fn echo_numeral(&self, numeral: &str) -> String {
+            if !self.eastern_numerals {
+                return numeral.to_string();
+            }
+            numeral
+                .chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(d) => ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'][d as usize],
+                    None => c,
+                })
+                .collect()
+        }
+
+        /// Whether numbers are written as amounts of money, with `--currency=`, `--cheque`, or
+        /// `--legal`
+        fn money_output(&self) -> bool {
+            self.currency.is_some() || self.cheque.is_some() || self.legal
+        }
+
+        /// Writes a non-negative number as an amount of money for `--currency=`, `--cheque`,
+        /// or `--legal`, in dollars unless the amount or `--currency=` names another currency
+        fn money_words(&self, numeral: &str, options: &FormatOptions) -> Result<String, String> {
+            let (negative, amount) = match numeral.trim().strip_prefix('-') {
+                Some(magnitude) => (true, magnitude),
+                None => (false, numeral),
+            };
+            if negative && (self.cheque.is_some() || self.legal) {
+                return Err(format!(
+                    "No cheque or legal amount for negative value: {}",
+                    numeral
+                ));
+            }
+            let default = self.currency.unwrap_or(Currency::Usd);
+            let (currency, major, minor) = match self.rounding {
+                Some(mode) => parse_amount_rounded(amount, default, mode),
+                None => parse_amount(amount, default),
+            }
+            .map_err(|e| format!("{}: {}", e.trim_end_matches('.'), numeral))?;
+            match self.cheque {
+                Some(only) => to_cheque_words(major, minor, currency, only),
+                None if self.legal => to_legal_words(major, minor, currency),
+                None if negative => to_negative_currency_words(
+                    major,
+                    minor,
+                    currency,
+                    self.negative_amount,
+                    options,
+                ),
+                None => to_currency_words_with(major, minor, currency, options),
+            }
+            .map_err(|e| format!("{}: {}", e.trim_end_matches('.'), numeral))
+        }
+
+        /// Writes a number in the `--lang=` language, which has no ordinals
+        fn localized_words(
+            &self,
+            language: &dyn Language,
+            value: i128,
+            options: &FormatOptions,
+        ) -> Result<String, String> {
+            if self.ordinal.is_some() {
+                return Err(format!("Ordinals are only available in English: {}", value));
+            }
+            let words = language.cardinal_with(value.unsigned_abs(), options);
+            Ok(if value < 0 {
+                format!("{} {}", language.negative_word(), words)
+            } else {
+                words
+            })
+        }
+
+        /// The words for `{ordinal}` in a `--format=` template, from the uncased words for the
+        /// input; only whole numbers in English have one
+        fn template_ordinal(&self, c: &OutputComponent, words: &str) -> String {
+            let whole = match c {
+                OutputComponent::ToConvert { .. } | OutputComponent::ToConvertNegative { .. } => {
+                    true
+                }
+                #[cfg(feature = "bigint")]
+                OutputComponent::ToConvertBig { .. } => true,
+                _ => false,
+            };
+            if !whole || self.money_output() || self.language.is_some() {
+                String::new()
+            } else if self.ordinal.is_some() {
+                // the words are already an ordinal
+                words.to_string()
+            } else {
+                ordinal_from_cardinal(words)
+            }
+        }
+    }
+
+    /// How a run went, for the exit code of the command-line program
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::{Config, Status};
+    /// let args = vec!["numbers_into_words", "42", "x"];
+    /// let (_, status) = Config::parse(args.into_iter().map(String::from).collect()).process();
+    /// assert_eq!(status, Status::PartialFailure);
+    /// ```
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Status {
+        /// Every input was converted (or there were none, as with `--help`)
+        Success,
+        /// Some inputs were converted, but others could not be
+        PartialFailure,
+        /// No input could be converted
+        Failure,
+        /// An option was invalid, or there were no arguments
+        UsageError,
+    }
+
+    impl Status {
+        /// The status of two runs taken together, e.g. two lines of streamed input
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use numbers_into_words::Status;
+        /// assert_eq!(Status::Failure.merge(Status::Failure), Status::Failure);
+        /// assert_eq!(Status::Success.merge(Status::Failure), Status::PartialFailure);
+        /// ```
+        pub fn merge(self, other: Self) -> Self {
+            match (self, other) {
+                (Self::UsageError, _) | (_, Self::UsageError) => Self::UsageError,
+                (a, b) if a == b => a,
+                _ => Self::PartialFailure,
+            }
+        }
+    }
+
+    /// Helper for command-line mode
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use numbers_into_words::Config;
+    /// use std::env;
+    ///
+    /// /* Essentially the entire main function for the command-line program */
+    /// let args: Vec<String> = env::args().collect();
+    /// let config = Config::parse(args);
+    /// let (output, _status) = config.process();
+    /// println!("{}", output);
+    /// ```
+    ///
+    /// ```
+    /// use numbers_into_words::{Config, Status};
+    ///
+    /// let args: Vec<String> = vec!["program_name".to_string(), "42".to_string()];
+    /// assert_eq!(
+    ///     Config::parse(args).process(),
+    ///     (String::from("42: forty-two"), Status::Success));
+    /// ```
+    pub struct Config {
+        output_components: Result<Vec<OutputComponent>, String>,
+        inputs: Vec<String>,
+        help: bool,
+        and_help: bool,
+        prog_name: String,
+        minimal_output: bool,
+        copy: bool,
+        histogram: bool,
+        list: bool,
+        csv: bool,
+        jsonl: bool,
+        follow: bool,
+        reverse: bool,
+        digits: Option<DigitAlphabet>,
+        and_behavior: AndBehavior,
+        ssml: Option<SsmlStyle>,
+        /// The options as they stood after the last argument, for numbers that come after
+        /// all of them, as from standard input
+        style: Style,
+        /// The options for each of `inputs`
+        styles: Vec<Style>,
+        bench: Option<u64>,
+        normalize: Option<Normalizer>,
+        output: Option<String>,
+        append: bool,
+        null: bool,
+        quiet: bool,
+        errors_only: bool,
+        column: Option<usize>,
+        template: Option<String>,
+        version: bool,
+        usage_error: bool,
+        strict: bool,
+        range: Option<NumberRange>,
+        random: Option<(u64, UniformNumbers)>,
+    }
+
+    fn and_help() -> String {
+        "\
+                \"and\" options:\n\
+                -------------------------------------------------------------------\n\
+                \u{0020}   --and=none           Don't use the word \"and\"\n\
+                \u{0020}                        (e.g. \"five-hundred seventy-two\")\n\
+                \n\
+                \u{0020}   --and=last           Only use the word \"and\" in the\n\
+                \u{0020}                        hundreds-tens-units group\n\
+                \u{0020}                        (e.g. \"three-hundred five thousand,\n\
+                \u{0020}                               five-hundred and seventy-two\",\n\
+                \u{0020}                        but \"three-hundred five million,\n\
+                \u{0020}                             five-hundred seventy-two thousand\")\n\
+                \n\
+                \u{0020}   --and=below1k        Only use the word \"and\" for numbers below 1000\n\
+                \u{0020}                        (e.g. \"three-hundred five thousand,\n\
+                \u{0020}                               five-hundred seventy-two\",\n\
+                \u{0020}                        but \"three-hundred and five\")\n\
+                \n\
+                \u{0020}   --and=all            Always use \"and\" (default behavior)\n\
+                \u{0020}                        (e.g. \"five-hundred and twenty-four million,\n\
+                \u{0020}                               three-hundred and seventy-eight\")\n\
+                "
+        .to_string()
+    }
+
+    fn help_text(prog_name: &String) -> String {
+        format!(
+            "\
+                numbers_into_words: Converts positive integers to words\n\
+                {}\n\
+                -------------------------------------------------------\n\
+                \n\
+                Usage: {} [OPTIONS] [NUMBERS] [-- NEGATIVE NUMBERS]\n\
+                \u{0020}      {} bench [--n=COUNT]\n\
+                \u{0020}      {} --reverse [PHRASES]\n\
+                \u{0020}      {} normalize [--reverse] [--markup=(plain | markdown | html)]\n\
+                \u{0020}          [--and=...] < INPUT\n\
+                \n\
+                Options that shape the words (--and=, --case=, --lang=,\n\
+                --currency=, and so on) apply to the numbers after them, so\n\
+                \"--case=title 112 --case=upper 113\" writes each differently.\n\
+                \n\
+                Options:\n\
+                \u{0020} --help                 Display this help message\n\
+                \n\
+                \u{0020} --version              Display the version, cargo features,\n\
+                \u{0020}                        and languages\n\
+                \n\
+                \u{0020} --and=(none | last | below1k | all )\n\
+                \n\
+                \u{0020}                        Specify when the word \"and\"\n\
+                \u{0020}                        should be used in phrases like\n\
+                \u{0020}                        \"five-hundred and seventy-two\"\n\
+                \n\
+                \u{0020} --and-help             Describe the options for --and=\n\
+                \n\
+                \u{0020} --minimal              Output only the words for each\n\
+                \u{0020}                        number (rather than prefacing\n\
+                \u{0020}                        with the numerals, e.g. \"five\"\n\
+                \u{0020}                        instead of \"5: five\")\n\
+                \n\
+                \u{0020} --format=TEMPLATE      Write each result by filling in\n\
+                \u{0020}                        TEMPLATE, e.g. \"{{value}} spelled\n\
+                \u{0020}                        out is {{words}}\"; the placeholders\n\
+                \u{0020}                        are {{input}}, {{value}}, {{words}},\n\
+                \u{0020}                        {{ordinal}}, and {{error}} (an input\n\
+                \u{0020}                        that can't be converted is only\n\
+                \u{0020}                        written with a template that has\n\
+                \u{0020}                        {{error}})\n\
+                \n\
+                \u{0020} --copy                 Also copy the words to the clipboard\n\
+                \u{0020}                        (requires the \"clipboard\" feature)\n\
+                \n\
+                \u{0020} --paste                Read numbers from the clipboard\n\
+                \u{0020}                        (requires the \"clipboard\" feature)\n\
+                \n\
+                \u{0020} --file=PATH            Read numbers from the file PATH,\n\
+                \u{0020}                        separated by spaces or newlines\n\
+                \u{0020}                        (may be given more than once)\n\
+                \n\
+                \u{0020} --output=PATH          Write the results to the file PATH\n\
+                \u{0020}                        instead, keeping errors on standard\n\
+                \u{0020}                        error\n\
+                \n\
+                \u{0020} --append               With --output=, add to the end of\n\
+                \u{0020}                        the file instead of replacing it\n\
+                \n\
+                \u{0020} --column=N             Read tab-separated lines from\n\
+                \u{0020}                        standard input and write field N\n\
+                \u{0020}                        (counting from 1) of each in words,\n\
+                \u{0020}                        passing the rest through\n\
+                \n\
+                \u{0020} -0, --null             End each result with a NUL byte\n\
+                \u{0020}                        instead of a newline (e.g. for\n\
+                \u{0020}                        xargs -0), keeping errors on\n\
+                \u{0020}                        standard error\n\
+                \n\
+                \u{0020} --quiet                Don't write any errors (the exit\n\
+                \u{0020}                        code still tells if there were any)\n\
+                \n\
+                \u{0020} --errors-only          Write only the errors, without the\n\
+                \u{0020}                        results\n\
+                \n\
+                \u{0020} --histogram            Bucket the numbers and print a bar\n\
+                \u{0020}                        for each bucket, labeled in words\n\
+                \n\
+                \u{0020} --list                 Join all the numbers into one\n\
+                \u{0020}                        phrase (e.g. \"seven, nineteen,\n\
+                \u{0020}                        and forty-two\")\n\
+                \n\
+                \u{0020} --csv                  Write input,value,words rows for\n\
+                \u{0020}                        a spreadsheet, with a header\n\
+                \n\
+                \u{0020} --jsonl                Write one JSON object per line; with\n\
+                \u{0020}                        no numbers given, read them from\n\
+                \u{0020}                        standard input as it arrives\n\
+                \n\
+                \u{0020} --follow               Read numbers from standard input\n\
+                \u{0020}                        until it ends (e.g. from tail -f),\n\
+                \u{0020}                        writing each line's results at once\n\
+                \n\
+                \u{0020} --range=A..B           Write every number from A up to\n\
+                \u{0020}                        (or down to) B, or through B with\n\
+                \u{0020}                        A..=B, as it is converted\n\
+                \n\
+                \u{0020} --step=N               With --range=, count by N\n\
+                \n\
+                \u{0020} --random=N             Write N random numbers, from 0 to\n\
+                \u{0020}                        1,000,000 unless --min=A or --max=B\n\
+                \u{0020}                        is given; --seed=S gives the same\n\
+                \u{0020}                        numbers each time\n\
+                \n\
+                \u{0020} --strict               Reject a number with any character\n\
+                \u{0020}                        other than digits, _, and , (e.g.\n\
+                \u{0020}                        \"4x3\") instead of skipping it\n\
+                \n\
+                \u{0020} --reverse              Read each argument (or, if there\n\
+                \u{0020}                        are none, each line of input) as\n\
+                \u{0020}                        words and print the number, e.g.\n\
+                \u{0020}                        \"three-hundred and five\" gives 305;\n\
+                \u{0020}                        with --lang=, reads words in that\n\
+                \u{0020}                        language\n\
+                \n\
+                \u{0020} --hyphen=(all | tens | none)\n\
+                \n\
+                \u{0020}                        Hyphenate \"three-hundred\" and\n\
+                \u{0020}                        \"forty-seven\" (all, the default),\n\
+                \u{0020}                        only \"forty-seven\" (tens), or\n\
+                \u{0020}                        neither (none)\n\
+                \n\
+                \u{0020} --case=(lower | upper | title | sentence)\n\
+                \n\
+                \u{0020}                        Letter case of the words (e.g.\n\
+                \u{0020}                        \"Four-Hundred and Twenty\" for title)\n\
+                \n\
+                \u{0020} --titlecase, --uppercase, --sentence\n\
+                \n\
+                \u{0020}                        Short for --case=title, upper, or\n\
+                \u{0020}                        sentence\n\
+                \n\
+                \u{0020} --identifier=(snake | kebab | camel)\n\
+                \n\
+                \u{0020}                        Write the words as an identifier,\n\
+                \u{0020}                        without \"and\" or punctuation (e.g.\n\
+                \u{0020}                        \"four_hundred_twenty\" for snake)\n\
+                \n\
+                \u{0020} --digits=(plain | nato | niner | icao)\n\
+                \n\
+                \u{0020}                        Spell each argument one digit at a\n\
+                \u{0020}                        time for a read-back, keeping\n\
+                \u{0020}                        leading zeros: \"zero four two\n\
+                \u{0020}                        seven\", \"Zero Four Two Seven\",\n\
+                \u{0020}                        \"Fife Niner\" (niner), or \"fower\n\
+                \u{0020}                        too seven\" (icao)\n\
+                \n\
+                \u{0020} --zero=WORD            Use WORD for zero (e.g. \"nought\")\n\
+                \n\
+                \u{0020} --scale=(short | long | milliard | indian | myriad)\n\
+                \n\
+                \u{0020}                        Name 10^9 \"billion\" (short, the\n\
+                \u{0020}                        default), \"thousand million\" (long),\n\
+                \u{0020}                        or \"milliard\" (milliard), or group\n\
+                \u{0020}                        by lakh and crore (indian) or by\n\
+                \u{0020}                        ten thousands (myriad); e.g.\n\
+                \u{0020}                        1000000000 is\n\
+                \u{0020}                          short:    one billion\n\
+                \u{0020}                          long:     one thousand million\n\
+                \u{0020}                          milliard: one milliard\n\
+                \u{0020}                          indian:   one-hundred crore\n\
+                \u{0020}                          myriad:   ten oku\n\
+                \n\
+                \u{0020} --lang=CODE            Write the words in another\n\
+                \u{0020}                        language: en (the default),\n\
+                \u{0020}                        ar, cy, cy-traditional, da,\n\
+                \u{0020}                        de, eo, es, fr, ga, hi, it,\n\
+                \u{0020}                        ja, ja-kana, ko, ko-native,\n\
+                \u{0020}                        nb (or no), nl, pl, pt (or\n\
+                \u{0020}                        pt-pt), pt-br, ru, sv, tr, uk,\n\
+                \u{0020}                        zh, zh-daxie; --locale=TAG also\n\
+                \u{0020}                        takes a locale such as fr_CA.UTF-8\n\
+                \n\
+                \u{0020} --eastern-numerals     Write the numeral before each\n\
+                \u{0020}                        number in Eastern Arabic digits\n\
+                \u{0020}                        (e.g. \"٤٢\")\n\
+                \n\
+                \u{0020} --currency=CODE        Write each number as an amount of\n\
+                \u{0020}                        money in the currency CODE (usd,\n\
+                \u{0020}                        gbp, eur, inr, jpy, kwd, or bhd)\n\
+                \n\
+                \u{0020} --legal                Write each number as an amount\n\
+                \u{0020}                        for a contract, restating it in\n\
+                \u{0020}                        numerals (e.g. \"One Dollar and\n\
+                \u{0020}                        Fifty Cents (US$1.50)\")\n\
+                \n\
+                \u{0020} --ssml=(spelled | say-as)\n\
+                \n\
+                \u{0020}                        Write the output as an SSML\n\
+                \u{0020}                        document for speech synthesis,\n\
+                \u{0020}                        with each number spelled out as a\n\
+                \u{0020}                        sentence (spelled, the default for\n\
+                \u{0020}                        --ssml) or left to the speech\n\
+                \u{0020}                        engine as a cardinal (say-as)\n\
+                \n\
+                \u{0020} --ssml-break=MS        Like --ssml, but pause for MS\n\
+                \u{0020}                        milliseconds between groups\n\
+                \n\
+                \u{0020} --negative-money=(word | parentheses | deficit)\n\
+                \n\
+                \u{0020}                        Write negative amounts of money as\n\
+                \u{0020}                        \"negative one-hundred dollars\"\n\
+                \u{0020}                        (the default), \"(one-hundred\n\
+                \u{0020}                        dollars)\", or \"one-hundred dollars\n\
+                \u{0020}                        deficit\"\n\
+                \n\
+                \u{0020} --round=(half-up | half-even | truncate)\n\
+                \n\
+                \u{0020}                        Round amounts of money with more\n\
+                \u{0020}                        decimal places than the currency\n\
+                \u{0020}                        has (by default, they are errors)\n\
+                \n\
+                \u{0020} --cheque[=only]        Write each number as an amount\n\
+                \u{0020}                        for a cheque (e.g. \"One and\n\
+                \u{0020}                        50/100 Dollars\"), ending in\n\
+                \u{0020}                        \"Only\" with --cheque=only\n\
+                \n\
+                \u{0020} --article              Write \"a hundred\" or \"a thousand\"\n\
+                \u{0020}                        instead of \"one hundred\" or \"one\n\
+                \u{0020}                        thousand\" at the start of a number\n\
+                \n\
+                \u{0020} --negative-word=WORD   Use WORD for negative numbers\n\
+                \u{0020}                        (e.g. \"minus\")\n\
+                \n\
+                \u{0020} --negative-place=(before | after)\n\
+                \n\
+                \u{0020}                        Put the negative word before (the\n\
+                \u{0020}                        default) or after the number, as in\n\
+                \u{0020}                        \"five below zero\"\n\
+                \n\
+                \u{0020} --group-sep=TEXT       Join groups with TEXT instead of\n\
+                \u{0020}                        \", \" (\\n and \\t are newline\n\
+                \u{0020}                        and tab)\n\
+                \n\
+                \u{0020} --ordinal=(suffix | words)\n\
+                \n\
+                \u{0020}                        Write ordinals instead, either as\n\
+                \u{0020}                        numerals (e.g. \"42nd\") or as\n\
+                \u{0020}                        words (e.g. \"forty-second\")\n\
+                \n\
+                \u{0020} --signed               Read a leading \"-\" on any argument\n\
+                \u{0020}                        as a negative number rather than\n\
+                \u{0020}                        an option (e.g. --signed -42 7)\n\
+                \u{0020}                        (-0 is then zero; use --null)\n\
+                \n\
+                \u{0020} --                     Treat the remaining arguments as\n\
+                \u{0020}                        numbers, where a leading \"-\" makes\n\
+                \u{0020}                        the number negative (e.g. -- -412)\n\
+                \n\
+                Examples:\n\
+                \n\
+                {}\n\
+                {}\n\
+                \n\
+                Note: values supported range from {} to {}\
+            ",
+            COPYRIGHT_INFO,
+            prog_name,
+            prog_name,
+            prog_name,
+            prog_name,
+            example_session(&["234", "409_343", "305814"], prog_name.as_str()),
+            example_session(
+                &["--and=last", "--minimal", "234", "409_343", "305814"],
+                prog_name.as_str()
+            ),
+            i64::MIN,
+            u128::MAX,
+        )
+    }
+
+    fn example_session(inputs: &[&str], prog_name: &str) -> String {
+        let mut args: Vec<&str> = vec![prog_name];
+        for k in inputs {
+            args.push(k)
+        }
+        let command_line = args.join(" ");
+        let config = Config::parse(args.iter().map(|x| String::from(*x)).collect());
+        let (output, _) = config.process();
+        format!("$ {}\n{}", command_line, output)
+    }
+
+    impl Config {
+        /// A `Config` with nothing to convert and every option at its default, for the parsers
+        /// to fill in
+        fn empty(prog_name: String) -> Self {
+            Self {
+                output_components: Ok(Vec::new()),
+                inputs: Vec::new(),
+                help: false,
+                and_help: false,
+                minimal_output: false,
+                copy: false,
+                histogram: false,
+                list: false,
+                csv: false,
+                jsonl: false,
+                follow: false,
+                reverse: false,
+                digits: None,
+                and_behavior: AndBehavior::All,
+                ssml: None,
+                style: Style::new(),
+                styles: Vec::new(),
+                bench: None,
+                normalize: None,
+                output: None,
+                append: false,
+                null: false,
+                quiet: false,
+                errors_only: false,
+                column: None,
+                template: None,
+                version: false,
+                usage_error: false,
+                strict: false,
+                range: None,
+                random: None,
+                prog_name,
+            }
+        }
+
+        /// Parses the command-line arguments and encodes them in a `Config`
+        pub fn parse(args: Vec<String>) -> Self {
+            let prog_name = args[0].clone();
+            if args.len() < 2 {
+                return Self {
+                    output_components: Err(format!(
+                        "No arguments. For help, run:\n$ {} --help",
+                        prog_name
+                    )),
+                    ..Self::empty(prog_name)
+                };
+            }
+            if args[1] == "bench" {
+                return Self::parse_bench(prog_name, &args[2..]);
+            }
+            if args[1] == "normalize" {
+                return Self::parse_normalize(prog_name, &args[2..]);
+            }
+
+            // `--range 1..100` and `--random 5` are read as `--range=1..100` and `--random=5`
+            let args = join_option_values(&args);
+            let mut help: bool = false;
+            let mut version: bool = false;
+            let mut and_help: bool = false;
+            let mut minimal_output: bool = false;
+            let mut copy: bool = false;
+            let mut histogram: bool = false;
+            let mut list: bool = false;
+            let mut csv: bool = false;
+            let mut output: Option<String> = None;
+            let mut append: bool = false;
+            let mut null: bool = false;
+            let mut quiet: bool = false;
+            let mut errors_only: bool = false;
+            let mut column: Option<usize> = None;
+            let mut template: Option<String> = None;
+            let mut range: Option<NumberRange> = None;
+            let mut step: Option<u128> = None;
+            let mut count: Option<u64> = None;
+            let mut min: Option<u64> = None;
+            let mut max: Option<u64> = None;
+            let mut seed: Option<u64> = None;
+            let mut ssml: Option<SsmlStyle> = None;
+            let mut style = Style::new();
+            let mut snapshots: Vec<(AndBehavior, Style)> = Vec::new();
+            let mut and_behavior: AndBehavior = AndBehavior::All;
+            let mut options_ended = false;
+            let mut usage_error = false;
+            let reverse = args[1..].iter().any(|x| x.to_lowercase() == "--reverse");
+            let jsonl = args[1..].iter().any(|x| x.to_lowercase() == "--jsonl");
+            let follow = args[1..].iter().any(|x| x.to_lowercase() == "--follow");
+            let strict = args[1..].iter().any(|x| x.to_lowercase() == "--strict");
+            let signed = args[1..].iter().any(|x| x.to_lowercase() == "--signed");
+            let streams = jsonl
+                || follow
+                || args[1..]
+                    .iter()
+                    .any(|x| x.to_lowercase().starts_with("--column="));
+            let digits =
+                args[1..]
+                    .iter()
+                    .rev()
+                    .find_map(|x| match InputComponent::parse_single_input(x) {
+                        InputComponent::DigitsOption(k) => Some(k),
+                        _ => None,
+                    });
+            // each component is kept with the text it was read from, for the input column
+            // of --csv
+            let mut input_cmpts: Vec<(InputComponent, String)> = args[1..]
+                .iter()
+                .flat_map(|x| {
+                    // with --signed, "-42" and "-0" are numbers rather than options (--null
+                    // still ends lines with NUL)
+                    let option =
+                        x.starts_with("--") || (x.len() > 1 && x.starts_with('-') && !signed);
+                    if reverse && (options_ended || !option) {
+                        return vec![(InputComponent::ToParse(x.clone()), x.clone())];
+                    }
+                    if let Some(alphabet) = digits {
+                        if options_ended || !option {
+                            return vec![(InputComponent::ToSpell(x.clone(), alphabet), x.clone())];
+                        }
+                    }
+                    if options_ended || (signed && !option) {
+                        let k = InputComponent::parse_signed_number(x).strictly(x, strict);
+                        return vec![(k, x.clone())];
+                    }
+                    match InputComponent::parse_single_input(x) {
+                        InputComponent::Paste => InputComponent::from_clipboard(),
+                        InputComponent::File(path) => {
+                            InputComponent::from_file(&path, reverse, digits, strict)
+                        }
+                        InputComponent::EndOfOptions => {
+                            options_ended = true;
+                            vec![]
+                        }
+                        k @ InputComponent::Error(_) if option => {
+                            usage_error = true;
+                            vec![(k, x.clone())]
+                        }
+                        k => vec![(k.strictly(x, strict && !option), x.clone())],
+                    }
+                })
+                .collect();
+            // with --jsonl, --follow, or --column=, standard input is streamed a line at a time by
+            // `process_line`
+            if reverse
+                && !streams
+                && !input_cmpts
+                    .iter()
+                    .any(|(x, _)| matches!(x, InputComponent::ToParse(_)))
+            {
+                input_cmpts.extend(InputComponent::from_stdin());
+            }
+            for (k, _) in input_cmpts.clone() {
+                match k {
+                    InputComponent::Help => {
+                        help = true;
+                    }
+                    InputComponent::Version => {
+                        version = true;
+                    }
+                    InputComponent::AndOption(k) => {
+                        and_behavior = k;
+                    }
+                    InputComponent::MinimalOutput => {
+                        minimal_output = true;
+                    }
+                    InputComponent::AndHelp => {
+                        and_help = true;
+                    }
+                    InputComponent::Copy => {
+                        copy = true;
+                    }
+                    InputComponent::Histogram => {
+                        histogram = true;
+                    }
+                    InputComponent::List => {
+                        list = true;
+                    }
+                    InputComponent::Csv => {
+                        csv = true;
+                    }
+                    InputComponent::Output(k) => {
+                        output = Some(k);
+                    }
+                    InputComponent::Append => {
+                        append = true;
+                    }
+                    InputComponent::Null => {
+                        null = true;
+                    }
+                    InputComponent::Quiet => {
+                        quiet = true;
+                    }
+                    InputComponent::ErrorsOnly => {
+                        errors_only = true;
+                    }
+                    InputComponent::Column(k) => {
+                        column = Some(k);
+                    }
+                    InputComponent::Template(k) => {
+                        template = Some(k);
+                    }
+                    InputComponent::RangeOption(k) => {
+                        range = Some(k);
+                    }
+                    InputComponent::StepOption(k) => {
+                        step = Some(k);
+                    }
+                    InputComponent::RandomOption(k) => {
+                        count = Some(k);
+                    }
+                    InputComponent::MinOption(k) => {
+                        min = Some(k);
+                    }
+                    InputComponent::MaxOption(k) => {
+                        max = Some(k);
+                    }
+                    InputComponent::SeedOption(k) => {
+                        seed = Some(k);
+                    }
+                    InputComponent::EasternNumerals => {
+                        style.eastern_numerals = true;
+                    }
+                    InputComponent::ChequeOption(only) => {
+                        style.cheque = Some(only);
+                    }
+                    InputComponent::Legal => {
+                        style.legal = true;
+                    }
+                    InputComponent::SsmlOption(k) => {
+                        ssml = Some(k);
+                    }
+                    InputComponent::CurrencyOption(k) => {
+                        style.currency = Some(k);
+                    }
+                    InputComponent::RoundingOption(k) => {
+                        style.rounding = Some(k);
+                    }
+                    InputComponent::NegativeMoneyOption(k) => {
+                        style.negative_amount = k;
+                    }
+                    InputComponent::OrdinalOption(k) => {
+                        style.ordinal = Some(k);
+                    }
+                    InputComponent::HyphenOption(k) => {
+                        style.format = style.format.hyphenation(k);
+                    }
+                    InputComponent::GroupSeparator(k) => {
+                        style.format = style.format.group_separator(&k);
+                    }
+                    InputComponent::CaseOption(k) => {
+                        style.format = style.format.case(k);
+                    }
+                    InputComponent::IdentifierOption(k) => {
+                        style.identifier = Some(k);
+                    }
+                    InputComponent::ZeroWord(k) => {
+                        style.format = style.format.zero_word(&k);
+                    }
+                    InputComponent::NegativeWord(k) => {
+                        style.format = style.format.negative_word(&k);
+                    }
+                    InputComponent::NegativePlacementOption(k) => {
+                        style.format = style.format.negative_placement(k);
+                    }
+                    InputComponent::Article => {
+                        style.format = style.format.article(true);
+                    }
+                    InputComponent::ScaleOption(k) => {
+                        style.format = style.format.scale(k);
+                    }
+                    InputComponent::LanguageOption(k) => {
+                        style.language = match k.as_str() {
+                            "en" => None,
+                            code => language_from_code(code).map(Rc::from),
+                        };
+                        style.reader = match &style.language {
+                            Some(language) if reverse => {
+                                Some(Rc::new(GroupReader::new(&**language)))
+                            }
+                            _ => None,
+                        };
+                    }
+                    _ => {}
+                }
+                // the options apply to the numbers after them, so that `112 --and=none 113`
+                // writes 112 with "and" and 113 without
+                snapshots.push((and_behavior, style.clone()));
+            }
+            let ((output_components, inputs), styles): (
+                (Vec<OutputComponent>, Vec<String>),
+                Vec<Style>,
+            ) = input_cmpts
+                .iter()
+                .zip(snapshots)
+                .filter_map(|((x, text), (and_behavior, style))| {
+                    OutputComponent::from_input(x, and_behavior)
+                        .map(|c| ((c, text.trim().to_string()), style))
+                })
+                .unzip();
+            let range = range.map(|r| r.step(step.unwrap_or(1)));
+            let (low, high) = (min.unwrap_or(0), max.unwrap_or(DEFAULT_MAX));
+            let random = count.map(|n| match seed {
+                Some(k) => (n, UniformNumbers::new(k, low, high)),
+                None => (n, UniformNumbers::from_clock(low, high)),
+            });
+            // --range= and --random= write numbers of their own, one at a time
+            let generator = match (range, count) {
+                (Some(_), _) => Some("--range"),
+                (None, Some(_)) => Some("--random"),
+                (None, None) => None,
+            };
+            let conflict = [
+                (range.is_some() && count.is_some(), "--random"),
+                (histogram, "--histogram"),
+                (list, "--list"),
+                (ssml.is_some(), "--ssml"),
+                (copy, "--copy"),
+                (reverse, "--reverse"),
+                (
+                    output_components
+                        .iter()
+                        .any(|c| !matches!(c, OutputComponent::Error(_))),
+                    "numbers to convert",
+                ),
+            ]
+            .into_iter()
+            .find_map(|(given, name)| given.then_some(name));
+            let generator_error = if quiet && errors_only {
+                Some("--quiet can't be combined with --errors-only".to_string())
+            } else if step.is_some() && range.is_none() {
+                Some("--step needs --range".to_string())
+            } else if count.is_none() && (min.is_some() || max.is_some() || seed.is_some()) {
+                Some("--min, --max, and --seed need --random".to_string())
+            } else if range.is_some_and(|r| r.len() > MAX_RANGE_LEN) {
+                Some(format!("Range has more than {} numbers", MAX_RANGE_LEN))
+            } else if count.is_some_and(|n| n > MAX_COUNT) {
+                Some(format!(
+                    "Can't write more than {} random numbers",
+                    MAX_COUNT
+                ))
+            } else if low > high {
+                Some(format!("--min is more than the largest number, {}", high))
+            } else {
+                generator
+                    .zip(conflict)
+                    .map(|(g, name)| format!("{} can't be combined with {}", g, name))
+            };
+            let output_components = match generator_error {
+                Some(e) => Err(format!("{}\nFor help, run: {} --help", e, prog_name)),
+                None => Ok(output_components),
+            };
+
+            Self {
+                output_components,
+                inputs,
+                help,
+                and_help,
+                minimal_output,
+                copy,
+                histogram,
+                list,
+                csv,
+                jsonl,
+                follow,
+                reverse,
+                digits,
+                and_behavior,
+                ssml,
+                style,
+                styles,
+                output,
+                append,
+                null,
+                quiet,
+                errors_only,
+                column,
+                template,
+                version,
+                usage_error,
+                strict,
+                range,
+                random,
+                ..Self::empty(prog_name)
+            }
+        }
+
+        /// Parses the arguments following the `bench` subcommand
+        fn parse_bench(prog_name: String, args: &[String]) -> Self {
+            let mut count: Result<u64, String> = Ok(benchmark::DEFAULT_COUNT);
+            let mut rest = args.iter();
+            while count.is_ok() {
+                let Some(arg) = rest.next() else {
+                    break;
+                };
+                let value = match arg.strip_prefix("--n=") {
+                    Some(v) => Ok(v),
+                    None if arg == "--n" => rest
+                        .next()
+                        .map(|x| x.as_str())
+                        .ok_or_else(|| "Missing count after --n".to_string()),
+                    None => Err(format!("Invalid bench option {}", arg)),
+                };
+                count = value.and_then(|v| {
+                    v.replace(['_', ','], "")
+                        .parse::<u64>()
+                        .map_err(|_| format!("Invalid count: {}", v))
+                });
+            }
+            Self {
+                output_components: count
+                    .as_ref()
+                    .map(|_| Vec::new())
+                    .map_err(|e| format!("{}\nFor help, run: {} --help", e, prog_name)),
+                bench: count.ok(),
+                ..Self::empty(prog_name)
+            }
+        }
+
+        /// Parses the arguments following the `normalize` subcommand
+        fn parse_normalize(prog_name: String, args: &[String]) -> Self {
+            let mut direction = Direction::ToWords;
+            let mut markup = Markup::Plain;
+            let mut and_behavior: Result<AndBehavior, String> = Ok(AndBehavior::All);
+            for arg in args {
+                match arg.to_lowercase().as_str() {
+                    "--reverse" => direction = Direction::ToNumerals,
+                    "--markup=plain" => markup = Markup::Plain,
+                    "--markup=markdown" => markup = Markup::Markdown,
+                    "--markup=html" => markup = Markup::Html,
+                    _ => {
+                        and_behavior = and_behavior.and_then(|_| {
+                            match InputComponent::parse_single_input(arg) {
+                                InputComponent::AndOption(k) => Ok(k),
+                                InputComponent::Error(e) => Err(e),
+                                _ => Err(format!("Invalid normalize option {}", arg)),
+                            }
+                        })
+                    }
+                }
+            }
+            let normalizer =
+                and_behavior.map(|k| Normalizer::new(direction).markup(markup).and_behavior(k));
+            Self {
+                output_components: normalizer
+                    .as_ref()
+                    .map(|_| Vec::new())
+                    .map_err(|e| format!("{}\nFor help, run: {} --help", e, prog_name)),
+                normalize: normalizer.ok(),
+                ..Self::empty(prog_name)
+            }
+        }
+
+        /// The normalizer to stream standard input through, for the `normalize` subcommand
+        pub fn normalizer(&self) -> Option<Normalizer> {
+            self.normalize.clone()
+        }
+
+        /// The numeral and the words for one input, or, for `--reverse`, the number read from
+        /// the words and the words themselves
+        fn convert(&self, c: &OutputComponent, style: &Style) -> Result<(String, String), String> {
+            match c {
+                OutputComponent::ToConvert {
+                    value,
+                    and_behavior,
+                } => {
+                    let numeral = value.to_string();
+                    let options = style.format.clone().and_behavior(*and_behavior);
+                    if style.money_output() {
+                        let words = style.money_words(&numeral, &options)?;
+                        Ok((numeral, words))
+                    } else if let Some(language) = &style.language {
+                        // values above i128::MAX are still written, just not signed
+                        let words = match i128::try_from(*value) {
+                            Ok(v) => style.localized_words(language.as_ref(), v, &options)?,
+                            Err(_) => language.cardinal_with(*value, &options),
+                        };
+                        Ok((numeral, words))
+                    } else {
+                        let words = uncased_words(*value, &options);
+                        Ok((numeral.clone(), style.apply_ordinal(&numeral, words)))
+                    }
+                }
+                OutputComponent::ToConvertNegative {
+                    value,
+                    and_behavior,
+                } => {
+                    if self.histogram {
+                        return Err(format!("Histogram skips negative value: {}", value));
+                    }
+                    let numeral = value.to_string();
+                    let options = style.format.clone().and_behavior(*and_behavior);
+                    if style.money_output() {
+                        let words = style.money_words(&numeral, &options)?;
+                        Ok((numeral, words))
+                    } else if let Some(language) = &style.language {
+                        let words =
+                            style.localized_words(language.as_ref(), *value as i128, &options)?;
+                        Ok((numeral, words))
+                    } else {
+                        let words = uncased_signed_words(*value, &options);
+                        Ok((numeral.clone(), style.apply_ordinal(&numeral, words)))
+                    }
+                }
+                #[cfg(feature = "bigint")]
+                OutputComponent::ToConvertBig {
+                    digits,
+                    and_behavior,
+                } => {
+                    if self.histogram {
+                        return Err(format!(
+                            "Histogram skips value too big to bucket: {}",
+                            digits
+                        ));
+                    }
+                    if style.language.is_some() {
+                        return Err(format!("Value too big for this language: {}", digits));
+                    }
+                    let words = style
+                        .format
+                        .restyle_uncased(&to_word_big(digits, *and_behavior).expect("digits only"));
+                    Ok((digits.clone(), style.apply_ordinal(digits, words)))
+                }
+                OutputComponent::ToConvertDecimal {
+                    value,
+                    and_behavior,
+                } => {
+                    if self.histogram {
+                        Err(format!("Histogram skips decimal value: {}", value))
+                    } else if style.money_output() {
+                        let options = style.format.clone().and_behavior(*and_behavior);
+                        let words = style.money_words(&value.to_string(), &options)?;
+                        Ok((value.to_string(), words))
+                    } else if style.ordinal.is_some() {
+                        Err(format!("No ordinal for decimal value: {}", value))
+                    } else if style.language.is_some() {
+                        Err(format!(
+                            "Decimal values are only available in English: {}",
+                            value
+                        ))
+                    } else {
+                        let words = style.format.restyle_uncased(&value.to_words(*and_behavior));
+                        Ok((value.to_string(), words))
+                    }
+                }
+                OutputComponent::ToConvertMoney {
+                    amount,
+                    and_behavior,
+                } => {
+                    if self.histogram {
+                        Err(format!("Histogram skips amount of money: {}", amount))
+                    } else if style.ordinal.is_some() {
+                        Err(format!("No ordinal for amount of money: {}", amount))
+                    } else if style.language.is_some() {
+                        Err(format!(
+                            "Amounts of money are only available in English: {}",
+                            amount
+                        ))
+                    } else {
+                        let options = style.format.clone().and_behavior(*and_behavior);
+                        let words = style.money_words(amount, &options)?;
+                        Ok((amount.clone(), words))
+                    }
+                }
+                OutputComponent::ToParse(text) => {
+                    let parsed = match &style.language {
+                        Some(language) => match &style.reader {
+                            Some(reader) => language.parse_cardinal_with(text, reader),
+                            None => language.parse_cardinal(text),
+                        }
+                        .map_err(|e| e.trim_end_matches('.').to_string()),
+                        None => from_words(&text.to_lowercase()).map_err(|e| e.to_string()),
+                    };
+                    match parsed {
+                        Ok(value) => Ok((value.to_string(), text.clone())),
+                        Err(e) => Err(format!("Could not read \"{}\": {}", text, e)),
+                    }
+                }
+                OutputComponent::ToSpell { text, alphabet } => {
+                    if self.histogram {
+                        Err(format!("Histogram skips digits: {}", text))
+                    } else if style.language.is_some() {
+                        Err(format!("Digits are only spelled in English: {}", text))
+                    } else {
+                        let words = spell_digits_with(text, *alphabet, style.format.zero())
+                            .map_err(|e| format!("{}: {}", e.trim_end_matches('.'), text))?;
+                        Ok((text.clone(), words))
+                    }
+                }
+                OutputComponent::Error(e) => Err(e.clone()),
+            }
+        }
+
+        /// Whether the numbers are to be streamed from standard input through `process_line`,
+        /// as with `--jsonl`, `--follow`, or `--column=` when no numbers are given
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use numbers_into_words::Config;
+        /// let args = vec![String::from("numbers_into_words"), String::from("--jsonl")];
+        /// let config = Config::parse(args);
+        /// assert!(config.streams_input());
+        /// assert_eq!(
+        ///     config.process_line("7 x").0,
+        ///     "{\"input\":\"7\",\"value\":7,\"words\":\"seven\"}\n\
+        ///      {\"input\":\"x\",\"error\":\"Invalid input: x\"}"
+        ///         .to_string()
+        /// );
+        /// ```
+        pub fn streams_input(&self) -> bool {
+            (self.jsonl || self.follow || self.column.is_some())
+                && !self.help
+                && self.bench.is_none()
+                && matches!(&self.output_components, Ok(cmpts) if cmpts.is_empty())
+        }
+
+        /// Returns the output for one line of standard input, read the same way as a line of
+        /// a file given with `--file=`, or, with `--column=`, the line with that field written
+        /// in words
+        pub fn process_line(&self, line: &str) -> (String, Status) {
+            let (results, diagnostics, status) = self.process_line_split(line);
+            (results + &diagnostics, status)
+        }
+
+        /// Like `process_line`, but keeps the results apart from the errors, as in
+        /// `process_split`
+        ///
+        /// With `--column=`, a field that can't be converted is left as it was, with an error,
+        /// as is a line without that field. Blank lines and empty fields pass through.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use numbers_into_words::{Config, Status};
+        /// let args = vec!["numbers_into_words", "--column=2", "--case=title"];
+        /// let config = Config::parse(args.into_iter().map(String::from).collect());
+        /// assert_eq!(
+        ///     config.process_line_split("widgets\t1005\tin stock"),
+        ///     (
+        ///         "widgets\tOne Thousand, Five\tin stock".to_string(),
+        ///         String::new(),
+        ///         Status::Success
+        ///     )
+        /// );
+        /// assert_eq!(
+        ///     config.process_line_split("item\tcount"),
+        ///     (
+        ///         "item\tcount".to_string(),
+        ///         "\nInvalid input: count".to_string(),
+        ///         Status::Failure
+        ///     )
+        /// );
+        /// ```
+        pub fn process_line_split(&self, line: &str) -> (String, String, Status) {
+            if let Some(column) = self.column {
+                let mut fields: Vec<&str> = line.split('\t').collect();
+                let converted = match fields.get(column - 1) {
+                    Some(field) if !field.trim().is_empty() => self.convert_field(field),
+                    None if !line.trim().is_empty() => {
+                        Err(format!("Line has fewer than {} fields", column))
+                    }
+                    _ => {
+                        return self.shown((
+                            self.terminate(line.to_string()),
+                            String::new(),
+                            Status::Success,
+                        ))
+                    }
+                };
+                return self.shown(match converted {
+                    Ok(words) => {
+                        fields[column - 1] = &words;
+                        (
+                            self.terminate(fields.join("\t")),
+                            String::new(),
+                            Status::Success,
+                        )
+                    }
+                    Err(e) => (
+                        self.terminate(line.to_string()),
+                        format!("\n{}", e),
+                        Status::Failure,
+                    ),
+                });
+            }
+            let (cmpts, inputs): (Vec<OutputComponent>, Vec<String>) =
+                InputComponent::from_line(line, self.reverse, self.digits, self.strict)
+                    .into_iter()
+                    .filter_map(|(x, text)| {
+                        OutputComponent::from_input(&x, self.and_behavior).map(|c| (c, text))
+                    })
+                    .unzip();
+            self.render_split(&cmpts, &inputs, &[])
+        }
+
+        /// The numbers to write one at a time with `process_value`, if `--range=` or
+        /// `--random=` was given (and not `--help`)
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use numbers_into_words::Config;
+        /// let args = vec!["numbers_into_words", "--range=1..=3", "--minimal"];
+        /// let config = Config::parse(args.into_iter().map(String::from).collect());
+        /// let words: Vec<String> = config
+        ///     .generated_values()
+        ///     .unwrap()
+        ///     .map(|x| config.process_value(x).0)
+        ///     .collect();
+        /// assert_eq!(words, vec!["one", "two", "three"]);
+        /// ```
+        pub fn generated_values(&self) -> Option<Box<dyn Iterator<Item = u128>>> {
+            if self.help
+                || self.version
+                || !matches!(&self.output_components, Ok(cmpts) if cmpts.is_empty())
+            {
+                return None;
+            }
+            match (self.range, &self.random) {
+                (Some(range), _) => Some(Box::new(range.iter())),
+                (None, Some((count, numbers))) => Some(Box::new(
+                    numbers.clone().take(*count as usize).map(u128::from),
+                )),
+                (None, None) => None,
+            }
+        }
+
+        /// The line to write before the numbers of `--range=` or `--random=`: the header
+        /// row, for `--csv`
+        pub fn values_header(&self) -> Option<&'static str> {
+            if self.csv {
+                Some(CSV_HEADER)
+            } else {
+                None
+            }
+        }
+
+        /// Returns the output for one number of `--range=` or `--random=`, as if it were an
+        /// argument
+        pub fn process_value(&self, value: u128) -> (String, String, Status) {
+            let input = value.to_string();
+            let c = match self.digits {
+                Some(alphabet) => OutputComponent::ToSpell {
+                    text: input.clone(),
+                    alphabet,
+                },
+                None => OutputComponent::ToConvert {
+                    value,
+                    and_behavior: self.and_behavior,
+                },
+            };
+            self.render_split(&[c], &[input], &[])
+        }
+
+        /// The field of each line of standard input to write in words, counting from 1, if
+        /// `--column=` was given
+        pub fn column(&self) -> Option<usize> {
+            self.column
+        }
+
+        /// Words for one field with `--column=`, which must hold exactly one number
+        fn convert_field(&self, field: &str) -> Result<String, String> {
+            let inputs = InputComponent::from_line(field, self.reverse, self.digits, self.strict);
+            let c = match inputs.as_slice() {
+                [(x, _)] => OutputComponent::from_input(x, self.and_behavior),
+                _ => None,
+            }
+            .ok_or_else(|| format!("Invalid input: {}", field.trim()))?;
+            let (label, words) = self.convert(&c, &self.style)?;
+            if let OutputComponent::ToParse(_) = c {
+                return Ok(label);
+            }
+            Ok(match self.style.identifier {
+                Some(style) => identifier(&words, style),
+                None => self.style.format.apply_case(words),
+            })
+        }
+
+        /// Whether results are written at all, which they aren't with `--errors-only`
+        pub fn shows_results(&self) -> bool {
+            !self.errors_only
+        }
+
+        /// Drops the errors and hints from the output for `--quiet` (unless the options
+        /// themselves are wrong), or the results for `--errors-only`; the status is kept, so
+        /// that the exit code still tells how it went
+        fn shown(&self, output: (String, String, Status)) -> (String, String, Status) {
+            let (results, diagnostics, status) = output;
+            if self.quiet && status != Status::UsageError {
+                (results, String::new(), status)
+            } else if self.errors_only && !self.help {
+                let diagnostics = diagnostics.trim_start_matches('\n').to_string();
+                (String::new(), diagnostics, status)
+            } else {
+                (results, diagnostics, status)
+            }
+        }
+
+        /// Ends a line of output with `\0` for `--null`
+        fn terminate(&self, line: String) -> String {
+            if self.null {
+                line + "\0"
+            } else {
+                line
+            }
+        }
+
+        /// Returns the program output appropriate for the command-line arguments used to encode
+        /// the `Config`
+        pub fn process(&self) -> (String, Status) {
+            let (results, diagnostics, status) = self.process_split();
+            (results + &diagnostics, status)
+        }
+
+        /// Like `process`, but keeps the results apart from the errors and hints, so that
+        /// they can be written to different places
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use numbers_into_words::Config;
+        /// let args = vec!["numbers_into_words", "--minimal", "42", "x"];
+        /// let config = Config::parse(args.into_iter().map(String::from).collect());
+        /// let (results, diagnostics, _) = config.process_split();
+        /// assert_eq!(results, "forty-two".to_string());
+        /// assert_eq!(diagnostics, "\nErrors\n-----\nInvalid input: x".to_string());
+        /// ```
+        pub fn process_split(&self) -> (String, String, Status) {
+            if let (Some(count), Ok(_)) = (self.bench, &self.output_components) {
+                return (
+                    benchmark::run(count).to_string(),
+                    String::new(),
+                    Status::Success,
+                );
+            }
+            if self.version {
+                return (version().to_string(), String::new(), Status::Success);
+            }
+            match &self.output_components {
+                Err(e) => (String::new(), e.clone(), Status::UsageError),
+                Ok(cmpts) => self.render_split(cmpts, &self.inputs, &self.styles),
+            }
+        }
+
+        /// The file to write the results to, if `--output=` was given, and whether to append
+        /// to it (`--append`) rather than replace it
+        pub fn output_file(&self) -> Option<(&str, bool)> {
+            self.output.as_deref().map(|path| (path, self.append))
+        }
+
+        /// Whether each line streamed from standard input is to be flushed as soon as it is
+        /// converted, with `--follow`, for pipelines that never end
+        pub fn follows(&self) -> bool {
+            self.follow && self.streams_input()
+        }
+
+        /// Whether each result ends with `\0` instead of a newline, with `-0` or `--null`
+        pub fn null_terminated(&self) -> bool {
+            self.null
+        }
+
+        /// Whether the errors from `process_split` belong on standard error rather than after
+        /// the results, so that the results can be read on their own: with `--output=`,
+        /// `--null`, `--csv`, or `--ssml`
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use numbers_into_words::Config;
+        /// let args = vec!["numbers_into_words", "--csv", "7", "x"];
+        /// let config = Config::parse(args.into_iter().map(String::from).collect());
+        /// assert!(config.errors_apart());
+        /// let (results, _, _) = config.process_split();
+        /// assert_eq!(results, "input,value,words\n7,7,seven");
+        /// ```
+        pub fn errors_apart(&self) -> bool {
+            self.output.is_some() || self.null || self.csv || self.ssml.is_some()
+        }
+
+        /// Writes the output for `cmpts`, where each was read from the text in `inputs` with
+        /// the options in `styles` (or, past its end, the last options given), keeping the
+        /// results apart from the errors and hints, which start with a newline if there are
+        /// results before them
+        fn render_split(
+            &self,
+            cmpts: &[OutputComponent],
+            inputs: &[String],
+            styles: &[Style],
+        ) -> (String, String, Status) {
+            let mut valid = false;
+            let mut errors = false;
+            // counted apart from `valid` and `errors`, which track what is written where
+            let mut converted: usize = 0;
+            let mut failed: usize = 0;
+
+            let mut valid_vec: Vec<String> = Vec::new();
+            let mut error_vec: Vec<String> = Vec::new();
+            let mut words_vec: Vec<String> = Vec::new();
+            let mut values: Vec<u128> = Vec::new();
+            // with --range= or --random=, the header is written once by the caller, from
+            // `values_header`
+            let mut csv_rows: Vec<String> = if self.range.is_some() || self.random.is_some() {
+                Vec::new()
+            } else {
+                vec![CSV_HEADER.to_string()]
+            };
+            let mut json_lines: Vec<String> = Vec::new();
+
+            for (idx, (c, input)) in cmpts.iter().zip(inputs).enumerate() {
+                let style = styles.get(idx).unwrap_or(&self.style);
+                if let OutputComponent::ToConvert { value, .. } = c {
+                    values.push(*value);
+                }
+                let (label, words) = match self.convert(c, style) {
+                    Ok(converted) => converted,
+                    Err(e) => {
+                        failed += 1;
+                        json_lines.push(json_error(input, &e));
+                        match &self.template {
+                            Some(t) if t.contains("{error}") => {
+                                valid_vec.push(fill_template(t, [input, "", "", "", &e]));
+                                valid = true;
+                            }
+                            _ => {
+                                error_vec.push(e);
+                                errors = true;
+                            }
+                        }
+                        continue;
+                    }
+                };
+                if let OutputComponent::ToParse(text) = c {
+                    valid_vec.push(match &self.template {
+                        Some(t) => fill_template(t, [input, &label, text, "", ""]),
+                        None => label.clone(),
+                    });
+                    words_vec.push(label.clone());
+                    csv_rows.push(csv_row(&[input, &label, text]));
+                    json_lines.push(json_line(input, &label, text));
+                    valid = true;
+                    converted += 1;
+                    continue;
+                }
+                let ordinal = match &self.template {
+                    Some(_) => style.template_ordinal(c, &words),
+                    None => String::new(),
+                };
+                let (words, ordinal) = match style.identifier {
+                    Some(k) => (identifier(&words, k), identifier(&ordinal, k)),
+                    None if self.list => (words, ordinal),
+                    None => (
+                        style.format.apply_case(words),
+                        style.format.apply_case(ordinal),
+                    ),
+                };
+                valid_vec.push(match (&self.template, self.ssml) {
+                    (Some(t), _) => fill_template(t, [input, &label, &words, &ordinal, ""]),
+                    (None, Some(k)) => fragment(k, &label, &words, style.format.separator()),
+                    (None, None) if self.minimal_output => words.clone(),
+                    (None, None) => format!("{}: {}", style.echo_numeral(&label), words),
+                });
+                csv_rows.push(csv_row(&[input, &label, &words]));
+                json_lines.push(json_line(input, &label, &words));
+                words_vec.push(words);
+                valid = true;
+                converted += 1;
+            }
+            if self.jsonl {
+                // errors are written as objects too, so that every line of the output is JSON
+                valid = !json_lines.is_empty();
+                valid_vec = json_lines;
+                error_vec.clear();
+                errors = false;
+            } else if self.histogram && valid {
+                valid_vec = vec![histogram_to_words(&values, self.and_behavior)];
+                words_vec = valid_vec.clone();
+            } else if self.list && valid {
+                let phrase = join_list(&words_vec, &ListOptions::new());
+                valid_vec = vec![match self.style.identifier {
+                    Some(_) => phrase,
+                    None => self.style.format.apply_case(phrase),
+                }];
+                words_vec = valid_vec.clone();
+            } else if self.csv && valid {
+                valid_vec = csv_rows;
+            } else if self.ssml.is_some() && self.template.is_none() && valid {
+                valid_vec = vec![speak(&valid_vec)];
+            }
+            if self.copy && !words_vec.is_empty() {
+                if let Err(e) = copy_to_clipboard(&words_vec.join("\n")) {
+                    error_vec.push(e);
+                    errors = true;
+                    failed += 1;
+                }
+            }
+            let mut valid_conversions = String::new();
+            if !valid_vec.is_empty() && self.help {
+                valid_conversions.push_str("\n---\n\n");
+            }
+            for (idx, k) in valid_vec.iter().enumerate() {
+                valid_conversions.push_str(k.as_str());
+                if self.null {
+                    valid_conversions.push('\0');
+                } else if idx != valid_vec.len() - 1 {
+                    valid_conversions.push('\n');
+                }
+            }
+
+            let errors = if !error_vec.is_empty() {
+                format!(
+                    "{}Errors\n-----\n{}",
+                    if valid && errors { "\n" } else { "" },
+                    error_vec.join("\n")
+                )
+            } else {
+                String::from("")
+            };
+            let status = if self.usage_error {
+                Status::UsageError
+            } else if failed == 0 {
+                Status::Success
+            } else if converted == 0 {
+                Status::Failure
+            } else {
+                Status::PartialFailure
+            };
+
+            self.shown((
+                format!(
+                    "{}{}{}",
+                    if self.help {
+                        help_text(&self.prog_name)
+                    } else {
+                        "".to_string()
+                    },
+                    if self.and_help {
+                        and_help()
+                    } else {
+                        "".to_string()
+                    },
+                    valid_conversions,
+                ),
+                format!(
+                    "{}{}",
+                    errors,
+                    if !error_vec.is_empty() && !valid && !self.help {
+                        format!("\nFor help, run: {} --help", self.prog_name)
+                    } else {
+                        "".to_string()
+                    }
+                ),
+                status,
+            ))
+        }
+    }
+
+    /// Joins an option that takes a value to the argument after it, e.g. `--range 1..100`
+    /// to `--range=1..100` or `--random 5` to `--random=5`, unless it comes after `--`
+    fn join_option_values(args: &[String]) -> Vec<String> {
+        let mut joined: Vec<String> = Vec::new();
+        let mut rest = args.iter();
+        while let Some(arg) = rest.next() {
+            let lower = arg.to_lowercase();
+            let options_ended = joined.iter().any(|x| x == "--");
+            let takes_value = [
+                "--range", "--step", "--random", "--min", "--max", "--seed", "--lang", "--locale",
+            ];
+            if takes_value.contains(&lower.as_str()) && !options_ended {
+                if let Some(value) = rest.next() {
+                    joined.push(format!("{}={}", arg, value));
+                    continue;
+                }
+            }
+            joined.push(arg.clone());
+        }
+        joined
+    }
+
+    /// The language code for a `--lang=` or `--locale=` tag, which may be a locale such as
+    /// `fr_CA.UTF-8`: its encoding is dropped, and a region that isn't a language of its own
+    /// falls back to the language, so that `pt_BR` is `pt-br` but `fr_CA` is `fr`
+    fn locale_code(tag: &str) -> Option<&'static str> {
+        let tag = tag.split('.').next().unwrap_or_default().replace('_', "-");
+        let language = tag.split('-').next().unwrap_or_default();
+        let code = [tag.as_str(), language]
+            .into_iter()
+            .find_map(|code| LANGUAGE_CODES.into_iter().find(|x| *x == code));
+        code
+    }
+
+    /// The placeholders of a `--format=` template, in the order `fill_template` takes them
+    const PLACEHOLDERS: [&str; 5] = ["input", "value", "words", "ordinal", "error"];
+
+    /// Checks that every placeholder in a `--format=` template is one of `PLACEHOLDERS`;
+    /// braces around anything other than a name are left as they are
+    fn check_template(template: &str) -> Result<(), String> {
+        for (start, _) in template.match_indices('{') {
+            let Some(end) = template[start..].find('}') else {
+                break;
+            };
+            let name = &template[start + 1..start + end];
+            let is_name = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+            if is_name && !PLACEHOLDERS.contains(&name) {
+                return Err(format!("Invalid placeholder: {{{}}}", name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Fills in a `--format=` template with the input, value, words, ordinal, and error, in
+    /// that order
+    fn fill_template(template: &str, fields: [&str; 5]) -> String {
+        let mut filled = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            filled.push_str(&rest[..start]);
+            rest = &rest[start..];
+            let placeholder = rest.find('}').and_then(|end| {
+                PLACEHOLDERS
+                    .iter()
+                    .position(|p| *p == &rest[1..end])
+                    .map(|k| (k, end))
+            });
+            match placeholder {
+                Some((k, end)) => {
+                    filled.push_str(fields[k]);
+                    rest = &rest[end + 1..];
+                }
+                None => {
+                    filled.push('{');
+                    rest = &rest[1..];
+                }
+            }
+        }
+        filled.push_str(rest);
+        filled
+    }
+
+    /// A line of `--jsonl` output, where the value is a JSON number unless it is written
+    /// otherwise (e.g. "£12" or "007")
+    fn json_line(input: &str, value: &str, words: &str) -> String {
+        let digits = value.strip_prefix('-').unwrap_or(value);
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, "1"));
+        let number = !whole.is_empty()
+            && !fraction.is_empty()
+            && (whole == "0" || !whole.starts_with('0'))
+            && whole
+                .bytes()
+                .chain(fraction.bytes())
+                .all(|b| b.is_ascii_digit());
+        format!(
+            "{{\"input\":{},\"value\":{},\"words\":{}}}",
+            json_string(input),
+            if number {
+                value.to_string()
+            } else {
+                json_string(value)
+            },
+            json_string(words)
+        )
+    }
+
+    /// A line of `--jsonl` output for an input that could not be converted
+    fn json_error(input: &str, error: &str) -> String {
+        format!(
+            "{{\"input\":{},\"error\":{}}}",
+            json_string(input),
+            json_string(error)
+        )
+    }
+
+    #[cfg(feature = "clipboard")]
+    fn copy_to_clipboard(text: &str) -> Result<(), String> {
+        super::clipboard::copy(text)
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    fn copy_to_clipboard(_text: &str) -> Result<(), String> {
+        Err(CLIPBOARD_DISABLED.to_string())
+    }
+
+    #[cfg(not(feature = "clipboard"))]
+    const CLIPBOARD_DISABLED: &str =
+        "Clipboard support not enabled (rebuild with `--features clipboard`)";
+
+    impl InputComponent {
+        /// Handles a number that does not fit in a `u128`
+        #[cfg(feature = "bigint")]
+        fn too_big(_text: &str, digits: String) -> Self {
+            Self::ToConvertBig(digits.trim_start_matches('0').to_string())
+        }
+
+        /// Handles a number that does not fit in a `u128`
+        #[cfg(not(feature = "bigint"))]
+        fn too_big(text: &str, _digits: String) -> Self {
+            Self::Error(format!("Too big: {}", text))
+        }
+
+        /// Reads whitespace-separated inputs from the system clipboard, each with its text
+        #[cfg(feature = "clipboard")]
+        fn from_clipboard() -> Vec<(Self, String)> {
+            match super::clipboard::paste() {
+                Ok(text) => text
+                    .split_whitespace()
+                    .map(|word| (Self::parse_signed_number(word), word.to_string()))
+                    .collect(),
+                Err(e) => vec![(Self::Error(e), String::new())],
+            }
+        }
+
+        #[cfg(not(feature = "clipboard"))]
+        fn from_clipboard() -> Vec<(Self, String)> {
+            vec![(Self::Error(CLIPBOARD_DISABLED.to_string()), String::new())]
+        }
+
+        /// Reads the numbers in a file for `--file=`, or one phrase per line for `--reverse`,
+        /// labeling any errors with the file name and line number
+        fn from_file(
+            path: &str,
+            reverse: bool,
+            digits: Option<DigitAlphabet>,
+            strict: bool,
+        ) -> Vec<(Self, String)> {
+            let text = match fs::read_to_string(path) {
+                Ok(text) => text,
+                Err(e) => {
+                    return vec![(
+                        Self::Error(format!("Could not read {}: {}", path, e)),
+                        String::new(),
+                    )]
+                }
+            };
+            text.lines()
+                .enumerate()
+                .flat_map(|(idx, line)| {
+                    Self::from_line(line, reverse, digits, strict)
+                        .into_iter()
+                        .map(move |(k, word)| match k {
+                            Self::Error(e) => {
+                                (Self::Error(format!("{}:{}: {}", path, idx + 1, e)), word)
+                            }
+                            k => (k, word),
+                        })
+                })
+                .collect()
+        }
+
+        /// Reads the inputs on one line of a file or of standard input, each with its text:
+        /// the whole line as words for `--reverse`, or else each number on the line, where a
+        /// leading `-` makes it negative
+        fn from_line(
+            line: &str,
+            reverse: bool,
+            digits: Option<DigitAlphabet>,
+            strict: bool,
+        ) -> Vec<(Self, String)> {
+            let words: Vec<&str> = if reverse {
+                vec![line.trim()]
+            } else {
+                line.split_whitespace().collect()
+            };
+            words
+                .into_iter()
+                .filter(|word| !word.is_empty())
+                .map(|word| {
+                    let k = match (reverse, digits) {
+                        (true, _) => Self::ToParse(word.to_string()),
+                        (false, Some(alphabet)) => Self::ToSpell(word.to_string(), alphabet),
+                        (false, None) => Self::parse_signed_number(word).strictly(word, strict),
+                    };
+                    (k, word.to_string())
+                })
+                .collect()
+        }
+
+        /// Reads one phrase per line from standard input for `--reverse`, each with its text
+        fn from_stdin() -> Vec<(Self, String)> {
+            io::stdin()
+                .lines()
+                .filter_map(|line| match line {
+                    Ok(line) if line.trim().is_empty() => None,
+                    Ok(line) => Some((Self::ToParse(line.trim().to_string()), line)),
+                    Err(e) => Some((
+                        Self::Error(format!("Could not read input: {}", e)),
+                        String::new(),
+                    )),
+                })
+                .collect()
+        }
+
+        /// Parses an argument after the `--` that ends the options, where a leading `-` marks
+        /// a negative number
+        fn parse_signed_number(text: &str) -> Self {
+            let magnitude = text.trim().trim_start_matches('-').to_lowercase();
+            if split_currency(&magnitude).is_some() {
+                return Self::ToConvertMoney(text.trim().to_string());
+            }
+            if let Some(k) = Self::parse_scientific(text) {
+                return k;
+            }
+            if text.contains('.') {
+                return Self::parse_decimal(text);
+            }
+            match text.trim().strip_prefix('-') {
+                Some(magnitude) => match Self::parse_single_input(magnitude) {
+                    Self::ToConvert(0) => Self::ToConvert(0),
+                    Self::ToConvert(x) => match u64::try_from(x)
+                        .ok()
+                        .and_then(|x| 0_i64.checked_sub_unsigned(x))
+                    {
+                        Some(k) => Self::ToConvertNegative(k),
+                        None => Self::Error(format!("Too small: {}", text)),
+                    },
+                    #[cfg(feature = "bigint")]
+                    Self::ToConvertBig(_) => Self::Error(format!("Too small: {}", text)),
+                    _ => Self::Error(format!("Invalid input: {}", text)),
+                },
+                None => match Self::parse_single_input(text) {
+                    #[cfg(feature = "bigint")]
+                    k @ Self::ToConvertBig(_) => k,
+                    k @ (Self::ToConvert(_) | Self::Error(_)) => k,
+                    _ => Self::Error(format!("Invalid input: {}", text)),
+                },
+            }
+        }
+
+        /// With `--strict`, rejects a whole number that was read by skipping characters
+        /// other than digits and separators (e.g. "4x3"), rather than converting it
+        fn strictly(self, text: &str, strict: bool) -> Self {
+            let whole = match self {
+                Self::ToConvert(_) | Self::ToConvertNegative(_) => true,
+                #[cfg(feature = "bigint")]
+                Self::ToConvertBig(_) => true,
+                _ => false,
+            };
+            if !strict || !whole {
+                return self;
+            }
+            // the sign of a negative number isn't a stray character
+            let magnitude = match self {
+                Self::ToConvertNegative(_) | Self::ToConvert(0) => {
+                    text.trim().trim_start_matches('-')
+                }
+                _ => text.trim(),
+            };
+            match parse_strict(magnitude) {
+                Err("Invalid digit.") if expand_scientific(magnitude).is_err() => {
+                    Self::Error(format!("Not a number (--strict): {}", text.trim()))
+                }
+                _ => self,
+            }
+        }
+
+        /// Parses a number in scientific notation (e.g. "2.5e6") by writing it out in full, or
+        /// returns `None` if it isn't one
+        fn parse_scientific(text: &str) -> Option<Self> {
+            let expanded = match expand_scientific(text) {
+                Ok(k) => k,
+                Err("Exponent too big.") => {
+                    return Some(Self::Error(format!("Too big: {}", text.trim())))
+                }
+                Err("Exponent too small.") => {
+                    return Some(Self::Error(format!(
+                        "Too many digits after the point: {}",
+                        text.trim()
+                    )))
+                }
+                Err(_) => return None,
+            };
+            Some(match Self::parse_signed_number(&expanded) {
+                Self::Error(e) => Self::Error(e.replace(&expanded, text.trim())),
+                k => k,
+            })
+        }
+
+        /// Parses a number written with a decimal point
+        fn parse_decimal(text: &str) -> Self {
+            match Decimal::parse(text) {
+                Ok(d) => Self::ToConvertDecimal(d),
+                Err("Value too big.") => Self::Error(format!("Too big: {}", text)),
+                Err(_) => Self::Error(format!("Invalid input: {}", text)),
+            }
+        }
+
+        fn parse_single_input(text: &str) -> Self {
+            let cleaned = text.to_lowercase();
+            if cleaned == "--" {
+                Self::EndOfOptions
+            } else if cleaned == "-0" {
+                Self::Null
+            } else if cleaned.len() > 2 && cleaned.starts_with("--") {
+                if &cleaned[2..] == "help" {
+                    Self::Help
+                } else if &cleaned[2..] == "version" {
+                    Self::Version
+                } else if &cleaned[2..] == "strict" {
+                    Self::Strict
+                } else if &cleaned[2..] == "signed" {
+                    Self::Signed
+                } else if let Some(r) = cleaned[2..].strip_prefix("range=") {
+                    match NumberRange::parse(r) {
+                        Ok(k) => Self::RangeOption(k),
+                        Err(_) => Self::Error(format!("Invalid range: {}", r)),
+                    }
+                } else if let Some(n) = cleaned[2..].strip_prefix("step=") {
+                    match parse_strict(n) {
+                        Ok(k) if k > 0 => Self::StepOption(k),
+                        _ => Self::Error(format!("Invalid step: {}", n)),
+                    }
+                } else if let Some(n) = cleaned[2..].strip_prefix("random=") {
+                    match parse_strict(n).map(u64::try_from) {
+                        Ok(Ok(k)) => Self::RandomOption(k),
+                        _ => Self::Error(format!("Invalid count: {}", n)),
+                    }
+                } else if let Some(n) = cleaned[2..].strip_prefix("min=") {
+                    match parse_strict(n).map(u64::try_from) {
+                        Ok(Ok(k)) => Self::MinOption(k),
+                        _ => Self::Error(format!("Invalid minimum: {}", n)),
+                    }
+                } else if let Some(n) = cleaned[2..].strip_prefix("max=") {
+                    match parse_strict(n).map(u64::try_from) {
+                        Ok(Ok(k)) => Self::MaxOption(k),
+                        _ => Self::Error(format!("Invalid maximum: {}", n)),
+                    }
+                } else if let Some(n) = cleaned[2..].strip_prefix("seed=") {
+                    match parse_strict(n).map(u64::try_from) {
+                        Ok(Ok(k)) => Self::SeedOption(k),
+                        _ => Self::Error(format!("Invalid seed: {}", n)),
+                    }
+                } else if &cleaned[2..] == "and-help" {
+                    Self::AndHelp
+                } else if &cleaned[2..] == "minimal" {
+                    Self::MinimalOutput
+                } else if &cleaned[2..] == "copy" {
+                    Self::Copy
+                } else if &cleaned[2..] == "paste" {
+                    Self::Paste
+                } else if &cleaned[2..] == "histogram" {
+                    Self::Histogram
+                } else if &cleaned[2..] == "list" {
+                    Self::List
+                } else if &cleaned[2..] == "csv" {
+                    Self::Csv
+                } else if &cleaned[2..] == "jsonl" {
+                    Self::Jsonl
+                } else if &cleaned[2..] == "follow" {
+                    Self::Follow
+                } else if &cleaned[2..] == "reverse" {
+                    Self::Reverse
+                } else if &cleaned[2..] == "eastern-numerals" {
+                    Self::EasternNumerals
+                } else if &cleaned[2..] == "cheque" {
+                    Self::ChequeOption(false)
+                } else if &cleaned[2..] == "cheque=only" {
+                    Self::ChequeOption(true)
+                } else if &cleaned[2..] == "legal" {
+                    Self::Legal
+                } else if &cleaned[2..] == "ssml" {
+                    Self::SsmlOption(SsmlStyle::Spelled)
+                } else if let Some(style) = cleaned[2..].strip_prefix("ssml=") {
+                    match style {
+                        "spelled" => Self::SsmlOption(SsmlStyle::Spelled),
+                        "say-as" => Self::SsmlOption(SsmlStyle::SayAs),
+                        k => Self::Error(format!("Invalid SSML option: {}", k)),
+                    }
+                } else if let Some(time) = cleaned[2..].strip_prefix("ssml-break=") {
+                    match time.trim_end_matches("ms").parse::<u32>() {
+                        Ok(ms) => Self::SsmlOption(SsmlStyle::SpelledWithBreaks(ms)),
+                        Err(_) => Self::Error(format!("Invalid SSML break: {}", time)),
+                    }
+                } else if &cleaned[2..] == "article" {
+                    Self::Article
+                } else if let Some(style) = cleaned[2..].strip_prefix("ordinal=") {
+                    match style {
+                        "suffix" => Self::OrdinalOption(OrdinalStyle::Suffix),
+                        "words" => Self::OrdinalOption(OrdinalStyle::Words),
+                        k => Self::Error(format!("Invalid ordinal option: {}", k)),
+                    }
+                } else if let Some(style) = cleaned[2..].strip_prefix("hyphen=") {
+                    match style {
+                        "all" => Self::HyphenOption(Hyphenation::All),
+                        "tens" => Self::HyphenOption(Hyphenation::TensOnly),
+                        "none" => Self::HyphenOption(Hyphenation::None),
+                        k => Self::Error(format!("Invalid hyphen option: {}", k)),
+                    }
+                } else if &cleaned[2..] == "titlecase" {
+                    Self::CaseOption(Case::Title)
+                } else if &cleaned[2..] == "uppercase" {
+                    Self::CaseOption(Case::Upper)
+                } else if &cleaned[2..] == "sentence" {
+                    Self::CaseOption(Case::Sentence)
+                } else if let Some(style) = cleaned[2..].strip_prefix("case=") {
+                    match style {
+                        "lower" => Self::CaseOption(Case::Lower),
+                        "upper" => Self::CaseOption(Case::Upper),
+                        "title" => Self::CaseOption(Case::Title),
+                        "sentence" => Self::CaseOption(Case::Sentence),
+                        k => Self::Error(format!("Invalid case option: {}", k)),
+                    }
+                } else if let Some(style) = cleaned[2..].strip_prefix("identifier=") {
+                    match style {
+                        "snake" => Self::IdentifierOption(IdentifierStyle::Snake),
+                        "kebab" => Self::IdentifierOption(IdentifierStyle::Kebab),
+                        "camel" => Self::IdentifierOption(IdentifierStyle::Camel),
+                        k => Self::Error(format!("Invalid identifier option: {}", k)),
+                    }
+                } else if let Some(style) = cleaned[2..].strip_prefix("digits=") {
+                    match style {
+                        "plain" => Self::DigitsOption(DigitAlphabet::Plain),
+                        "nato" => Self::DigitsOption(DigitAlphabet::Nato { niner: false }),
+                        "niner" => Self::DigitsOption(DigitAlphabet::Nato { niner: true }),
+                        "icao" => Self::DigitsOption(DigitAlphabet::Icao),
+                        k => Self::Error(format!("Invalid digits option: {}", k)),
+                    }
+                } else if let Some(scale) = cleaned[2..].strip_prefix("scale=") {
+                    match scale {
+                        "short" => Self::ScaleOption(Scale::Short),
+                        "long" => Self::ScaleOption(Scale::Long),
+                        "milliard" => Self::ScaleOption(Scale::LongMilliard),
+                        "indian" => Self::ScaleOption(Scale::Indian),
+                        "myriad" => Self::ScaleOption(Scale::Myriad),
+                        k => Self::Error(format!("Invalid scale option: {}", k)),
+                    }
+                } else if let Some(code) = cleaned[2..].strip_prefix("currency=") {
+                    match Currency::from_code(code) {
+                        Some(k) => Self::CurrencyOption(k),
+                        None => Self::Error(format!("Unknown currency: {}", code)),
+                    }
+                } else if let Some(mode) = cleaned[2..].strip_prefix("round=") {
+                    match mode {
+                        "half-up" => Self::RoundingOption(RoundingMode::HalfUp),
+                        "half-even" => Self::RoundingOption(RoundingMode::HalfEven),
+                        "truncate" => Self::RoundingOption(RoundingMode::Truncate),
+                        k => Self::Error(format!("Invalid rounding option: {}", k)),
+                    }
+                } else if let Some(style) = cleaned[2..].strip_prefix("negative-money=") {
+                    match style {
+                        "word" => Self::NegativeMoneyOption(NegativeAmount::Word),
+                        "parentheses" => Self::NegativeMoneyOption(NegativeAmount::Parentheses),
+                        "deficit" => Self::NegativeMoneyOption(NegativeAmount::Deficit),
+                        k => Self::Error(format!("Invalid negative money option: {}", k)),
+                    }
+                } else if let Some(tag) = cleaned[2..]
+                    .strip_prefix("lang=")
+                    .or_else(|| cleaned[2..].strip_prefix("locale="))
+                {
+                    match locale_code(tag) {
+                        Some(code) => Self::LanguageOption(code.to_string()),
+                        None => Self::Error(format!(
+                            "Unknown language: {} (available: {})",
+                            tag,
+                            LANGUAGE_CODES.join(", ")
+                        )),
+                    }
+                } else if let Some(word) = cleaned[2..].strip_prefix("zero=") {
+                    Self::ZeroWord(word.to_string())
+                } else if let Some(word) = cleaned[2..].strip_prefix("negative-word=") {
+                    Self::NegativeWord(word.to_string())
+                } else if let Some(place) = cleaned[2..].strip_prefix("negative-place=") {
+                    match place {
+                        "before" => Self::NegativePlacementOption(NegativePlacement::Before),
+                        "after" => Self::NegativePlacementOption(NegativePlacement::After),
+                        k => Self::Error(format!("Invalid negative placement: {}", k)),
+                    }
+                } else if cleaned.starts_with("--file=") {
+                    Self::File(text["--file=".len()..].to_string())
+                } else if cleaned.starts_with("--output=") {
+                    Self::Output(text["--output=".len()..].to_string())
+                } else if &cleaned[2..] == "append" {
+                    Self::Append
+                } else if &cleaned[2..] == "null" {
+                    Self::Null
+                } else if &cleaned[2..] == "quiet" {
+                    Self::Quiet
+                } else if &cleaned[2..] == "errors-only" {
+                    Self::ErrorsOnly
+                } else if let Some(n) = cleaned[2..].strip_prefix("column=") {
+                    match n.parse::<usize>() {
+                        Ok(k) if k > 0 => Self::Column(k),
+                        _ => Self::Error(format!("Invalid column: {}", n)),
+                    }
+                } else if cleaned.starts_with("--format=") {
+                    let template = &text["--format=".len()..];
+                    match check_template(template) {
+                        Ok(()) => Self::Template(template.to_string()),
+                        Err(e) => Self::Error(e),
+                    }
+                } else if cleaned.starts_with("--group-sep=") {
+                    Self::GroupSeparator(
+                        text["--group-sep=".len()..]
+                            .replace("\\n", "\n")
+                            .replace("\\t", "\t"),
+                    )
+                } else if let Some(and_option) = cleaned[2..].strip_prefix("and=") {
+                    match and_option {
+                        "none" => Self::AndOption(AndBehavior::None),
+                        "last" => Self::AndOption(AndBehavior::LastGroup),
+                        "below1k" => Self::AndOption(AndBehavior::OnlyUnderThousand),
+                        "all" => Self::AndOption(AndBehavior::All),
+                        k => Self::Error(format!("Invalid \"and\" option: {}", k)),
+                    }
+                } else {
+                    Self::Error(format!("Invalid option {}", cleaned))
+                }
+            } else if cleaned.len() > 1 && cleaned.starts_with('-') {
+                match Self::parse_signed_number(text) {
+                    Self::Error(_) => Self::Error(format!("Invalid option {}", cleaned)),
+                    _ => Self::Error(format!(
+                        "Invalid option {} (for a negative number, put -- or --signed first)",
+                        text
+                    )),
+                }
+            } else if split_currency(&cleaned).is_some() {
+                Self::ToConvertMoney(text.trim().to_string())
+            } else if let Some(k) = Self::parse_scientific(&cleaned) {
+                k
+            } else if cleaned.contains('.') {
+                Self::parse_decimal(&cleaned)
+            } else {
+                let n_text = cleaned
+                    .chars()
+                    .filter(|x| x.is_ascii_digit())
+                    .collect::<String>();
+                if n_text.is_empty() {
+                    Self::Error(format!("Invalid input: {}", text))
+                } else {
+                    match n_text.parse::<u128>() {
+                        Ok(x) => Self::ToConvert(x),
+                        Err(_) => Self::too_big(text, n_text),
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_example_session() {
+            assert_eq!(
+                example_session(&["--help"], "PROGRAM_NAME"),
+                "$ PROGRAM_NAME --help\n".to_owned() + &help_text(&"PROGRAM_NAME".to_string())
+            );
+            assert_eq!(
+                example_session(&["--and-help"], "PROGRAM_NAME"),
+                "$ PROGRAM_NAME --and-help\n".to_owned() + &and_help()
+            );
+            assert_eq!(
+                example_session(&["234", "15_234", "4x3x5x2xyz"], "blah"),
+                "$ blah 234 15_234 4x3x5x2xyz\n234: two-hundred and thirty-four\n".to_owned()
+                    + "15234: fifteen thousand, two-hundred and thirty-four\n"
+                    + "4352: four thousand, three-hundred and fifty-two"
+            );
+            assert_eq!(
+                example_session(&["--histogram", "--and=none", "12", "15", "31"], "blah"),
+                "$ blah --histogram --and=none 12 15 31\n".to_owned()
+                    + "ten to twenty:    ## two values\n"
+                    + "twenty to thirty: zero values\n"
+                    + "thirty to forty:  # one value"
+            );
+        }
+
+        #[test]
+        #[allow(clippy::useless_vec)]
+        fn test_config_parse() {
+            let mut cfg = Config::parse(vec!["blahblah".to_string()]);
+            assert!(cfg.output_components.is_err());
+            assert!(!cfg.help);
+            assert!(!cfg.and_help);
+            assert!(!cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+
+            cfg = Config::parse(
+                vec!["blahblah", "--help"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(cfg.output_components.is_ok());
+            assert_eq!(cfg.output_components.unwrap().len(), 0);
+            assert!(cfg.help);
+            assert!(!cfg.and_help);
+            assert!(!cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+
+            cfg = Config::parse(
+                vec!["blahblah", "--and-help", "234"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(cfg.output_components.is_ok());
+            assert_eq!(cfg.output_components.unwrap().len(), 1);
+            assert!(cfg.and_help);
+            assert!(!cfg.help);
+            assert!(!cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+
+            cfg = Config::parse(
+                vec!["blahblah", "--minimal", "234", "2265245"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(cfg.output_components.is_ok());
+            assert_eq!(cfg.output_components.unwrap().len(), 2);
+            assert!(!cfg.and_help);
+            assert!(!cfg.help);
+            assert!(cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+
+            cfg = Config::parse(
+                vec!["blahblah", "--and=none", "234", "2265245"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(cfg.output_components.is_ok());
+            let oc = cfg.output_components.unwrap();
+            assert_eq!(oc.len(), 2);
+            assert_eq!(
+                oc[0],
+                OutputComponent::ToConvert {
+                    value: 234,
+                    and_behavior: AndBehavior::None
+                }
+            );
+            assert_eq!(
+                oc[1],
+                OutputComponent::ToConvert {
+                    value: 2265245,
+                    and_behavior: AndBehavior::None
+                }
+            );
+            assert!(!cfg.and_help);
+            assert!(!cfg.help);
+            assert!(!cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+
+            cfg = Config::parse(
+                vec!["blahblah", "--and=last", "234", "2265245"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(cfg.output_components.is_ok());
+            let oc = cfg.output_components.unwrap();
+            assert_eq!(oc.len(), 2);
+            assert_eq!(
+                oc[0],
+                OutputComponent::ToConvert {
+                    value: 234,
+                    and_behavior: AndBehavior::LastGroup
+                }
+            );
+            assert_eq!(
+                oc[1],
+                OutputComponent::ToConvert {
+                    value: 2265245,
+                    and_behavior: AndBehavior::LastGroup
+                }
+            );
+            assert!(!cfg.and_help);
+            assert!(!cfg.help);
+            assert!(!cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+
+            cfg = Config::parse(
+                vec!["blahblah", "--and=below1k", "234", "2265245"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(cfg.output_components.is_ok());
+            let oc = cfg.output_components.unwrap();
+            assert_eq!(oc.len(), 2);
+            assert_eq!(
+                oc[0],
+                OutputComponent::ToConvert {
+                    value: 234,
+                    and_behavior: AndBehavior::OnlyUnderThousand
+                }
+            );
+            assert_eq!(
+                oc[1],
+                OutputComponent::ToConvert {
+                    value: 2265245,
+                    and_behavior: AndBehavior::OnlyUnderThousand
+                }
+            );
+            assert!(!cfg.and_help);
+            assert!(!cfg.help);
+            assert!(!cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+
+            cfg = Config::parse(
+                vec!["blahblah", "--and=all", "234", "2265245"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(cfg.output_components.is_ok());
+            let oc = cfg.output_components.unwrap();
+            assert_eq!(oc.len(), 2);
+            assert_eq!(
+                oc[0],
+                OutputComponent::ToConvert {
+                    value: 234,
+                    and_behavior: AndBehavior::All
+                }
+            );
+            assert_eq!(
+                oc[1],
+                OutputComponent::ToConvert {
+                    value: 2265245,
+                    and_behavior: AndBehavior::All
+                }
+            );
+            assert!(!cfg.and_help);
+            assert!(!cfg.help);
+            assert!(!cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+
+            cfg = Config::parse(
+                vec!["blahblah", "234", "2265245"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(cfg.output_components.is_ok());
+            let oc = cfg.output_components.unwrap();
+            assert_eq!(oc.len(), 2);
+            assert_eq!(
+                oc[0],
+                OutputComponent::ToConvert {
+                    value: 234,
+                    and_behavior: AndBehavior::All
+                }
+            );
+            assert_eq!(
+                oc[1],
+                OutputComponent::ToConvert {
+                    value: 2265245,
+                    and_behavior: AndBehavior::All
+                }
+            );
+            assert!(!cfg.and_help);
+            assert!(!cfg.help);
+            assert!(!cfg.minimal_output);
+            assert_eq!(cfg.prog_name, "blahblah".to_string());
+        }
+
+        #[test]
+        #[cfg(not(feature = "clipboard"))]
+        fn test_clipboard_disabled() {
+            assert_eq!(
+                example_session(&["--copy", "42"], "blah"),
+                "$ blah --copy 42\n42: forty-two\n".to_owned()
+                    + "Errors\n-----\n"
+                    + CLIPBOARD_DISABLED
+            );
+            assert_eq!(
+                example_session(&["--paste"], "blah"),
+                "$ blah --paste\nErrors\n-----\n".to_owned()
+                    + CLIPBOARD_DISABLED
+                    + "\nFor help, run: blah --help"
+            );
+        }
 
-    fn and_help() -> String {
-        "\
-                \"and\" options:\n\
-                -------------------------------------------------------------------\n\
-                \u{0020}   --and=none           Don't use the word \"and\"\n\
-                \u{0020}                        (e.g. \"five-hundred seventy-two\")\n\
-                \n\
-                \u{0020}   --and=last           Only use the word \"and\" in the\n\
-                \u{0020}                        hundreds-tens-units group\n\
-                \u{0020}                        (e.g. \"three-hundred five thousand,\n\
-                \u{0020}                               five-hundred and seventy-two\",\n\
-                \u{0020}                        but \"three-hundred five million,\n\
-                \u{0020}                             five-hundred seventy-two thousand\")\n\
-                \n\
-                \u{0020}   --and=below1k        Only use the word \"and\" for numbers below 1000\n\
-                \u{0020}                        (e.g. \"three-hundred five thousand,\n\
-                \u{0020}                               five-hundred seventy-two\",\n\
-                \u{0020}                        but \"three-hundred and five\")\n\
-                \n\
-                \u{0020}   --and=all            Always use \"and\" (default behavior)\n\
-                \u{0020}                        (e.g. \"five-hundred and twenty-four million,\n\
-                \u{0020}                               three-hundred and seventy-eight\")\n\
-                "
-        .to_string()
-    }
+        #[test]
+        fn test_option_scope() {
+            assert_eq!(
+                example_session(&["--case=title", "112", "--case=upper", "113"], "blah"),
+                "$ blah --case=title 112 --case=upper 113\n".to_owned()
+                    + "112: One-Hundred and Twelve\n"
+                    + "113: ONE-HUNDRED AND THIRTEEN"
+            );
+            assert_eq!(
+                example_session(
+                    &[
+                        "--scale=long",
+                        "2000000000",
+                        "--scale=indian",
+                        "--hyphen=none",
+                        "10000021"
+                    ],
+                    "blah"
+                ),
+                "$ blah --scale=long 2000000000 --scale=indian --hyphen=none 10000021\n".to_owned()
+                    + "2000000000: two thousand million\n"
+                    + "10000021: one crore, twenty one"
+            );
+            assert_eq!(
+                example_session(
+                    &[
+                        "--lang=fr",
+                        "2",
+                        "--lang=en",
+                        "--zero=nought",
+                        "0",
+                        "--currency=gbp",
+                        "3"
+                    ],
+                    "blah"
+                ),
+                "$ blah --lang=fr 2 --lang=en --zero=nought 0 --currency=gbp 3\n".to_owned()
+                    + "2: deux\n"
+                    + "0: nought\n"
+                    + "3: three pounds"
+            );
+            // numbers read from standard input take the options as they stood at the end
+            let config = Config::parse(
+                ["blah", "--jsonl", "--case=title", "--case=upper"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert_eq!(
+                config.process_line("7").0,
+                "{\"input\":\"7\",\"value\":7,\"words\":\"SEVEN\"}"
+            );
+        }
+
+        #[test]
+        fn test_and_option_scope() {
+            assert_eq!(
+                example_session(
+                    &["112", "--and=none", "113", "1105", "--and=last", "1105"],
+                    "blah"
+                ),
+                "$ blah 112 --and=none 113 1105 --and=last 1105\n".to_owned()
+                    + "112: one-hundred and twelve\n"
+                    + "113: one-hundred thirteen\n"
+                    + "1105: one thousand, one-hundred five\n"
+                    + "1105: one thousand, one-hundred and five"
+            );
+            assert_eq!(
+                example_session(&["--histogram", "12", "15", "--and=none", "31"], "blah"),
+                example_session(&["--histogram", "--and=none", "12", "15", "31"], "blah").replace(
+                    "--histogram --and=none 12 15 31",
+                    "--histogram 12 15 --and=none 31"
+                )
+            );
+        }
+
+        #[test]
+        fn test_negative_numbers() {
+            assert_eq!(
+                example_session(&["--and=none", "--", "-412", "7", "-0"], "blah"),
+                "$ blah --and=none -- -412 7 -0\n".to_owned()
+                    + "-412: negative four-hundred twelve\n"
+                    + "7: seven\n"
+                    + "0: zero"
+            );
+            assert_eq!(
+                example_session(&["--", "--help"], "blah"),
+                "$ blah -- --help\nErrors\n-----\nInvalid input: --help\n".to_owned()
+                    + "For help, run: blah --help"
+            );
+            assert_eq!(
+                InputComponent::parse_signed_number("-9_223_372_036_854_775_808"),
+                InputComponent::ToConvertNegative(i64::MIN)
+            );
+            assert_eq!(
+                InputComponent::parse_signed_number("-9_223_372_036_854_775_809"),
+                InputComponent::Error("Too small: -9_223_372_036_854_775_809".to_string())
+            );
+            assert_eq!(
+                InputComponent::parse_signed_number("15"),
+                InputComponent::ToConvert(15)
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--"),
+                InputComponent::EndOfOptions
+            );
+        }
+
+        #[test]
+        fn test_decimal_input() {
+            assert_eq!(
+                example_session(&["3.1415", "1_000.50", "1.2.3", "--", "-0.5"], "blah"),
+                "$ blah 3.1415 1_000.50 1.2.3 -- -0.5\n".to_owned()
+                    + "3.1415: three point one four one five\n"
+                    + "1000.50: one thousand point five zero\n"
+                    + "-0.5: negative zero point five\n"
+                    + "Errors\n-----\nInvalid input: 1.2.3"
+            );
+            assert_eq!(
+                InputComponent::parse_signed_number("---help"),
+                InputComponent::Error("Invalid input: ---help".to_string())
+            );
+        }
+
+        #[test]
+        fn test_ordinal_option() {
+            assert_eq!(
+                example_session(&["--ordinal=words", "42", "102", "--", "-3"], "blah"),
+                "$ blah --ordinal=words 42 102 -- -3\n".to_owned()
+                    + "42: forty-second\n"
+                    + "102: one-hundred and second\n"
+                    + "-3: negative third"
+            );
+            assert_eq!(
+                example_session(
+                    &["--ordinal=suffix", "--minimal", "11", "23", "2.5"],
+                    "blah"
+                ),
+                "$ blah --ordinal=suffix --minimal 11 23 2.5\n".to_owned()
+                    + "11th\n"
+                    + "23rd\n"
+                    + "Errors\n-----\nNo ordinal for decimal value: 2.5"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--ordinal=roman"),
+                InputComponent::Error("Invalid ordinal option: roman".to_string())
+            );
+        }
+
+        #[test]
+        fn test_hyphen_option() {
+            assert_eq!(
+                example_session(&["--hyphen=tens", "--ordinal=words", "347"], "blah"),
+                "$ blah --hyphen=tens --ordinal=words 347\n".to_owned()
+                    + "347: three hundred and forty-seventh"
+            );
+            assert_eq!(
+                example_session(&["--hyphen=none", "--", "-347"], "blah"),
+                "$ blah --hyphen=none -- -347\n".to_owned()
+                    + "-347: negative three hundred and forty seven"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--hyphen=some"),
+                InputComponent::Error("Invalid hyphen option: some".to_string())
+            );
+        }
+
+        #[test]
+        fn test_group_separator_option() {
+            assert_eq!(
+                example_session(&["--group-sep=", "--and=none", "2859"], "blah"),
+                "$ blah --group-sep= --and=none 2859\n".to_owned()
+                    + "2859: two thousandeight-hundred fifty-nine"
+            );
+            assert_eq!(
+                example_session(&["--group-sep=\\n", "--minimal", "2859"], "blah"),
+                "$ blah --group-sep=\\n --minimal 2859\n".to_owned()
+                    + "two thousand\neight-hundred and fifty-nine"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--group-sep=; "),
+                InputComponent::GroupSeparator("; ".to_string())
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--x"),
+                InputComponent::Error("Invalid option --x".to_string())
+            );
+        }
+
+        #[test]
+        fn test_case_option() {
+            assert_eq!(
+                example_session(&["--case=title", "--ordinal=words", "21"], "blah"),
+                "$ blah --case=title --ordinal=words 21\n".to_owned() + "21: Twenty-First"
+            );
+            assert_eq!(
+                example_session(&["--case=SENTENCE", "--", "-1.5"], "blah"),
+                "$ blah --case=SENTENCE -- -1.5\n".to_owned() + "-1.5: Negative one point five"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--case=camel"),
+                InputComponent::Error("Invalid case option: camel".to_string())
+            );
+            assert_eq!(
+                example_session(&["--uppercase", "--list", "7", "19"], "blah"),
+                "$ blah --uppercase --list 7 19\n".to_owned() + "SEVEN AND NINETEEN"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--titlecase"),
+                InputComponent::CaseOption(Case::Title)
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--Sentence"),
+                InputComponent::CaseOption(Case::Sentence)
+            );
+        }
+
+        #[test]
+        fn test_zero_option() {
+            assert_eq!(
+                example_session(&["--zero=nought", "0", "0.05", "10"], "blah"),
+                "$ blah --zero=nought 0 0.05 10\n".to_owned()
+                    + "0: nought\n"
+                    + "0.05: nought point nought five\n"
+                    + "10: ten"
+            );
+        }
+
+        #[test]
+        fn test_negative_word_option() {
+            assert_eq!(
+                example_session(
+                    &[
+                        "--negative-word=below zero",
+                        "--negative-place=after",
+                        "--",
+                        "-5",
+                        "5"
+                    ],
+                    "blah"
+                ),
+                "$ blah --negative-word=below zero --negative-place=after -- -5 5\n".to_owned()
+                    + "-5: five below zero\n"
+                    + "5: five"
+            );
+            assert_eq!(
+                example_session(&["--negative-word=minus", "--", "-2.5"], "blah"),
+                "$ blah --negative-word=minus -- -2.5\n".to_owned() + "-2.5: minus two point five"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--negative-place=middle"),
+                InputComponent::Error("Invalid negative placement: middle".to_string())
+            );
+        }
+
+        #[test]
+        fn test_article_option() {
+            assert_eq!(
+                example_session(&["--article", "120", "1000000"], "blah"),
+                "$ blah --article 120 1000000\n".to_owned()
+                    + "120: a hundred and twenty\n"
+                    + "1000000: a million"
+            );
+        }
+
+        #[test]
+        fn test_scale_option() {
+            assert_eq!(
+                example_session(
+                    &["--scale=long", "2000000000", "--", "-3000000000000"],
+                    "blah"
+                ),
+                "$ blah --scale=long 2000000000 -- -3000000000000\n".to_owned()
+                    + "2000000000: two thousand million\n"
+                    + "-3000000000000: negative three billion"
+            );
+            assert_eq!(
+                example_session(&["--scale=long", "1001000000"], "blah"),
+                "$ blah --scale=long 1001000000\n".to_owned()
+                    + "1001000000: one thousand one million"
+            );
+            assert_eq!(
+                example_session(
+                    &["--scale=milliard", "--ordinal=words", "2000000000"],
+                    "blah"
+                ),
+                "$ blah --scale=milliard --ordinal=words 2000000000\n".to_owned()
+                    + "2000000000: two milliardth"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--scale=huge"),
+                InputComponent::Error("Invalid scale option: huge".to_string())
+            );
+            // the examples in the help text
+            for (scale, words) in [
+                ("short", "one billion"),
+                ("long", "one thousand million"),
+                ("milliard", "one milliard"),
+                ("indian", "one-hundred crore"),
+                ("myriad", "ten oku"),
+            ] {
+                let option = format!("--scale={}", scale);
+                assert_eq!(
+                    example_session(&[&option, "--minimal", "1000000000"], "blah"),
+                    format!("$ blah {} --minimal 1000000000\n{}", option, words)
+                );
+                assert!(help_text(&"blah".to_string()).contains(&format!(
+                    "{:<10}{}\n",
+                    format!("{}:", scale),
+                    words
+                )));
+            }
+        }
+
+        #[test]
+        fn test_cheque_option() {
+            assert_eq!(
+                example_session(&["--cheque", "123.45", "2000", "1.5"], "blah"),
+                "$ blah --cheque 123.45 2000 1.5\n".to_owned()
+                    + "123.45: One Hundred Twenty-Three and 45/100 Dollars\n"
+                    + "2000: Two Thousand and 00/100 Dollars\n"
+                    + "1.5: One and 50/100 Dollars"
+            );
+            assert_eq!(
+                example_session(&["--cheque=only", "--minimal", "7"], "blah"),
+                "$ blah --cheque=only --minimal 7\n".to_owned() + "Seven and 00/100 Dollars Only"
+            );
+            assert_eq!(
+                example_session(&["--cheque", "1.234", "--", "-5"], "blah"),
+                "$ blah --cheque 1.234 -- -5\n".to_owned()
+                    + "Errors\n-----\n"
+                    + "Too many decimal places: 1.234\n"
+                    + "No cheque or legal amount for negative value: -5\n"
+                    + "For help, run: blah --help"
+            );
+        }
+
+        #[test]
+        fn test_negative_money_option() {
+            assert_eq!(
+                example_session(&["--currency=gbp", "--", "-3", "-0.5", "-€2"], "blah"),
+                "$ blah --currency=gbp -- -3 -0.5 -€2\n".to_owned()
+                    + "-3: negative three pounds\n"
+                    + "-0.5: negative fifty pence\n"
+                    + "-€2: negative two euros"
+            );
+            assert_eq!(
+                example_session(
+                    &["--negative-money=parentheses", "--", "-$100", "-$0.01"],
+                    "blah"
+                ),
+                "$ blah --negative-money=parentheses -- -$100 -$0.01\n".to_owned()
+                    + "-$100: (one-hundred dollars)\n"
+                    + "-$0.01: (one cent)"
+            );
+            assert_eq!(
+                example_session(
+                    &["--currency=usd", "--negative-money=deficit", "--", "-100"],
+                    "blah"
+                ),
+                "$ blah --currency=usd --negative-money=deficit -- -100\n".to_owned()
+                    + "-100: one-hundred dollars deficit"
+            );
+        }
+
+        #[test]
+        fn test_ssml_option() {
+            assert_eq!(
+                example_session(&["--ssml", "--case=sentence", "42", "--", "-5"], "blah"),
+                "$ blah --ssml --case=sentence 42 -- -5\n".to_owned()
+                    + "<speak>\n"
+                    + "<s>Forty-two</s>\n"
+                    + "<s>Negative five</s>\n"
+                    + "</speak>"
+            );
+            assert_eq!(
+                example_session(
+                    &["--ssml-break=150ms", "--group-sep= / ", "1002003"],
+                    "blah"
+                ),
+                "$ blah --ssml-break=150ms --group-sep= /  1002003\n".to_owned()
+                    + "<speak>\n"
+                    + "<s>one million<break time=\"150ms\"/> two thousand<break time=\"150ms\"/> \
+                       three</s>\n"
+                    + "</speak>"
+            );
+            assert_eq!(
+                example_session(&["--ssml=say-as", "7"], "blah"),
+                "$ blah --ssml=say-as 7\n".to_owned()
+                    + "<speak>\n"
+                    + "<say-as interpret-as=\"cardinal\">7</say-as>\n"
+                    + "</speak>"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--ssml-break=soon"),
+                InputComponent::Error("Invalid SSML break: soon".to_string())
+            );
+            // the errors go to standard error, after the document is closed
+            let config = Config::parse(
+                ["blah", "--ssml", "x", "7"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(config.errors_apart());
+            assert_eq!(
+                config.process_split(),
+                (
+                    "<speak>\n<s>seven</s>\n</speak>".to_string(),
+                    "\nErrors\n-----\nInvalid input: x".to_string(),
+                    Status::PartialFailure
+                )
+            );
+        }
+
+        #[test]
+        fn test_csv_option() {
+            assert_eq!(
+                example_session(&["--csv", "2_001", "7", "x", "--", "-5"], "blah"),
+                "$ blah --csv 2_001 7 x -- -5\n".to_owned()
+                    + "input,value,words\n"
+                    + "2_001,2001,\"two thousand, one\"\n"
+                    + "7,7,seven\n"
+                    + "-5,-5,negative five\n"
+                    + "Errors\n-----\n"
+                    + "Invalid input: x"
+            );
+            // the command-line program writes the errors to standard error, so that the rows
+            // are a CSV file of their own
+            let config = Config::parse(
+                ["blah", "--csv", "x", "7"]
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+            );
+            assert!(config.errors_apart());
+            assert_eq!(
+                config.process_split(),
+                (
+                    "input,value,words\n7,7,seven".to_string(),
+                    "\nErrors\n-----\nInvalid input: x".to_string(),
+                    Status::PartialFailure
+                )
+            );
+            assert_eq!(
+                example_session(&["--csv", "--reverse", "forty-two"], "blah"),
+                "$ blah --csv --reverse forty-two\n".to_owned()
+                    + "input,value,words\n"
+                    + "forty-two,42,forty-two"
+            );
+        }
+
+        #[test]
+        fn test_output_option() {
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&["blah", "--output=Out/Results.txt", "5", "x"]));
+            assert_eq!(config.output_file(), Some(("Out/Results.txt", false)));
+            assert_eq!(
+                config.process_split(),
+                (
+                    "5: five".to_string(),
+                    "\nErrors\n-----\nInvalid input: x".to_string(),
+                    Status::PartialFailure
+                )
+            );
+            let config = Config::parse(args(&["blah", "--append", "--output=log", "y"]));
+            assert_eq!(config.output_file(), Some(("log", true)));
+            assert_eq!(
+                config.process_split(),
+                (
+                    String::new(),
+                    "Errors\n-----\nInvalid input: y\nFor help, run: blah --help".to_string(),
+                    Status::Failure
+                )
+            );
+            assert_eq!(Config::parse(args(&["blah", "5"])).output_file(), None);
+        }
+
+        #[test]
+        fn test_column_option() {
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&["blah", "--column=3", "--identifier=kebab"]));
+            assert!(config.streams_input());
+            assert_eq!(config.column(), Some(3));
+            assert_eq!(
+                config.process_line_split("a\tb\t-42\tc"),
+                (
+                    "a\tb\tnegative-forty-two\tc".to_string(),
+                    String::new(),
+                    Status::Success
+                )
+            );
+            assert_eq!(
+                config.process_line_split("a\tb"),
+                (
+                    "a\tb".to_string(),
+                    "\nLine has fewer than 3 fields".to_string(),
+                    Status::Failure
+                )
+            );
+            assert_eq!(
+                config.process_line_split("a\tb\t"),
+                ("a\tb\t".to_string(), String::new(), Status::Success)
+            );
+            assert_eq!(
+                config.process_line_split(""),
+                (String::new(), String::new(), Status::Success)
+            );
+            assert_eq!(
+                config.process_line_split("a\tb\t1 2"),
+                (
+                    "a\tb\t1 2".to_string(),
+                    "\nInvalid input: 1 2".to_string(),
+                    Status::Failure
+                )
+            );
+            let config = Config::parse(args(&["blah", "--column=1", "--reverse", "-0"]));
+            assert_eq!(
+                config.process_line_split("ninety-nine\tred balloons"),
+                (
+                    "99\tred balloons\0".to_string(),
+                    String::new(),
+                    Status::Success
+                )
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--column=0"),
+                InputComponent::Error("Invalid column: 0".to_string())
+            );
+        }
 
-    fn help_text(prog_name: &String) -> String {
-        format!(
-            "\
-                numbers_into_words: Converts positive integers to words\n\
-                {}\n\
-                -------------------------------------------------------\n\
-                \n\
-                Usage: {} [OPTIONS] [NUMBERS]\n\
-                \n\
-                Options:\n\
-                \u{0020} --help                 Display this help message\n\
-                \n\
-                \u{0020} --and=(none | last | below1k | all )\n\
-                \n\
-                \u{0020}                        Specify when the word \"and\"\n\
-                \u{0020}                        should be used in phrases like\n\
-                \u{0020}                        \"five-hundred and seventy-two\"\n\
-                \n\
-                \u{0020} --and-help             Describe the options for --and=\n\
-                \n\
-                \u{0020} --minimal              Output only the words for each\n\
-                \u{0020}                        number (rather than prefacing\n\
-                \u{0020}                        with the numerals, e.g. \"five\"\n\
-                \u{0020}                        instead of \"5: five\")\n\
-                \n\
-                Examples:\n\
-                \n\
-                {}\n\
-                {}\n\
-                \n\
-                Note: maximum value supported is {}\
-            ",
-            COPYRIGHT_INFO,
-            prog_name,
-            example_session(&["234", "409_343", "305814"], prog_name.as_str()),
-            example_session(
-                &["--and=last", "--minimal", "234", "409_343", "305814"],
-                prog_name.as_str()
-            ),
-            u64::MAX,
-        )
-    }
+        #[test]
+        fn test_status() {
+            let status = |a: &[&str]| {
+                let mut args = vec!["blah".to_string()];
+                args.extend(a.iter().map(|x| x.to_string()));
+                Config::parse(args).process().1
+            };
+            assert_eq!(status(&["5", "1_000"]), Status::Success);
+            assert_eq!(status(&["--help"]), Status::Success);
+            assert_eq!(status(&["5", "x"]), Status::PartialFailure);
+            assert_eq!(status(&["x", "--jsonl", "y"]), Status::Failure);
+            assert_eq!(status(&["--minimal", "--bogus", "5"]), Status::UsageError);
+            assert_eq!(status(&["--column=0", "5"]), Status::UsageError);
+            assert_eq!(status(&[]), Status::UsageError);
+            assert_eq!(
+                Status::PartialFailure.merge(Status::Success),
+                Status::PartialFailure
+            );
+            assert_eq!(Status::Success.merge(Status::Success), Status::Success);
+            assert_eq!(
+                Status::UsageError.merge(Status::Failure),
+                Status::UsageError
+            );
+        }
 
-    fn example_session(inputs: &[&str], prog_name: &str) -> String {
-        let mut args: Vec<&str> = vec![prog_name];
-        for k in inputs {
-            args.push(k)
+        #[test]
+        fn test_scientific_input() {
+            assert_eq!(
+                example_session(
+                    &[
+                        "--minimal",
+                        "1e9",
+                        "2.5E6",
+                        "1.25e-1",
+                        "1e5000",
+                        "1e-99999999"
+                    ],
+                    "blah"
+                ),
+                "$ blah --minimal 1e9 2.5E6 1.25e-1 1e5000 1e-99999999\n".to_owned()
+                    + "one billion\n"
+                    + "two million, five-hundred thousand\n"
+                    + "zero point one two five\n"
+                    + "Errors\n-----\n"
+                    + "Too big: 1e5000\n"
+                    + "Too many digits after the point: 1e-99999999"
+            );
+            assert_eq!(
+                InputComponent::parse_signed_number("-1.5e3"),
+                InputComponent::ToConvertNegative(-1500)
+            );
         }
-        let command_line = args.join(" ");
-        let config = Config::parse(args.iter().map(|x| String::from(*x)).collect());
-        let output = config.process();
-        format!("$ {}\n{}", command_line, output)
-    }
 
-    impl Config {
-        /// Parses the command-line arguments and encodes them in a `Config`
-        pub fn parse(args: Vec<String>) -> Self {
-            let prog_name = args[0].clone();
-            if args.len() < 2 {
-                return Self {
-                    output_components: Err(format!(
-                        "No arguments. For help, run:\n$ {} --help",
-                        prog_name
-                    )),
-                    help: false,
-                    and_help: false,
-                    minimal_output: false,
-                    prog_name,
-                };
-            }
+        #[test]
+        fn test_range_option() {
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&[
+                "blah", "--range", "0..=20", "--step", "10", "--csv",
+            ]));
+            let values = config.generated_values().unwrap();
+            assert_eq!(values.collect::<Vec<u128>>(), vec![0, 10, 20]);
+            assert_eq!(config.values_header(), Some(CSV_HEADER));
+            assert_eq!(
+                config.process_value(20),
+                ("20,20,twenty".to_string(), String::new(), Status::Success)
+            );
+            assert!(Config::parse(args(&["blah", "--range=1..3", "--help"]))
+                .generated_values()
+                .is_none());
+            let config = Config::parse(args(&["blah", "--range=1..3", "--list"]));
+            assert_eq!(
+                config.process(),
+                (
+                    "--range can't be combined with --list\nFor help, run: blah --help".to_string(),
+                    Status::UsageError
+                )
+            );
+            let config = Config::parse(args(&["blah", "--range=0..=10000000"]));
+            assert_eq!(
+                config.process().0,
+                "Range has more than 10000000 numbers\nFor help, run: blah --help"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--step=0"),
+                InputComponent::Error("Invalid step: 0".to_string())
+            );
+        }
 
-            let mut help: bool = false;
-            let mut and_help: bool = false;
-            let mut minimal_output: bool = false;
-            let mut and_behavior: AndBehavior = AndBehavior::All;
-            let input_cmpts: Vec<InputComponent> = args[1..]
-                .iter()
-                .map(|x| InputComponent::parse_single_input(x))
-                .collect();
-            for k in input_cmpts.clone() {
-                match k {
-                    InputComponent::Help => {
-                        help = true;
-                    }
-                    InputComponent::AndOption(k) => {
-                        and_behavior = k;
-                    }
-                    InputComponent::MinimalOutput => {
-                        minimal_output = true;
-                    }
-                    InputComponent::AndHelp => {
-                        and_help = true;
-                    }
-                    _ => {}
-                }
-            }
-            let output_components: Result<Vec<OutputComponent>, String> = Ok(input_cmpts
-                .iter()
-                .map(|x| match x {
-                    InputComponent::ToConvert(k) => Some(OutputComponent::ToConvert {
-                        value: *k,
-                        and_behavior,
-                    }),
-                    InputComponent::Error(k) => Some(OutputComponent::Error(k.clone())),
-                    _ => None,
-                })
-                .filter(|x| x.is_some())
-                .map(|x| x.unwrap())
-                .collect());
+        #[test]
+        fn test_random_option() {
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&[
+                "blah", "--random", "50", "--min=3", "--max", "5", "--seed=9",
+            ]));
+            let values: Vec<u128> = config.generated_values().unwrap().collect();
+            assert_eq!(values.len(), 50);
+            assert!(values.iter().all(|x| (3..=5).contains(x)));
+            assert_eq!(
+                values,
+                UniformNumbers::new(9, 3, 5)
+                    .take(50)
+                    .map(u128::from)
+                    .collect::<Vec<u128>>()
+            );
+            assert_eq!(
+                Config::parse(args(&["blah", "--max=5"])).process().0,
+                "--min, --max, and --seed need --random\nFor help, run: blah --help"
+            );
+            assert_eq!(
+                Config::parse(args(&["blah", "--random=3", "--min=7", "--max=5"]))
+                    .process()
+                    .0,
+                "--min is more than the largest number, 5\nFor help, run: blah --help"
+            );
+            assert_eq!(
+                Config::parse(args(&["blah", "--random=3", "--range=1..5"])).process(),
+                (
+                    "--range can't be combined with --random\nFor help, run: blah --help"
+                        .to_string(),
+                    Status::UsageError
+                )
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--random=many"),
+                InputComponent::Error("Invalid count: many".to_string())
+            );
+        }
 
-            Self {
-                output_components,
-                help,
-                and_help,
-                prog_name,
-                minimal_output,
-            }
+        #[test]
+        fn test_signed_option() {
+            assert_eq!(
+                example_session(&["--signed", "-42", "-1.5", "7"], "blah"),
+                "$ blah --signed -42 -1.5 7\n".to_owned()
+                    + "-42: negative forty-two\n"
+                    + "-1.5: negative one point five\n"
+                    + "7: seven"
+            );
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&["blah", "-42", "-x"]));
+            assert_eq!(
+                config.process(),
+                (
+                    "Errors\n-----\n".to_owned()
+                        + "Invalid option -42 (for a negative number, put -- or --signed first)\n"
+                        + "Invalid option -x\n"
+                        + "For help, run: blah --help",
+                    Status::UsageError
+                )
+            );
+            let config = Config::parse(args(&["blah", "--signed", "-0", "5"]));
+            assert!(!config.null_terminated());
+            assert_eq!(
+                config.process(),
+                ("0: zero\n5: five".to_string(), Status::Success)
+            );
+            assert!(Config::parse(args(&["blah", "--signed", "--null", "5"])).null_terminated());
         }
 
-        /// Returns the program output appropriate for the command-line arguments used to encode
-        /// the `Config`
-        pub fn process(&self) -> String {
-            match &self.output_components {
-                Err(e) => e.clone(),
-                Ok(cmpts) => {
-                    let mut valid = false;
-                    let mut errors = false;
-
-                    let mut valid_vec: Vec<String> = Vec::new();
-                    let mut error_vec: Vec<String> = Vec::new();
-
-                    for c in cmpts {
-                        match c {
-                            OutputComponent::ToConvert {
-                                value,
-                                and_behavior,
-                            } => {
-                                valid_vec.push(format!(
-                                    "{}{}",
-                                    if self.minimal_output {
-                                        "".to_string()
-                                    } else {
-                                        format!("{}: ", value)
-                                    },
-                                    to_word(*value, *and_behavior)
-                                ));
-                                valid = true;
-                            }
-                            OutputComponent::Error(e) => {
-                                error_vec.push(e.clone());
-                                errors = true;
-                            }
-                        }
-                    }
-                    let mut valid_conversions = String::new();
-                    if !valid_vec.is_empty() && self.help {
-                        valid_conversions.push_str("\n---\n\n");
-                    }
-                    for (idx, k) in valid_vec.iter().enumerate() {
-                        valid_conversions.push_str(k.as_str());
-                        if idx != valid_vec.len() - 1 {
-                            valid_conversions.push('\n');
-                        }
-                    }
-                    if valid && errors {
-                        valid_conversions.push('\n');
-                    }
+        #[test]
+        fn test_strict_option() {
+            assert_eq!(
+                example_session(&["--strict", "--minimal", "4x3x5x2xyz", "1,005"], "blah"),
+                "$ blah --strict --minimal 4x3x5x2xyz 1,005\n".to_owned()
+                    + "one thousand, five\n"
+                    + "Errors\n-----\n"
+                    + "Not a number (--strict): 4x3x5x2xyz"
+            );
+            assert_eq!(
+                example_session(&["--strict", "--", "-12", "$3", "0.5x"], "blah"),
+                "$ blah --strict -- -12 $3 0.5x\n".to_owned()
+                    + "-12: negative twelve\n"
+                    + "$3: three dollars\n"
+                    + "Errors\n-----\n"
+                    + "Invalid input: 0.5x"
+            );
+        }
 
-                    let errors = if !error_vec.is_empty() {
-                        format!("Errors\n-----\n{}", error_vec.join("\n"))
-                    } else {
-                        String::from("")
-                    };
+        #[test]
+        fn test_version_option() {
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&["blah", "--version", "5"]));
+            assert_eq!(
+                config.process_split(),
+                (version().to_string(), String::new(), Status::Success)
+            );
+        }
 
-                    format!(
-                        "{}{}{}{}{}",
-                        if self.help {
-                            help_text(&self.prog_name)
-                        } else {
-                            "".to_string()
-                        },
-                        if self.and_help {
-                            and_help()
-                        } else {
-                            "".to_string()
-                        },
-                        valid_conversions,
-                        errors,
-                        if !error_vec.is_empty() && !valid && !self.help {
-                            format!("\nFor help, run: {} --help", self.prog_name)
-                        } else {
-                            "".to_string()
-                        }
-                    )
-                }
-            }
+        #[test]
+        fn test_format_option() {
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&[
+                "blah",
+                "--format={value} spelled out is {words}",
+                "1_001",
+                "x",
+            ]));
+            assert_eq!(
+                config.process_split(),
+                (
+                    "1001 spelled out is one thousand, one".to_string(),
+                    "\nErrors\n-----\nInvalid input: x".to_string(),
+                    Status::PartialFailure
+                )
+            );
+            let config = Config::parse(args(&[
+                "blah",
+                "--case=title",
+                "--format=[{input}] {ordinal} {error}",
+                "x",
+                "--",
+                "-3",
+            ]));
+            assert_eq!(
+                config.process_split(),
+                (
+                    "[x]  Invalid input: x\n[-3] Negative Third ".to_string(),
+                    String::new(),
+                    Status::PartialFailure
+                )
+            );
+            let config = Config::parse(args(&["blah", "--format={words} {{}", "2.5"]));
+            assert_eq!(config.process().0, "two point five {{}");
+            assert_eq!(
+                InputComponent::parse_single_input("--format={word}"),
+                InputComponent::Error("Invalid placeholder: {word}".to_string())
+            );
         }
-    }
 
-    impl InputComponent {
-        fn parse_single_input(text: &str) -> Self {
-            let cleaned = text.to_lowercase();
-            if cleaned.len() > 2 && &cleaned[..2] == "--" {
-                if &cleaned[2..] == "help" {
-                    Self::Help
-                } else if &cleaned[2..] == "and-help" {
-                    Self::AndHelp
-                } else if &cleaned[2..] == "minimal" {
-                    Self::MinimalOutput
-                } else if &cleaned[2..6] == "and=" {
-                    match &cleaned[6..] {
-                        "none" => Self::AndOption(AndBehavior::None),
-                        "last" => Self::AndOption(AndBehavior::LastGroup),
-                        "below1k" => Self::AndOption(AndBehavior::OnlyUnderThousand),
-                        "all" => Self::AndOption(AndBehavior::All),
-                        k => Self::Error(format!("Invalid \"and\" option: {}", k)),
-                    }
-                } else {
-                    Self::Error(format!("Invalid option {}", cleaned))
-                }
-            } else {
-                let n_text = cleaned
-                    .chars()
-                    .filter(|x| x.is_ascii_digit())
-                    .collect::<String>();
-                if n_text.is_empty() {
-                    Self::Error(format!("Invalid input: {}", text))
-                } else {
-                    match n_text.parse::<u64>() {
-                        Ok(x) => Self::ToConvert(x),
-                        Err(_) => Self::Error(format!("Too big: {}", text)),
-                    }
-                }
+        #[test]
+        fn test_null_option() {
+            assert_eq!(
+                example_session(&["-0", "--minimal", "5", "1000001"], "blah"),
+                "$ blah -0 --minimal 5 1000001\n".to_owned() + "five\0one million, one\0"
+            );
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&["blah", "--null", "--digits=plain", "-0", "12"]));
+            assert!(config.null_terminated());
+            assert_eq!(
+                config.process_split(),
+                ("12: one two\0".to_string(), String::new(), Status::Success)
+            );
+            assert!(!Config::parse(args(&["blah", "5"])).null_terminated());
+        }
+
+        #[test]
+        fn test_jsonl_option() {
+            assert_eq!(
+                example_session(&["--jsonl", "--case=title", "2_001", "£12", "x"], "blah"),
+                "$ blah --jsonl --case=title 2_001 £12 x\n".to_owned()
+                    + "{\"input\":\"2_001\",\"value\":2001,\"words\":\"Two Thousand, One\"}\n"
+                    + "{\"input\":\"£12\",\"value\":\"£12\",\"words\":\"Twelve Pounds\"}\n"
+                    + "{\"input\":\"x\",\"error\":\"Invalid input: x\"}"
+            );
+            assert_eq!(
+                json_line("0.25", "0.25", "a quarter"),
+                "{\"input\":\"0.25\",\"value\":0.25,\"words\":\"a quarter\"}".to_string()
+            );
+            for value in ["-", "5.", ".5", "01"] {
+                assert_eq!(
+                    json_line("", value, ""),
+                    format!("{{\"input\":\"\",\"value\":\"{}\",\"words\":\"\"}}", value)
+                );
             }
+            let config = Config::parse(vec!["blah".to_string(), "--jsonl".to_string()]);
+            assert!(config.streams_input());
+            assert_eq!(config.process_line("   "), (String::new(), Status::Success));
+            assert!(!Config::parse(vec![
+                "blah".to_string(),
+                "--jsonl".to_string(),
+                "1".to_string()
+            ])
+            .streams_input());
+        }
+
+        #[test]
+        fn test_follow_option() {
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            let config = Config::parse(args(&["blah", "--follow"]));
+            assert!(config.streams_input());
+            assert!(config.follows());
+            assert_eq!(
+                config.process_line("5 x"),
+                (
+                    "5: five\nErrors\n-----\nInvalid input: x".to_string(),
+                    Status::PartialFailure
+                )
+            );
+            assert_eq!(
+                Config::parse(args(&["blah", "--follow", "--jsonl"])).process_line("7"),
+                (
+                    "{\"input\":\"7\",\"value\":7,\"words\":\"seven\"}".to_string(),
+                    Status::Success
+                )
+            );
+            assert!(!Config::parse(args(&["blah", "--follow", "1"])).follows());
+            assert!(!Config::parse(args(&["blah", "--jsonl"])).follows());
+        }
+
+        #[test]
+        fn test_quiet_and_errors_only_options() {
+            let args = |a: &[&str]| a.iter().map(|x| x.to_string()).collect::<Vec<String>>();
+            assert_eq!(
+                Config::parse(args(&["blah", "--quiet", "5", "x"])).process(),
+                ("5: five".to_string(), Status::PartialFailure)
+            );
+            assert_eq!(
+                Config::parse(args(&["blah", "--errors-only", "5", "x"])).process_split(),
+                (
+                    String::new(),
+                    "Errors\n-----\nInvalid input: x".to_string(),
+                    Status::PartialFailure
+                )
+            );
+            assert_eq!(
+                Config::parse(args(&["blah", "--errors-only", "5"])).process(),
+                (String::new(), Status::Success)
+            );
+            let config = Config::parse(args(&["blah", "--column=2", "--errors-only"]));
+            assert!(!config.shows_results());
+            assert_eq!(
+                config.process_line_split("a\tx"),
+                (
+                    String::new(),
+                    "Invalid input: x".to_string(),
+                    Status::Failure
+                )
+            );
+            // mistakes in the options themselves are still shown
+            assert_eq!(
+                Config::parse(args(&["blah", "--quiet", "--bogus"])).process(),
+                (
+                    "Errors\n-----\nInvalid option --bogus\nFor help, run: blah --help".to_string(),
+                    Status::UsageError
+                )
+            );
+            assert_eq!(
+                Config::parse(args(&["blah", "--quiet", "--errors-only", "5"])).process(),
+                (
+                    "--quiet can't be combined with --errors-only\nFor help, run: blah --help"
+                        .to_string(),
+                    Status::UsageError
+                )
+            );
+        }
+
+        #[test]
+        fn test_list_option() {
+            assert_eq!(
+                example_session(&["--list", "--case=sentence", "7", "19", "42"], "blah"),
+                "$ blah --list --case=sentence 7 19 42\n".to_owned()
+                    + "Seven, nineteen, and forty-two"
+            );
+            assert_eq!(
+                example_session(&["--list", "--and=none", "1000002", "3", "x"], "blah"),
+                "$ blah --list --and=none 1000002 3 x\n".to_owned()
+                    + "one million, two and three\n"
+                    + "Errors\n-----\n"
+                    + "Invalid input: x"
+            );
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        #[test]
+        fn test_file_option() {
+            let path = std::env::temp_dir().join("numbers_into_words_test_file_option.txt");
+            fs::write(&path, "12 -5\n\n7 twelve\n").unwrap();
+            let file = format!("--file={}", path.display());
+            assert_eq!(
+                example_session(&["--minimal", &file, "3"], "blah"),
+                format!("$ blah --minimal {} 3\n", file)
+                    + "twelve\n"
+                    + "negative five\n"
+                    + "seven\n"
+                    + "three\n"
+                    + "Errors\n-----\n"
+                    + &format!("{}:3: Invalid input: twelve", path.display())
+            );
+            assert_eq!(
+                example_session(&["--reverse", &file], "blah"),
+                format!("$ blah --reverse {}\n", file)
+                    + "Errors\n-----\n"
+                    + "Could not read \"12 -5\": unknown word '12' at chars 0–2\n"
+                    + "Could not read \"7 twelve\": unknown word '7' at chars 0–1\n"
+                    + "For help, run: blah --help"
+            );
+            fs::remove_file(&path).unwrap();
+            assert!(example_session(&["--file=no/such/file.txt"], "blah")
+                .contains("Could not read no/such/file.txt: "));
+        }
 
         #[test]
-        fn test_example_session() {
+        fn test_digits_option() {
             assert_eq!(
-                example_session(&["--help"], "PROGRAM_NAME"),
-                "$ PROGRAM_NAME --help\n".to_owned() + &help_text(&"PROGRAM_NAME".to_string())
+                example_session(&["--digits=niner", "0459", "4a"], "blah"),
+                "$ blah --digits=niner 0459 4a\n".to_owned()
+                    + "0459: Zero Four Fife Niner\n"
+                    + "Errors\n-----\n"
+                    + "Invalid digit: 4a"
             );
             assert_eq!(
-                example_session(&["--and-help"], "PROGRAM_NAME"),
-                "$ PROGRAM_NAME --and-help\n".to_owned() + &and_help()
+                example_session(&["--digits=icao", "--minimal", "--", "427"], "blah"),
+                "$ blah --digits=icao --minimal -- 427\n".to_owned() + "fower too seven"
             );
             assert_eq!(
-                example_session(&["234", "15_234", "4x3x5x2xyz"], "blah"),
-                "$ blah 234 15_234 4x3x5x2xyz\n234: two-hundred and thirty-four\n".to_owned()
-                    + "15234: fifteen thousand, two-hundred and thirty-four\n"
-                    + "4352: four thousand, three-hundred and fifty-two"
+                InputComponent::parse_single_input("--digits=morse"),
+                InputComponent::Error("Invalid digits option: morse".to_string())
+            );
+            assert_eq!(
+                example_session(&["--zero=oh", "--digits=plain", "105", "0"], "blah"),
+                "$ blah --zero=oh --digits=plain 105 0\n".to_owned()
+                    + "105: one oh five\n"
+                    + "0: oh"
             );
         }
 
         #[test]
-        fn test_config_parse() {
-            let mut cfg = Config::parse(vec!["blahblah".to_string()]);
-            assert!(cfg.output_components.is_err());
-            assert!(!cfg.help);
-            assert!(!cfg.and_help);
-            assert!(!cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
-
-            cfg = Config::parse(
-                vec!["blahblah", "--help"]
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect(),
+        fn test_identifier_option() {
+            assert_eq!(
+                example_session(
+                    &["--identifier=snake", "--minimal", "423", "1.5", "--", "-12"],
+                    "blah"
+                ),
+                "$ blah --identifier=snake --minimal 423 1.5 -- -12\n".to_owned()
+                    + "four_hundred_twenty_three\n"
+                    + "one_point_five\n"
+                    + "negative_twelve"
             );
-            assert!(cfg.output_components.is_ok());
-            assert_eq!(cfg.output_components.unwrap().len(), 0);
-            assert!(cfg.help);
-            assert!(!cfg.and_help);
-            assert!(!cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
+            assert_eq!(
+                example_session(&["--identifier=camel", "--case=upper", "2001"], "blah"),
+                "$ blah --identifier=camel --case=upper 2001\n".to_owned() + "2001: TwoThousandOne"
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--identifier=pascal"),
+                InputComponent::Error("Invalid identifier option: pascal".to_string())
+            );
+        }
 
-            cfg = Config::parse(
-                vec!["blahblah", "--and-help", "234"]
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect(),
+        #[test]
+        fn test_legal_option() {
+            assert_eq!(
+                example_session(&["--legal", "--minimal", "1234.56", "£12"], "blah"),
+                "$ blah --legal --minimal 1234.56 £12\n".to_owned()
+                    + "One Thousand Two Hundred Thirty-Four Dollars and Fifty-Six Cents "
+                    + "(US$1,234.56)\n"
+                    + "Twelve Pounds (£12.00)"
             );
-            assert!(cfg.output_components.is_ok());
-            assert_eq!(cfg.output_components.unwrap().len(), 1);
-            assert!(cfg.and_help);
-            assert!(!cfg.help);
-            assert!(!cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
+        }
 
-            cfg = Config::parse(
-                vec!["blahblah", "--minimal", "234", "2265245"]
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect(),
+        #[test]
+        fn test_currency_option() {
+            assert_eq!(
+                example_session(
+                    &["--currency=inr", "--scale=indian", "12,34,567.50", "0.01"],
+                    "blah"
+                ),
+                "$ blah --currency=inr --scale=indian 12,34,567.50 0.01\n".to_owned()
+                    + "1234567.50: twelve lakh, thirty-four thousand, five-hundred and "
+                    + "sixty-seven rupees and fifty paise\n"
+                    + "0.01: one paisa"
             );
-            assert!(cfg.output_components.is_ok());
-            assert_eq!(cfg.output_components.unwrap().len(), 2);
-            assert!(!cfg.and_help);
-            assert!(!cfg.help);
-            assert!(cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
+            assert_eq!(
+                example_session(&["--currency=jpy", "--minimal", "1500", "1.5"], "blah"),
+                "$ blah --currency=jpy --minimal 1500 1.5\n".to_owned()
+                    + "one thousand, five-hundred yen\n"
+                    + "Errors\n-----\n"
+                    + "Too many decimal places: 1.5"
+            );
+            assert_eq!(
+                example_session(&["--currency=xyz", "1"], "blah"),
+                "$ blah --currency=xyz 1\n".to_owned()
+                    + "1: one\n"
+                    + "Errors\n-----\n"
+                    + "Unknown currency: xyz"
+            );
+        }
 
-            cfg = Config::parse(
-                vec!["blahblah", "--and=none", "234", "2265245"]
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect(),
+        #[test]
+        fn test_reverse_option() {
+            assert_eq!(
+                example_session(&["--reverse", "three-hundred and five", "4,000"], "blah"),
+                "$ blah --reverse three-hundred and five 4,000\n".to_owned()
+                    + "305\n"
+                    + "Errors\n-----\n"
+                    + "Could not read \"4,000\": unknown word '4,000' at chars 0–5"
             );
-            assert!(cfg.output_components.is_ok());
-            let oc = cfg.output_components.unwrap();
-            assert_eq!(oc.len(), 2);
             assert_eq!(
-                oc[0],
-                OutputComponent::ToConvert {
-                    value: 234,
-                    and_behavior: AndBehavior::None
-                }
+                example_session(&["--reverse", "--", "--twelve", "one million, two"], "blah"),
+                "$ blah --reverse -- --twelve one million, two\n".to_owned()
+                    + "1000002\n"
+                    + "Errors\n-----\n"
+                    + "Could not read \"--twelve\": unknown word '-' at chars 0–1"
             );
             assert_eq!(
-                oc[1],
-                OutputComponent::ToConvert {
-                    value: 2265245,
-                    and_behavior: AndBehavior::None
-                }
+                example_session(
+                    &["--reverse", "--lang=de", "dreihundertfünf", "drei hundert"],
+                    "blah"
+                ),
+                "$ blah --reverse --lang=de dreihundertfünf drei hundert\n".to_owned()
+                    + "305\n"
+                    + "300"
             );
-            assert!(!cfg.and_help);
-            assert!(!cfg.help);
-            assert!(!cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
+            assert_eq!(
+                example_session(&["--reverse", "Four Thousand", "TWELVE"], "blah"),
+                "$ blah --reverse Four Thousand TWELVE\n".to_owned() + "4000\n" + "12"
+            );
+            assert_eq!(
+                example_session(
+                    &[
+                        "--reverse",
+                        "--lang=ja",
+                        "三百二十一万",
+                        "--lang=hi",
+                        "बत्तीस लाख दस हज़ार"
+                    ],
+                    "blah"
+                ),
+                "$ blah --reverse --lang=ja 三百二十一万 --lang=hi बत्तीस लाख दस हज़ार\n".to_owned()
+                    + "3210000\n"
+                    + "3210000"
+            );
+        }
 
-            cfg = Config::parse(
-                vec!["blahblah", "--and=last", "234", "2265245"]
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect(),
+        #[test]
+        fn test_money_input() {
+            assert_eq!(
+                example_session(&["$1,234.56", "¥1500", "BHD 1.234", "€.5"], "blah"),
+                "$ blah $1,234.56 ¥1500 BHD 1.234 €.5\n".to_owned()
+                    + "$1,234.56: one thousand, two-hundred and thirty-four dollars and "
+                    + "fifty-six cents\n"
+                    + "¥1500: one thousand, five-hundred yen\n"
+                    + "BHD 1.234: one dinar and two-hundred and thirty-four fils\n"
+                    + "€.5: fifty cents"
             );
-            assert!(cfg.output_components.is_ok());
-            let oc = cfg.output_components.unwrap();
-            assert_eq!(oc.len(), 2);
             assert_eq!(
-                oc[0],
-                OutputComponent::ToConvert {
-                    value: 234,
-                    and_behavior: AndBehavior::LastGroup
-                }
+                example_session(
+                    &["--round=half-even", "--lang=fr", "$4.985", "£4.995"],
+                    "blah"
+                ),
+                "$ blah --round=half-even --lang=fr $4.985 £4.995\n".to_owned()
+                    + "Errors\n-----\n"
+                    + "Amounts of money are only available in English: $4.985\n"
+                    + "Amounts of money are only available in English: £4.995\n"
+                    + "For help, run: blah --help"
             );
             assert_eq!(
-                oc[1],
-                OutputComponent::ToConvert {
-                    value: 2265245,
-                    and_behavior: AndBehavior::LastGroup
-                }
+                example_session(&["--round=half-even", "$4.985", "£4.995", "$4.9x"], "blah"),
+                "$ blah --round=half-even $4.985 £4.995 $4.9x\n".to_owned()
+                    + "$4.985: four dollars and ninety-eight cents\n"
+                    + "£4.995: five pounds\n"
+                    + "Errors\n-----\n"
+                    + "Invalid amount: $4.9x"
             );
-            assert!(!cfg.and_help);
-            assert!(!cfg.help);
-            assert!(!cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
+            assert_eq!(
+                example_session(&["--currency=usd", "--round=truncate", "4.999"], "blah"),
+                "$ blah --currency=usd --round=truncate 4.999\n".to_owned()
+                    + "4.999: four dollars and ninety-nine cents"
+            );
+        }
 
-            cfg = Config::parse(
-                vec!["blahblah", "--and=below1k", "234", "2265245"]
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect(),
+        #[test]
+        fn test_eastern_numerals_option() {
+            assert_eq!(
+                example_session(
+                    &["--lang=ar", "--eastern-numerals", "2023", "--", "-7"],
+                    "blah"
+                ),
+                "$ blah --lang=ar --eastern-numerals 2023 -- -7\n".to_owned()
+                    + "٢٠٢٣: ألفان وثلاثة وعشرون\n"
+                    + "-٧: سالب سبعة"
             );
-            assert!(cfg.output_components.is_ok());
-            let oc = cfg.output_components.unwrap();
-            assert_eq!(oc.len(), 2);
+        }
+
+        #[test]
+        fn test_lang_option() {
             assert_eq!(
-                oc[0],
-                OutputComponent::ToConvert {
-                    value: 234,
-                    and_behavior: AndBehavior::OnlyUnderThousand
-                }
+                example_session(
+                    &["--lang=es", "--case=sentence", "21", "--", "-1000001"],
+                    "blah"
+                ),
+                "$ blah --lang=es --case=sentence 21 -- -1000001\n".to_owned()
+                    + "21: Veintiuno\n"
+                    + "-1000001: Menos un millón uno"
             );
             assert_eq!(
-                oc[1],
-                OutputComponent::ToConvert {
-                    value: 2265245,
-                    and_behavior: AndBehavior::OnlyUnderThousand
-                }
+                example_session(&["--lang=es", "--ordinal=words", "3", "1.5"], "blah"),
+                "$ blah --lang=es --ordinal=words 3 1.5\n".to_owned()
+                    + "Errors\n-----\n"
+                    + "Ordinals are only available in English: 3\n"
+                    + "No ordinal for decimal value: 1.5\n"
+                    + "For help, run: blah --help"
             );
-            assert!(!cfg.and_help);
-            assert!(!cfg.help);
-            assert!(!cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
+            assert_eq!(
+                InputComponent::parse_single_input("--lang=xx"),
+                InputComponent::Error(
+                    "Unknown language: xx (available: ar, cy, cy-traditional, da, de, en, eo, \
+                     es, fr, ga, hi, it, ja, ja-kana, ko, ko-native, nb, nl, no, pl, pt, \
+                     pt-br, pt-pt, ru, sv, tr, uk, zh, zh-daxie)"
+                        .to_string()
+                )
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--locale=pt_BR.UTF-8"),
+                InputComponent::LanguageOption("pt-br".to_string())
+            );
+            assert_eq!(
+                InputComponent::parse_single_input("--locale=fr_CA"),
+                InputComponent::LanguageOption("fr".to_string())
+            );
+            assert_eq!(
+                example_session(&["--lang", "es", "21"], "blah"),
+                "$ blah --lang es 21\n".to_owned() + "21: veintiuno"
+            );
+        }
 
-            cfg = Config::parse(
-                vec!["blahblah", "--and=all", "234", "2265245"]
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect(),
+        #[test]
+        fn test_u128_input() {
+            assert_eq!(
+                example_session(&["--and=none", "2_000_000_000_000_000_000_005"], "blah"),
+                "$ blah --and=none 2_000_000_000_000_000_000_005\n".to_owned()
+                    + "2000000000000000000005: two sextillion, five"
             );
+        }
+
+        #[test]
+        fn test_parse_bench() {
+            let args = |x: &[&str]| x.iter().map(|y| y.to_string()).collect::<Vec<String>>();
+
+            let mut cfg = Config::parse(args(&["blah", "bench"]));
+            assert_eq!(cfg.bench, Some(1_000_000));
             assert!(cfg.output_components.is_ok());
-            let oc = cfg.output_components.unwrap();
-            assert_eq!(oc.len(), 2);
+
+            cfg = Config::parse(args(&["blah", "bench", "--n", "5_000"]));
+            assert_eq!(cfg.bench, Some(5000));
+
+            cfg = Config::parse(args(&["blah", "bench", "--n=20"]));
+            assert_eq!(cfg.bench, Some(20));
+            assert!(cfg.process().0.starts_with("Converted 20 numbers in "));
+
+            cfg = Config::parse(args(&["blah", "bench", "--n"]));
+            assert_eq!(cfg.bench, None);
             assert_eq!(
-                oc[0],
-                OutputComponent::ToConvert {
-                    value: 234,
-                    and_behavior: AndBehavior::All
-                }
+                cfg.process(),
+                (
+                    "Missing count after --n\nFor help, run: blah --help".to_string(),
+                    Status::UsageError
+                )
             );
+
+            cfg = Config::parse(args(&["blah", "bench", "--n=lots"]));
             assert_eq!(
-                oc[1],
-                OutputComponent::ToConvert {
-                    value: 2265245,
-                    and_behavior: AndBehavior::All
-                }
+                cfg.process(),
+                (
+                    "Invalid count: lots\nFor help, run: blah --help".to_string(),
+                    Status::UsageError
+                )
             );
-            assert!(!cfg.and_help);
-            assert!(!cfg.help);
-            assert!(!cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
 
-            cfg = Config::parse(
-                vec!["blahblah", "234", "2265245"]
-                    .iter()
-                    .map(|x| x.to_string())
-                    .collect(),
+            cfg = Config::parse(args(&["blah", "bench", "--fast"]));
+            assert_eq!(
+                cfg.process(),
+                (
+                    "Invalid bench option --fast\nFor help, run: blah --help".to_string(),
+                    Status::UsageError
+                )
             );
-            assert!(cfg.output_components.is_ok());
-            let oc = cfg.output_components.unwrap();
-            assert_eq!(oc.len(), 2);
+        }
+
+        #[test]
+        fn test_parse_normalize() {
+            let args = |x: &[&str]| x.iter().map(|y| y.to_string()).collect::<Vec<String>>();
+            let normalizer = |a: &[&str]| Config::parse(args(a)).normalizer();
+            let mut words =
+                normalizer(&["blah", "normalize", "--and=none", "--markup=markdown"]).unwrap();
             assert_eq!(
-                oc[0],
-                OutputComponent::ToConvert {
-                    value: 234,
-                    and_behavior: AndBehavior::All
-                }
+                words.line("`1` and 105"),
+                "`1` and one-hundred five".to_string()
             );
+            let mut numerals = normalizer(&["blah", "normalize", "--reverse"]).unwrap();
+            assert_eq!(numerals.line("`one` and six"), "`1` and 6".to_string());
+            assert!(normalizer(&["blah", "5"]).is_none());
+
+            let cfg = Config::parse(args(&["blah", "normalize", "--minimal"]));
+            assert!(cfg.normalizer().is_none());
             assert_eq!(
-                oc[1],
-                OutputComponent::ToConvert {
-                    value: 2265245,
-                    and_behavior: AndBehavior::All
-                }
+                cfg.process(),
+                (
+                    "Invalid normalize option --minimal\nFor help, run: blah --help".to_string(),
+                    Status::UsageError
+                )
+            );
+            assert_eq!(
+                Config::parse(args(&["blah", "normalize", "--and=some"])).process(),
+                (
+                    "Invalid \"and\" option: some\nFor help, run: blah --help".to_string(),
+                    Status::UsageError
+                )
             );
-            assert!(!cfg.and_help);
-            assert!(!cfg.help);
-            assert!(!cfg.minimal_output);
-            assert_eq!(cfg.prog_name, "blahblah".to_string());
         }
 
         #[test]
@@ -1378,6 +6549,21 @@ pub mod process_input {
                 InputComponent::MinimalOutput
             );
 
+            assert_eq!(
+                InputComponent::parse_single_input("--histogram"),
+                InputComponent::Histogram
+            );
+
+            assert_eq!(
+                InputComponent::parse_single_input("--copy"),
+                InputComponent::Copy
+            );
+
+            assert_eq!(
+                InputComponent::parse_single_input("--paste"),
+                InputComponent::Paste
+            );
+
             assert_eq!(
                 InputComponent::parse_single_input("--and=none"),
                 InputComponent::AndOption(AndBehavior::None)
@@ -1420,7 +6606,26 @@ pub mod process_input {
 
             assert_eq!(
                 InputComponent::parse_single_input("1_000_000_000_000_000_000_000"),
-                InputComponent::Error("Too big: 1_000_000_000_000_000_000_000".to_string())
+                InputComponent::ToConvert(1_000_000_000_000_000_000_000)
+            );
+
+            #[cfg(not(feature = "bigint"))]
+            assert_eq!(
+                InputComponent::parse_single_input(
+                    "1_000_000_000_000_000_000_000_000_000_000_000_000_000"
+                ),
+                InputComponent::Error(
+                    "Too big: 1_000_000_000_000_000_000_000_000_000_000_000_000_000".to_string()
+                )
+            );
+            #[cfg(feature = "bigint")]
+            assert_eq!(
+                InputComponent::parse_single_input(
+                    "1_000_000_000_000_000_000_000_000_000_000_000_000_000"
+                ),
+                InputComponent::ToConvertBig(
+                    "1000000000000000000000000000000000000000".to_string()
+                )
             );
         }
     }
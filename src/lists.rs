@@ -0,0 +1,171 @@
+/* src/lists.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{uncased_words, FormatOptions};
+
+/// Options for joining several numbers into one phrase, such as "seven, nineteen, and
+/// forty-two"
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::FormatOptions;
+/// use numbers_into_words::lists::{list_to_words, ListOptions};
+/// let options = ListOptions::new().oxford_comma(false).conjunction("or");
+/// assert_eq!(
+///     list_to_words(&[7, 19, 42], &options, &FormatOptions::new()),
+///     "seven, nineteen or forty-two".to_string()
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListOptions {
+    oxford_comma: bool,
+    conjunction: String,
+}
+
+impl Default for ListOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ListOptions {
+    /// The default style: "and" before the last item, with an Oxford comma
+    pub fn new() -> Self {
+        Self {
+            oxford_comma: true,
+            conjunction: "and".to_string(),
+        }
+    }
+
+    /// Sets whether a comma comes before the conjunction in lists of three or more
+    pub fn oxford_comma(mut self, oxford_comma: bool) -> Self {
+        self.oxford_comma = oxford_comma;
+        self
+    }
+
+    /// Sets the word before the last item (e.g. "or")
+    pub fn conjunction(mut self, conjunction: &str) -> Self {
+        self.conjunction = conjunction.to_string();
+        self
+    }
+}
+
+/// Joins items into a series, such as "a, b, and c"
+///
+/// If any item has a comma of its own (e.g. "one million, two"), the items are separated
+/// by semicolons instead, so that the list can still be read.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::lists::{join_list, ListOptions};
+/// let options = ListOptions::new();
+/// assert_eq!(join_list(&["ten", "eleven"], &options), "ten and eleven".to_string());
+/// assert_eq!(
+///     join_list(&["one million, two", "three", "four"], &options),
+///     "one million, two; three; and four".to_string()
+/// );
+/// ```
+pub fn join_list<S: AsRef<str>>(items: &[S], options: &ListOptions) -> String {
+    let separator = if items.iter().any(|item| item.as_ref().contains(',')) {
+        "; "
+    } else {
+        ", "
+    };
+    match items {
+        [] => String::new(),
+        [only] => only.as_ref().to_string(),
+        [first, last] => format!(
+            "{} {} {}",
+            first.as_ref(),
+            options.conjunction,
+            last.as_ref()
+        ),
+        [rest @ .., last] => {
+            let rest: Vec<&str> = rest.iter().map(|item| item.as_ref()).collect();
+            format!(
+                "{}{} {} {}",
+                rest.join(separator),
+                if options.oxford_comma {
+                    separator.trim_end()
+                } else {
+                    ""
+                },
+                options.conjunction,
+                last.as_ref()
+            )
+        }
+    }
+}
+
+/// Writes the numbers as one phrase, for lottery draws and enumerations
+///
+/// The letter case in `format` applies to the phrase as a whole, so that `Case::Sentence`
+/// capitalizes only the first word.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::conversion_to_words::Case;
+/// use numbers_into_words::FormatOptions;
+/// use numbers_into_words::lists::{list_to_words, ListOptions};
+/// assert_eq!(
+///     list_to_words(
+///         &[7, 19, 42],
+///         &ListOptions::new(),
+///         &FormatOptions::new().case(Case::Sentence)
+///     ),
+///     "Seven, nineteen, and forty-two".to_string()
+/// );
+/// ```
+pub fn list_to_words(values: &[u64], list: &ListOptions, format: &FormatOptions) -> String {
+    let words: Vec<String> = values
+        .iter()
+        .map(|x| uncased_words(*x as u128, format))
+        .collect();
+    format.apply_case(join_list(&words, list))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_to_words() {
+        let format = FormatOptions::new();
+        let plain = ListOptions::new().oxford_comma(false);
+        assert_eq!(list_to_words(&[], &plain, &format), String::new());
+        assert_eq!(list_to_words(&[5], &plain, &format), "five".to_string());
+        assert_eq!(
+            list_to_words(&[5, 6], &ListOptions::new(), &format),
+            "five and six".to_string()
+        );
+        assert_eq!(
+            list_to_words(&[1, 2, 3, 4], &plain, &format),
+            "one, two, three and four".to_string()
+        );
+        assert_eq!(
+            list_to_words(&[1_000_002, 3, 4], &plain, &format),
+            "one million, two; three and four".to_string()
+        );
+        assert_eq!(
+            list_to_words(
+                &[1_000_002, 3],
+                &ListOptions::new().conjunction("or"),
+                &format.clone().group_separator(" ")
+            ),
+            "one million two or three".to_string()
+        );
+    }
+}
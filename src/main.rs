@@ -11,11 +11,155 @@
  * in repository root directory.
  * */
 
-use numbers_into_words::Config;
-use std::env;
+#[cfg(feature = "count-allocations")]
+use numbers_into_words::benchmark::CountingAllocator;
+use numbers_into_words::normalize::normalize_reader;
+use numbers_into_words::{Config, Status};
+use std::fs::OpenOptions;
+use std::io::{BufRead, Write};
+use std::{env, io, process};
+
+/// Counts allocations for the `bench` subcommand; left out of ordinary builds, where every
+/// allocation would pay for the counting
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// The exit code for how a run went; a file that can't be opened, read, or written is a
+/// failure too
+fn exit_code(status: Status) -> i32 {
+    match status {
+        Status::Success => 0,
+        Status::Failure => 1,
+        Status::UsageError => 2,
+        Status::PartialFailure => 3,
+    }
+}
+
+/// Writes results, which already end with `\0` for `--null`, and otherwise a newline
+fn write_results(config: &Config, out: &mut dyn Write, results: &str) -> Result<(), String> {
+    if config.null_terminated() {
+        write!(out, "{}", results)
+    } else {
+        writeln!(out, "{}", results)
+    }
+    .map_err(|e| format!("Could not write output: {}", e))
+}
+
+/// Converts standard input line by line as it arrives, for `--jsonl`, `--follow`, and
+/// `--column=`, writing any errors to standard error with the line number
+fn stream(config: &Config, out: &mut dyn Write) -> Result<Status, String> {
+    // blank lines don't count, so that all-blank input succeeds and failing lines fail
+    let mut status: Option<Status> = None;
+    for (idx, line) in io::stdin().lock().lines().enumerate() {
+        let line = line.map_err(|e| format!("Could not read input: {}", e))?;
+        let (results, diagnostics, line_status) = config.process_line_split(&line);
+        if !line.trim().is_empty() {
+            status = Some(status.map_or(line_status, |s| s.merge(line_status)));
+        }
+        if !diagnostics.is_empty() {
+            eprintln!("Line {}: {}", idx + 1, diagnostics.trim_start_matches('\n'));
+        }
+        // with --column=, every line is passed through, even a blank one
+        if !results.is_empty() || (config.column().is_some() && config.shows_results()) {
+            write_results(config, out, &results)?;
+        }
+        if config.follows() {
+            out.flush()
+                .map_err(|e| format!("Could not write output: {}", e))?;
+        }
+    }
+    Ok(status.unwrap_or(Status::Success))
+}
+
+/// Writes the numbers of `--range=` or `--random=` as they are converted, with any errors on
+/// standard error
+fn stream_values(
+    config: &Config,
+    values: Box<dyn Iterator<Item = u128>>,
+    out: &mut dyn Write,
+) -> Result<Status, String> {
+    if let Some(header) = config.values_header() {
+        let header = if config.null_terminated() {
+            format!("{}\0", header)
+        } else {
+            header.to_string()
+        };
+        write_results(config, out, &header)?;
+    }
+    let mut status: Option<Status> = None;
+    for value in values {
+        let (results, diagnostics, value_status) = config.process_value(value);
+        if !diagnostics.is_empty() {
+            eprintln!("{}", diagnostics.trim_start_matches('\n'));
+        }
+        if !results.is_empty() {
+            write_results(config, out, &results)?;
+        }
+        status = Some(status.map_or(value_status, |s| s.merge(value_status)));
+    }
+    Ok(status.unwrap_or(Status::Success))
+}
 
 fn main() {
     let args: Vec<String> = env::args().collect();
     let config = Config::parse(args);
-    println!("{}", config.process());
+    let mut out: Box<dyn Write> = match config.output_file() {
+        Some((path, append)) => match OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(append)
+            .truncate(!append)
+            .open(path)
+        {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                eprintln!("Could not open {}: {}", path, e);
+                process::exit(exit_code(Status::Failure));
+            }
+        },
+        None => Box::new(io::stdout().lock()),
+    };
+    let written = match (config.normalizer(), config.generated_values()) {
+        (Some(normalizer), _) => normalize_reader(io::stdin().lock(), &mut out, normalizer)
+            .map(|_| Status::Success)
+            .map_err(|e| format!("Could not normalize input: {}", e)),
+        (None, Some(values)) => stream_values(&config, values, &mut out),
+        (None, None) if config.streams_input() => stream(&config, &mut out),
+        (None, None) if config.errors_apart() => {
+            let (results, diagnostics, status) = config.process_split();
+            if !diagnostics.is_empty() {
+                eprintln!("{}", diagnostics.trim_start_matches('\n'));
+            }
+            if results.is_empty() {
+                Ok(status)
+            } else {
+                write_results(&config, &mut out, &results).map(|_| status)
+            }
+        }
+        (None, None) => {
+            let (output, status) = config.process();
+            // with --errors-only, there may be nothing to write
+            if output.is_empty() {
+                Ok(status)
+            } else {
+                writeln!(out, "{}", output)
+                    .map(|_| status)
+                    .map_err(|e| format!("Could not write output: {}", e))
+            }
+        }
+    };
+    // process::exit doesn't run destructors, so nothing else would flush the output
+    let written = written.and_then(|status| {
+        out.flush()
+            .map(|_| status)
+            .map_err(|e| format!("Could not write output: {}", e))
+    });
+    match written {
+        Ok(status) => process::exit(exit_code(status)),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(exit_code(Status::Failure));
+        }
+    }
 }
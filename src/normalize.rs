@@ -0,0 +1,540 @@
+/* src/normalize.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::AndBehavior;
+use super::decimal::Decimal;
+use super::parse_words::extract_numbers;
+use std::io::{self, BufRead, Read, Write};
+
+/// Signals which way `Normalizer` rewrites numbers
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
+    /// Write numerals as words ("42" becomes "forty-two")
+    ToWords,
+
+    /// Write numbers spelled out in words as numerals ("forty-two" becomes "42")
+    ToNumerals,
+}
+
+/// Signals what kind of document is being normalized, and so which text is left alone
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Markup {
+    /// Every part of every line is prose
+    Plain,
+
+    /// Fenced and indented code blocks, inline code, link destinations, URLs, and HTML
+    /// tags are left alone
+    Markdown,
+
+    /// Tags (with their attributes), comments, URLs, and the contents of `code`, `pre`,
+    /// `script`, and `style` elements are left alone
+    Html,
+}
+
+/// The longest line `normalize_reader` reads, in bytes, so that input without line breaks
+/// (such as a binary file) is caught rather than held in memory whole
+pub const MAX_LINE_LEN: usize = 1_000_000;
+
+/// Elements whose contents are never prose
+const RAW_ELEMENTS: [&str; 4] = ["code", "pre", "script", "style"];
+
+/// Text being passed over until a marker, which may be on a later line
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Skip {
+    /// The marker that ends the skipped text, e.g. "-->" for a comment
+    until: String,
+
+    /// What to skip after the marker, e.g. up to "</code>" after `<code>`
+    then: Option<String>,
+}
+
+/// Rewrites the numbers in lines of text, keeping the surrounding text as it is
+///
+/// The normalizer remembers what it is inside of (a code block, an HTML comment, ...)
+/// from one line to the next, so lines must be given in order.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::normalize::{Direction, Markup, Normalizer};
+/// let mut normalizer = Normalizer::new(Direction::ToWords).markup(Markup::Markdown);
+/// assert_eq!(
+///     normalizer.line("Take 2 pills, not `2`, at 3.5 hours."),
+///     "Take two pills, not `2`, at three point five hours.".to_string()
+/// );
+/// assert_eq!(normalizer.line("```"), "```".to_string());
+/// assert_eq!(normalizer.line("let x = 2;"), "let x = 2;".to_string());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Normalizer {
+    direction: Direction,
+    markup: Markup,
+    and_behavior: AndBehavior,
+    fence: Option<String>,
+    skip: Option<Skip>,
+    indented_code: bool,
+}
+
+impl Normalizer {
+    /// A normalizer for plain text, using "and" in every group
+    pub fn new(direction: Direction) -> Self {
+        Self {
+            direction,
+            markup: Markup::Plain,
+            and_behavior: AndBehavior::All,
+            fence: None,
+            skip: None,
+            indented_code: true,
+        }
+    }
+
+    /// Sets the kind of document
+    pub fn markup(mut self, markup: Markup) -> Self {
+        self.markup = markup;
+        self
+    }
+
+    /// Sets when "and" is used in the words written for numerals
+    pub fn and_behavior(mut self, and_behavior: AndBehavior) -> Self {
+        self.and_behavior = and_behavior;
+        self
+    }
+
+    /// Rewrites the numbers in the next line of the document
+    pub fn line(&mut self, line: &str) -> String {
+        if self.markup == Markup::Markdown && self.skip.is_none() && self.code_line(line) {
+            return line.to_string();
+        }
+        let mut out = String::with_capacity(line.len());
+        let mut rest = line;
+        while !rest.is_empty() {
+            if let Some(skip) = self.skip.take() {
+                let lower = rest.to_ascii_lowercase();
+                match lower.find(&skip.until) {
+                    Some(k) => {
+                        let end = k + skip.until.len();
+                        out.push_str(&rest[..end]);
+                        rest = &rest[end..];
+                        self.skip = skip.then.map(|until| Skip { until, then: None });
+                    }
+                    None => {
+                        out.push_str(rest);
+                        rest = "";
+                        self.skip = Some(skip);
+                    }
+                }
+                continue;
+            }
+            match self.next_hidden(rest) {
+                Some((start, end, skip)) => {
+                    out.push_str(&self.convert(&rest[..start]));
+                    out.push_str(&rest[start..end]);
+                    rest = &rest[end..];
+                    self.skip = skip;
+                }
+                None => {
+                    out.push_str(&self.convert(rest));
+                    rest = "";
+                }
+            }
+        }
+        out
+    }
+
+    /// Whether a Markdown line is part of a code block, keeping track of fences
+    fn code_line(&mut self, line: &str) -> bool {
+        let trimmed = line.trim_start();
+        let marker = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker));
+        if let Some(fence) = &self.fence {
+            if trimmed.starts_with(fence.as_str()) {
+                self.fence = None;
+            }
+            return true;
+        }
+        if let Some(marker) = marker {
+            self.fence = Some(marker.to_string());
+            return true;
+        }
+        // indented code can only start after a blank line
+        let indented = line.starts_with("    ") || line.starts_with('\t');
+        let code = indented && self.indented_code;
+        self.indented_code = line.trim().is_empty() || code;
+        code
+    }
+
+    /// Finds the first text in `text` that is not prose, as its byte range, and what to
+    /// skip after it
+    fn next_hidden(&self, text: &str) -> Option<(usize, usize, Option<Skip>)> {
+        if self.markup == Markup::Plain {
+            return None;
+        }
+        text.char_indices().find_map(|(k, c)| {
+            let rest = &text[k..];
+            let found = match c {
+                '<' => html_hidden(rest),
+                '`' if self.markup == Markup::Markdown => code_span(rest),
+                ']' if self.markup == Markup::Markdown && rest.starts_with("](") => {
+                    rest.find(')').map(|end| (end + 1, None))
+                }
+                _ if starts_word(text, k) => url(rest).map(|end| (end, None)),
+                _ => None,
+            };
+            found.map(|(end, skip)| (k, k + end, skip))
+        })
+    }
+
+    /// Rewrites the numbers in some prose
+    fn convert(&self, text: &str) -> String {
+        match self.direction {
+            Direction::ToWords => self.numerals_to_words(text),
+            Direction::ToNumerals => {
+                let mut out = String::with_capacity(text.len());
+                let mut last = 0;
+                for (span, value) in extract_numbers(text) {
+                    out.push_str(&text[last..span.start]);
+                    out.push_str(&value.to_string());
+                    last = span.end;
+                }
+                out.push_str(&text[last..]);
+                out
+            }
+        }
+    }
+
+    /// Writes each standalone numeral in `text`, such as "1,024" or "3.5", as words
+    fn numerals_to_words(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut last = 0;
+        let mut k = 0;
+        while k < text.len() {
+            if !(text.as_bytes()[k].is_ascii_digit() && starts_word(text, k)) {
+                k += 1;
+                continue;
+            }
+            let end = k + numeral_len(&text[k..]);
+            let token_end = end + token_len(&text[end..]);
+            if token_end == end && !left_as_digits(&text[k..end]) {
+                if let Ok(decimal) = Decimal::parse(&text[k..end]) {
+                    out.push_str(&text[last..k]);
+                    out.push_str(&decimal.to_words(self.and_behavior));
+                    last = end;
+                }
+            }
+            k = token_end;
+        }
+        out.push_str(&text[last..]);
+        out
+    }
+}
+
+/// Whether `k` is the start of a word in `text`, i.e. not after a letter, digit, `_`, or
+/// `.`
+fn starts_word(text: &str, k: usize) -> bool {
+    !text[..k]
+        .chars()
+        .next_back()
+        .is_some_and(|c| c.is_alphanumeric() || c == '_' || c == '.')
+}
+
+/// The length in bytes of the numeral at the start of `text`: digits, with commas before
+/// groups of three, and digits after a decimal point
+fn numeral_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    let digits = |from: usize| {
+        bytes[from..]
+            .iter()
+            .take_while(|b| b.is_ascii_digit())
+            .count()
+    };
+    let mut end = digits(0);
+    while bytes.get(end) == Some(&b',') && digits(end + 1) == 3 {
+        end += 4;
+    }
+    if bytes.get(end) == Some(&b'.') && digits(end + 1) > 0 {
+        end += 1 + digits(end + 1);
+    }
+    end
+}
+
+/// Whether a numeral reads better as digits: one padded with zeros, such as "007", or four
+/// digits alone, which are most often a year, such as "2024"
+fn left_as_digits(numeral: &str) -> bool {
+    let whole = numeral.split('.').next().unwrap_or(numeral);
+    (whole.len() > 1 && whole.starts_with('0'))
+        || (numeral.len() == 4 && numeral.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// The length in bytes of the rest of a word or code at the start of `text`, such as
+/// "rd" after "3" or ".3" after "1.2", which keeps a numeral from being read alone
+fn token_len(text: &str) -> usize {
+    let mut chars = text.char_indices().peekable();
+    while let Some((k, c)) = chars.next() {
+        let joins_digit =
+            matches!(c, '.' | ',') && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+        if !(c.is_alphanumeric() || c == '_' || joins_digit) {
+            return k;
+        }
+    }
+    text.len()
+}
+
+/// An HTML tag or comment at the start of `text`: its length on this line, and what to
+/// skip if it goes on past the line or opens an element that is not prose
+fn html_hidden(text: &str) -> Option<(usize, Option<Skip>)> {
+    let lower = text.to_ascii_lowercase();
+    let (until, then) = if lower.starts_with("<!--") {
+        ("-->", None)
+    } else if lower[1..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '/') {
+        let name: String = lower[1..]
+            .chars()
+            .take_while(|c| c.is_ascii_alphanumeric())
+            .collect();
+        let raw = RAW_ELEMENTS.contains(&name.as_str()) && !lower.starts_with("</");
+        (">", raw.then(|| format!("</{}>", name)))
+    } else {
+        return None;
+    };
+    let skip = |until: &str, then: Option<String>| Skip {
+        until: until.to_string(),
+        then,
+    };
+    Some(match lower.find(until) {
+        Some(k) => (k + until.len(), then.map(|then| skip(&then, None))),
+        None => (text.len(), Some(skip(until, then))),
+    })
+}
+
+/// Inline code at the start of `text`: its length, if the run of backticks is closed on
+/// the same line
+fn code_span(text: &str) -> Option<(usize, Option<Skip>)> {
+    let ticks = text.chars().take_while(|c| *c == '`').count();
+    let fence = &text[..ticks];
+    text[ticks..]
+        .find(fence)
+        .map(|k| (2 * ticks + k, None))
+        // an unclosed run of backticks is just text
+        .or(Some((ticks, None)))
+}
+
+/// The length of the URL at the start of `text`, if there is one
+fn url(text: &str) -> Option<usize> {
+    ["http://", "https://", "www.", "mailto:"]
+        .iter()
+        .any(|scheme| {
+            text.get(..scheme.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(scheme))
+        })
+        .then(|| {
+            text.find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | ')'))
+                .unwrap_or(text.len())
+        })
+}
+
+/// Lazily rewrites the numbers in each line; see `normalize`
+pub struct Normalize<I> {
+    lines: I,
+    normalizer: Normalizer,
+}
+
+impl<I, S> Iterator for Normalize<I>
+where
+    I: Iterator<Item = S>,
+    S: AsRef<str>,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.lines
+            .next()
+            .map(|line| self.normalizer.line(line.as_ref()))
+    }
+}
+
+/// Rewrites the numbers in each of `lines`, one line at a time as they are needed
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::normalize::{normalize, Direction, Markup, Normalizer};
+/// let normalizer = Normalizer::new(Direction::ToNumerals).markup(Markup::Html);
+/// let lines = ["<p class=\"one\">Twenty-one", "<!-- five", "six -->geese</p>"];
+/// assert_eq!(
+///     normalize(lines, normalizer).collect::<Vec<String>>(),
+///     vec!["<p class=\"one\">21", "<!-- five", "six -->geese</p>"]
+/// );
+/// ```
+pub fn normalize<I, S>(lines: I, normalizer: Normalizer) -> Normalize<I::IntoIter>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    Normalize {
+        lines: lines.into_iter(),
+        normalizer,
+    }
+}
+
+/// Rewrites the numbers in everything `reader` gives, writing the result to `writer`
+/// line by line, so that large documents are never held in memory
+///
+/// Line endings are written as `\n`. A line longer than `MAX_LINE_LEN` bytes, or one that
+/// is not UTF-8, is an error of kind `InvalidData`.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::normalize::{normalize_reader, Direction, Normalizer};
+/// let mut out = Vec::new();
+/// normalize_reader(&b"1 cat\n2 dogs\n"[..], &mut out, Normalizer::new(Direction::ToWords))
+///     .unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "one cat\ntwo dogs\n");
+/// ```
+pub fn normalize_reader<R: BufRead, W: Write>(
+    mut reader: R,
+    mut writer: W,
+    mut normalizer: Normalizer,
+) -> io::Result<()> {
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let limit = MAX_LINE_LEN as u64 + 1;
+        if (&mut reader).take(limit).read_until(b'\n', &mut line)? == 0 {
+            return writer.flush();
+        }
+        if line.last() == Some(&b'\n') {
+            line.pop();
+            if line.last() == Some(&b'\r') {
+                line.pop();
+            }
+        } else if line.len() > MAX_LINE_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Line is longer than {} bytes", MAX_LINE_LEN),
+            ));
+        }
+        let text = std::str::from_utf8(&line)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        writeln!(writer, "{}", normalizer.line(text))?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(normalizer: Normalizer, text: &str) -> String {
+        normalize(text.lines(), normalizer)
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_normalize_plain() {
+        let words = Normalizer::new(Direction::ToWords).and_behavior(AndBehavior::None);
+        assert_eq!(
+            run(
+                words.clone(),
+                "In 2024, 1,500 ran 3.5 km (not 10km); v1.2.3, 3rd, and 1,50 stay."
+            ),
+            "In 2024, one thousand, five-hundred ran three point five km (not 10km); \
+             v1.2.3, 3rd, and 1,50 stay."
+        );
+        assert_eq!(
+            run(
+                words.clone(),
+                "Agent 007 took 0.5 of 10000 and 0,500 in 1999."
+            ),
+            "Agent 007 took zero point five of ten thousand and 0,500 in 1999."
+        );
+        assert_eq!(
+            run(words, "See https://example.com/2 or <b>7</b>"),
+            "See https://example.com/two or <b>seven</b>"
+        );
+        assert_eq!(
+            run(
+                Normalizer::new(Direction::ToNumerals),
+                "Forty-two, then three hundred and five."
+            ),
+            "42, then 305."
+        );
+    }
+
+    #[test]
+    fn test_normalize_markdown() {
+        let words = Normalizer::new(Direction::ToWords).markup(Markup::Markdown);
+        let document = "\
+            # 3 steps\n\
+            \n\
+            \x20   let x = 4;\n\
+            Run `cargo test -j 8` for 2 [links](https://a.b/9) or www.c.d/5.\n\
+            ~~~\n\
+            6\n\
+            ~~~\n\
+            <!-- 7\n\
+            8 --> 9 <code class=\"x10\">11\n\
+            12</code> 13";
+        assert_eq!(
+            run(words.clone(), document),
+            "\
+            # three steps\n\
+            \n\
+            \x20   let x = 4;\n\
+            Run `cargo test -j 8` for two [links](https://a.b/9) or www.c.d/5.\n\
+            ~~~\n\
+            6\n\
+            ~~~\n\
+            <!-- 7\n\
+            8 --> nine <code class=\"x10\">11\n\
+            12</code> thirteen"
+        );
+        // four spaces after text is a continuation, not code
+        assert_eq!(
+            run(words, "Some text\n    5 more"),
+            "Some text\n    five more"
+        );
+    }
+
+    #[test]
+    fn test_normalize_html() {
+        let numerals = Normalizer::new(Direction::ToNumerals).markup(Markup::Html);
+        assert_eq!(
+            run(
+                numerals,
+                "<img alt=\"one\"\n  title=\"two\"> three <pre>four</pre> five `six`"
+            ),
+            "<img alt=\"one\"\n  title=\"two\"> 3 <pre>four</pre> 5 `6`"
+        );
+        let mut out = Vec::new();
+        normalize_reader(
+            &b"<p>1</p>\r\n<script>\nlet n = 2;\n</script>\n"[..],
+            &mut out,
+            Normalizer::new(Direction::ToWords).markup(Markup::Html),
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "<p>one</p>\n<script>\nlet n = 2;\n</script>\n"
+        );
+        let mut long = "7".repeat(MAX_LINE_LEN);
+        long.push('\n');
+        let mut out = Vec::new();
+        let normalizer = Normalizer::new(Direction::ToNumerals);
+        assert!(normalize_reader(long.as_bytes(), &mut out, normalizer.clone()).is_ok());
+        long.insert(0, '7');
+        let error = normalize_reader(long.as_bytes(), &mut out, normalizer).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(error.to_string(), "Line is longer than 1000000 bytes");
+    }
+}
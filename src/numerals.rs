@@ -0,0 +1,167 @@
+/* src/numerals.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+/// Parses a whole number written only with digits and the separators `_` and `,`,
+/// rejecting any other character instead of skipping it
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::numerals::parse_strict;
+/// assert_eq!(parse_strict("15_234"), Ok(15_234));
+/// assert_eq!(parse_strict("45,230"), Ok(45_230));
+/// assert_eq!(parse_strict("4x3x5x2xyz"), Err("Invalid digit."));
+/// ```
+pub fn parse_strict(text: &str) -> Result<u128, &'static str> {
+    let digits = text.trim().replace(['_', ','], "");
+    if digits.is_empty() {
+        return Err("No digits.");
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("Invalid digit.");
+    }
+    digits.parse::<u128>().map_err(|_| "Value too big.")
+}
+
+/// The most digits `expand_scientific` writes before the decimal point or after it
+pub const MAX_EXPANDED_DIGITS: usize = 1000;
+
+/// Writes a number in scientific notation, like "2.5e6" or "-1.5E-3", in full ("2500000"
+/// or "-0.0015"), ignoring `_` and `,` separators in the mantissa
+///
+/// A whole number has no decimal point, even if the mantissa did (e.g. "1.50e1" gives
+/// "15"); otherwise the digits after the point are kept as written.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::numerals::expand_scientific;
+/// assert_eq!(expand_scientific("1e9"), Ok("1000000000".to_string()));
+/// assert_eq!(expand_scientific("2.5e-1"), Ok("0.25".to_string()));
+/// assert_eq!(expand_scientific("42"), Err("No exponent."));
+/// ```
+pub fn expand_scientific(text: &str) -> Result<String, &'static str> {
+    let cleaned = text.trim().replace(['_', ','], "");
+    let (negative, cleaned) = match cleaned.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, cleaned.as_str()),
+    };
+    let (mantissa, exponent) = cleaned.split_once(['e', 'E']).ok_or("No exponent.")?;
+    let unsigned = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+    if unsigned.is_empty() || !unsigned.bytes().all(|b| b.is_ascii_digit()) {
+        return Err("Invalid exponent.");
+    }
+    let (whole, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err("No digits.");
+    }
+    if !whole
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return Err("Invalid digit.");
+    }
+    // a large negative exponent gives too many digits after the point, not a big number
+    let out_of_range = if exponent.starts_with('-') {
+        "Exponent too small."
+    } else {
+        "Exponent too big."
+    };
+    let exponent = match exponent.trim_start_matches('+').parse::<i64>() {
+        Ok(k) if k.unsigned_abs() as usize <= 2 * MAX_EXPANDED_DIGITS => k,
+        _ => return Err(out_of_range),
+    };
+    let digits = format!("{}{}", whole, fraction);
+    // where the decimal point falls in `digits` once the exponent is applied
+    let point = whole.len() as i64 + exponent;
+    if point.unsigned_abs() as usize > MAX_EXPANDED_DIGITS {
+        return Err(out_of_range);
+    }
+    let (int_part, frac_part) = if point <= 0 {
+        ("0".to_string(), "0".repeat(-point as usize) + &digits)
+    } else if point as usize >= digits.len() {
+        (
+            digits.clone() + &"0".repeat(point as usize - digits.len()),
+            String::new(),
+        )
+    } else {
+        let (i, f) = digits.split_at(point as usize);
+        (i.to_string(), f.to_string())
+    };
+    let int_part = match int_part.trim_start_matches('0') {
+        "" => "0",
+        k => k,
+    };
+    let zero = frac_part.bytes().all(|b| b == b'0');
+    let sign = if negative && !(zero && int_part == "0") {
+        "-"
+    } else {
+        ""
+    };
+    Ok(if zero {
+        format!("{}{}", sign, int_part)
+    } else {
+        format!("{}{}.{}", sign, int_part, frac_part)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_strict() {
+        assert_eq!(parse_strict(" 1_000,000 "), Ok(1_000_000));
+        assert_eq!(parse_strict("007"), Ok(7));
+        assert_eq!(parse_strict("_,"), Err("No digits."));
+        assert_eq!(parse_strict("12 34"), Err("Invalid digit."));
+        assert_eq!(parse_strict("-5"), Err("Invalid digit."));
+        assert_eq!(parse_strict("1e6"), Err("Invalid digit."));
+        assert_eq!(parse_strict(&"9".repeat(40)), Err("Value too big."));
+    }
+
+    #[test]
+    fn test_expand_scientific() {
+        let cases = [
+            ("1e9", "1000000000"),
+            ("2.5e6", "2500000"),
+            ("2.5E+6", "2500000"),
+            ("1.50e1", "15"),
+            ("1_234.5e2", "123450"),
+            ("1.2345e2", "123.45"),
+            ("-1.5e-3", "-0.0015"),
+            (".5e1", "5"),
+            ("-0e5", "0"),
+            ("7e0", "7"),
+        ];
+        for (text, expanded) in cases {
+            assert_eq!(expand_scientific(text), Ok(expanded.to_string()));
+        }
+        assert_eq!(expand_scientific("1e"), Err("Invalid exponent."));
+        assert_eq!(expand_scientific("1e+"), Err("Invalid exponent."));
+        assert_eq!(expand_scientific("1e1.5"), Err("Invalid exponent."));
+        assert_eq!(expand_scientific("e5"), Err("No digits."));
+        assert_eq!(expand_scientific("4x3e5"), Err("Invalid digit."));
+        assert_eq!(expand_scientific("1e5000"), Err("Exponent too big."));
+        assert_eq!(
+            expand_scientific("1e99999999999999999999"),
+            Err("Exponent too big.")
+        );
+        assert_eq!(expand_scientific("1e-5000"), Err("Exponent too small."));
+        assert_eq!(
+            expand_scientific("-1e-99999999"),
+            Err("Exponent too small.")
+        );
+    }
+}
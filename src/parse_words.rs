@@ -0,0 +1,743 @@
+/* src/parse_words.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{to_word, AndBehavior, TEENS, TENS, UNITS};
+use std::error::Error;
+use std::fmt;
+use std::iter;
+use std::ops::Range;
+
+/// A piece of a number phrase: a word, or the comma after a group
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Token<'a> {
+    Word(&'a str),
+    Comma,
+}
+
+/// Scale words, where the word at index `i` is 1000^(i + 1)
+const SCALES: [&str; 12] = [
+    "thousand",
+    "million",
+    "billion",
+    "trillion",
+    "quadrillion",
+    "quintillion",
+    "sextillion",
+    "septillion",
+    "octillion",
+    "nonillion",
+    "decillion",
+    "undecillion",
+];
+
+/// Splits a phrase into words at spaces and hyphens, keeping the commas between groups
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    spanned_tokens(text)
+        .into_iter()
+        .map(|(token, _)| token)
+        .collect()
+}
+
+/// Like `tokenize`, but with where each token is in `text`, in bytes
+fn spanned_tokens(text: &str) -> Vec<(Token<'_>, Range<usize>)> {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    for piece in text.split_inclusive(char::is_whitespace) {
+        let piece_start = start;
+        start += piece.len();
+        let piece = piece.trim_end_matches(char::is_whitespace);
+        if piece.is_empty() {
+            continue;
+        }
+        let (word, comma) = match piece.strip_suffix(',') {
+            Some(word) => (word, true),
+            None => (piece, false),
+        };
+        let word_end = piece_start + word.len();
+        let mut word_start = piece_start;
+        for w in word.split('-') {
+            // an empty word, from a stray hyphen, is placed on that hyphen
+            let span = match w.len() {
+                0 if word_start < word_end => word_start..word_start + 1,
+                0 => word_start - 1..word_start,
+                len => word_start..word_start + len,
+            };
+            tokens.push((Token::Word(w), span));
+            word_start += w.len() + 1;
+        }
+        if comma {
+            tokens.push((Token::Comma, word_start - 1..word_start));
+        }
+    }
+    tokens
+}
+
+/// What went wrong reading the words of a number
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// There were no words at all
+    NoWords,
+
+    /// A word is not one used to write numbers, or is misspelled
+    UnknownWord,
+
+    /// A known word is in the wrong place, such as "thousand" at the start
+    UnexpectedWord,
+
+    /// The words stop partway through a group, such as after "and"
+    UnexpectedEnd,
+
+    /// A comma is somewhere other than after a scale word, or ends the words
+    UnexpectedComma,
+
+    /// A scale word is not smaller than the one before it, as in "five thousand, six
+    /// million"
+    ScaleOutOfOrder,
+
+    /// The number does not fit in a `u64`
+    TooBig,
+}
+
+impl ParseErrorKind {
+    /// A short description of the problem, such as "Unknown word."
+    pub fn message(self) -> &'static str {
+        match self {
+            ParseErrorKind::NoWords => "No words.",
+            ParseErrorKind::UnknownWord => "Unknown word.",
+            ParseErrorKind::UnexpectedWord => "Unexpected word.",
+            ParseErrorKind::UnexpectedEnd => "Unexpected end of words.",
+            ParseErrorKind::UnexpectedComma => "Unexpected comma.",
+            ParseErrorKind::ScaleOutOfOrder => "Scale words out of order.",
+            ParseErrorKind::TooBig => "Value too big.",
+        }
+    }
+}
+
+/// Why `from_words` could not read some words, and where
+///
+/// The span counts characters (not bytes) from the start of the words, so it can be used
+/// to underline the problem; it is empty, at the end of the words, for
+/// `ParseErrorKind::UnexpectedEnd` and `ParseErrorKind::NoWords`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// What went wrong
+    pub kind: ParseErrorKind,
+
+    /// The word (or comma) at fault, or "" if there is none
+    pub token: String,
+
+    /// The word (or comma) before it, if there is one
+    pub previous: Option<String>,
+
+    /// Where the token is, in characters
+    pub span: Range<usize>,
+}
+
+impl ParseError {
+    /// The error for the token at `index` of `tokens`, or for the end of `text` if
+    /// `index` is past the last token
+    fn at(
+        kind: ParseErrorKind,
+        text: &str,
+        tokens: &[(Token, Range<usize>)],
+        index: usize,
+    ) -> Self {
+        let chars = |bytes: usize| text[..bytes].chars().count();
+        let (token, span) = match tokens.get(index) {
+            Some((_, span)) => (
+                text[span.clone()].to_string(),
+                chars(span.start)..chars(span.end),
+            ),
+            None => (String::new(), chars(text.len())..chars(text.len())),
+        };
+        ParseError {
+            kind,
+            token,
+            previous: index
+                .checked_sub(1)
+                .and_then(|k| tokens.get(k))
+                .map(|(_, span)| text[span.clone()].to_string()),
+            span,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let what = match self.kind {
+            ParseErrorKind::NoWords => return write!(f, "no words"),
+            ParseErrorKind::UnknownWord => format!("unknown word '{}'", self.token),
+            ParseErrorKind::UnexpectedWord => format!("unexpected word '{}'", self.token),
+            ParseErrorKind::UnexpectedEnd => "unexpected end of words".to_string(),
+            ParseErrorKind::UnexpectedComma => "unexpected comma".to_string(),
+            ParseErrorKind::ScaleOutOfOrder => {
+                format!("scale word '{}' out of order", self.token)
+            }
+            ParseErrorKind::TooBig => format!("value too big at '{}'", self.token),
+        };
+        write!(f, "{}", what)?;
+        if let Some(previous) = &self.previous {
+            write!(f, " after '{}'", previous)?;
+        }
+        write!(f, " at chars {}–{}", self.span.start, self.span.end)
+    }
+}
+
+impl Error for ParseError {}
+
+/// The position of `word` in `list`, as a number
+fn lookup(list: &[&str], word: &str) -> Option<u64> {
+    list.iter()
+        .position(|w| !w.is_empty() && *w == word)
+        .map(|k| k as u64)
+}
+
+/// The power of a thousand named by a scale word
+fn scale(word: &str) -> Option<u32> {
+    lookup(&SCALES, word).map(|k| k as u32 + 1)
+}
+
+fn is_known(word: &str) -> bool {
+    [&UNITS[..], &TEENS, &TENS, &SCALES, &["hundred", "and", "a"]]
+        .iter()
+        .any(|list| lookup(list, word).is_some())
+}
+
+/// Reads the word at `pos`, if there is one
+fn word_at<'a>(tokens: &[Token<'a>], pos: usize) -> Option<&'a str> {
+    match tokens.get(pos) {
+        Some(Token::Word(w)) => Some(w),
+        _ => None,
+    }
+}
+
+/// Reads a number from 1 to 99 at `pos`, or returns 0 if there is none there
+fn under_100(tokens: &[Token], pos: &mut usize) -> u64 {
+    let Some(word) = word_at(tokens, *pos) else {
+        return 0;
+    };
+    if let Some(k) = lookup(&TEENS, word) {
+        *pos += 1;
+        10 + k
+    } else if let Some(k) = lookup(&TENS, word) {
+        *pos += 1;
+        match word_at(tokens, *pos).and_then(|w| lookup(&UNITS[1..], w)) {
+            Some(u) => {
+                *pos += 1;
+                10 * k + u + 1
+            }
+            None => 10 * k,
+        }
+    } else if let Some(u) = lookup(&UNITS[1..], word) {
+        *pos += 1;
+        u + 1
+    } else {
+        0
+    }
+}
+
+/// Reads a group from 1 to 999 at `pos`, such as "three-hundred and five", where "a" can
+/// stand for "one" before "hundred" or a scale word if `article` is true
+///
+/// On failure, returns the position of the token that could not be read.
+fn group(tokens: &[Token], pos: &mut usize, article: bool) -> Result<u64, usize> {
+    let next = word_at(tokens, *pos + 1);
+    if article
+        && word_at(tokens, *pos) == Some("a")
+        && next.is_some_and(|w| w == "hundred" || scale(w).is_some())
+    {
+        *pos += 1;
+        if next == Some("hundred") {
+            *pos += 1;
+            return hundreds_rest(tokens, pos, 100);
+        }
+        return Ok(1);
+    }
+    let value = if let (Some(u), Some("hundred")) = (
+        word_at(tokens, *pos).and_then(|w| lookup(&UNITS[1..], w)),
+        next,
+    ) {
+        *pos += 2;
+        hundreds_rest(tokens, pos, 100 * (u + 1))?
+    } else {
+        under_100(tokens, pos)
+    };
+    if value == 0 {
+        Err(*pos)
+    } else {
+        Ok(value)
+    }
+}
+
+/// Reads the rest of a group after "hundred": nothing, or the tens and units, possibly
+/// after "and"
+fn hundreds_rest(tokens: &[Token], pos: &mut usize, hundreds: u64) -> Result<u64, usize> {
+    if word_at(tokens, *pos) == Some("and") {
+        *pos += 1;
+        match under_100(tokens, pos) {
+            0 => Err(*pos),
+            k => Ok(hundreds + k),
+        }
+    } else {
+        Ok(hundreds + under_100(tokens, pos))
+    }
+}
+
+/// Parse words written by this crate, such as "four thousand, three-hundred and
+/// fifty-two", back into a number
+///
+/// Any of the `AndBehavior` styles, hyphens or spaces, and groups separated by commas or
+/// spaces are accepted, but the words must be lowercase and spelled correctly.
+///
+/// # Errors
+///
+/// The `ParseError` names the word at fault and where it is, and displays as, for
+/// example, "unexpected word 'million' after 'thousand' at chars 23–30".
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::from_words;
+/// use numbers_into_words::parse_words::ParseErrorKind;
+/// assert_eq!(from_words("four thousand, three-hundred and fifty-two"), Ok(4352));
+/// assert_eq!(from_words("four thousand three hundred fifty two"), Ok(4352));
+/// assert_eq!(from_words("a hundred and five"), Ok(105));
+/// assert_eq!(from_words("zero"), Ok(0));
+///
+/// let error = from_words("four fourty").unwrap_err();
+/// assert_eq!(error.kind, ParseErrorKind::UnknownWord);
+/// assert_eq!(error.span, 5..11);
+/// assert_eq!(
+///     from_words("two hundred and six thousand million").unwrap_err().to_string(),
+///     "unexpected word 'million' after 'thousand' at chars 29–36"
+/// );
+/// ```
+pub fn from_words(text: &str) -> Result<u64, ParseError> {
+    let spanned = spanned_tokens(text);
+    if spanned.is_empty() {
+        return Err(ParseError::at(ParseErrorKind::NoWords, text, &spanned, 0));
+    }
+    let tokens: Vec<Token> = spanned.iter().map(|(token, _)| *token).collect();
+    if let Some(index) = tokens
+        .iter()
+        .position(|t| matches!(t, Token::Word(w) if !is_known(w)))
+    {
+        return Err(ParseError::at(
+            ParseErrorKind::UnknownWord,
+            text,
+            &spanned,
+            index,
+        ));
+    }
+    parse_tokens(&tokens).map_err(|(kind, index)| ParseError::at(kind, text, &spanned, index))
+}
+
+/// Parses the words of a number, all known to be in the vocabulary, or returns what went
+/// wrong and the position of the token at fault
+fn parse_tokens(tokens: &[Token]) -> Result<u64, (ParseErrorKind, usize)> {
+    if tokens == [Token::Word("zero")] {
+        return Ok(0);
+    }
+    // a token that can't be read: a comma, a word, or the end of the words
+    let unexpected = |pos: usize| match tokens.get(pos) {
+        Some(Token::Comma) => (ParseErrorKind::UnexpectedComma, pos),
+        Some(Token::Word(_)) => (ParseErrorKind::UnexpectedWord, pos),
+        None => (ParseErrorKind::UnexpectedEnd, pos),
+    };
+    let mut total: u128 = 0;
+    let mut last_power = u32::MAX;
+    let mut top = 0;
+    let mut pos = 0;
+    while pos < tokens.len() {
+        let first = pos == 0;
+        let value = group(tokens, &mut pos, first).map_err(unexpected)?;
+        let power = match word_at(tokens, pos).and_then(scale) {
+            Some(power) => {
+                pos += 1;
+                power
+            }
+            None => 0,
+        };
+        if power >= last_power {
+            return Err((ParseErrorKind::ScaleOutOfOrder, pos - 1));
+        }
+        if first {
+            top = pos - 1;
+        }
+        last_power = power;
+        total = 1000_u128
+            .checked_pow(power)
+            .and_then(|p| p.checked_mul(value as u128))
+            .and_then(|v| v.checked_add(total))
+            .ok_or((ParseErrorKind::TooBig, pos - 1))?;
+        if power > 0 && tokens.get(pos) == Some(&Token::Comma) {
+            pos += 1;
+            if pos == tokens.len() {
+                return Err((ParseErrorKind::UnexpectedComma, pos - 1));
+            }
+        } else if power == 0 && pos < tokens.len() {
+            return Err(unexpected(pos));
+        }
+    }
+    u64::try_from(total).map_err(|_| (ParseErrorKind::TooBig, top))
+}
+
+/// A run of letters and hyphens in prose, such as "forty-two"
+struct Piece {
+    /// Where the piece is in the prose, in bytes
+    span: Range<usize>,
+
+    /// The piece in lowercase
+    text: String,
+
+    /// Whether only spaces, or a comma and spaces, separate it from the piece before
+    joined: bool,
+
+    /// Whether that separation has a comma
+    comma: bool,
+}
+
+/// Splits prose into runs of letters and hyphens
+fn pieces(text: &str) -> Vec<Piece> {
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut last_end = 0;
+    for (idx, c) in text.char_indices().chain(iter::once((text.len(), ' '))) {
+        if c.is_alphabetic() || (c == '-' && start.is_some()) {
+            start.get_or_insert(idx);
+        } else if let Some(s) = start.take() {
+            let end = s + text[s..idx].trim_end_matches('-').len();
+            let gap = text[last_end..s].trim();
+            pieces.push(Piece {
+                span: s..end,
+                text: text[s..end].to_lowercase(),
+                joined: !pieces.is_empty() && (gap.is_empty() || gap == ","),
+                comma: gap == ",",
+            });
+            last_end = end;
+        }
+    }
+    pieces
+}
+
+/// Finds the numbers spelled out in prose, with the byte range of each
+///
+/// Each number is the longest run of words that `from_words` would read, except that
+/// letter case is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::parse_words::extract_numbers;
+/// assert_eq!(
+///     extract_numbers("Twenty-one geese and three hundred and five ducks, one swan."),
+///     vec![(0..10, 21), (21..43, 305), (51..54, 1)]
+/// );
+/// ```
+pub fn extract_numbers(text: &str) -> Vec<(Range<usize>, u64)> {
+    let pieces = pieces(text);
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < pieces.len() {
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut longest: Option<(usize, u64)> = None;
+        for (j, piece) in pieces.iter().enumerate().skip(i) {
+            if j > i && !piece.joined {
+                break;
+            }
+            if j > i && piece.comma {
+                tokens.push(Token::Comma);
+            }
+            if !piece.text.split('-').all(is_known) {
+                break;
+            }
+            tokens.extend(piece.text.split('-').map(Token::Word));
+            if let Ok(value) = parse_tokens(&tokens) {
+                longest = Some((j, value));
+            }
+        }
+        match longest {
+            Some((j, value)) => {
+                found.push((pieces[i].span.start..pieces[j].span.end, value));
+                i = j + 1;
+            }
+            None => i += 1,
+        }
+    }
+    found
+}
+
+/// The number of single-letter insertions, deletions, and substitutions that turn `a` into
+/// `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + usize::from(ca != *cb))
+                .min(above + 1)
+                .min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// The number word closest to a misspelled `word`, if exactly one is close enough
+fn closest_word(word: &str) -> Option<&'static str> {
+    if word.chars().count() < 4 {
+        return None;
+    }
+    let allowed = if word.chars().count() < 7 { 1 } else { 2 };
+    let mut best: Option<(&'static str, usize)> = None;
+    let mut tied = false;
+    for candidate in [&UNITS[..], &TEENS, &TENS, &SCALES, &["hundred"]]
+        .into_iter()
+        .flatten()
+        .filter(|w| !w.is_empty())
+    {
+        let distance = edit_distance(word, candidate);
+        match best {
+            Some((_, d)) if distance == d => tied = true,
+            Some((_, d)) if distance > d => {}
+            _ => {
+                best = Some((candidate, distance));
+                tied = false;
+            }
+        }
+    }
+    match best {
+        Some((candidate, distance)) if distance <= allowed && !tied => Some(candidate),
+        _ => None,
+    }
+}
+
+/// Like `from_words`, but accepting any letter case, "and" anywhere, common misspellings
+/// like "fourty", and numbers in digits, such as "5 thousand"
+///
+/// Along with the value, a warning is returned for each change made to read the words and
+/// for each word ignored.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::parse_words::from_words_lenient;
+/// assert_eq!(
+///     from_words_lenient("Four Thousand and Fourty-2"),
+///     Ok((
+///         4042,
+///         vec![
+///             "Read the words in lowercase".to_string(),
+///             "Ignored an \"and\" not after \"hundred\"".to_string(),
+///             "Read \"fourty\" as \"forty\"".to_string(),
+///             "Read \"2\" as \"two\"".to_string(),
+///         ]
+///     ))
+/// );
+/// assert_eq!(from_words_lenient("three hundred five"), Ok((305, vec![])));
+/// assert_eq!(from_words_lenient("lots"), Err("No number words."));
+/// ```
+pub fn from_words_lenient(text: &str) -> Result<(u64, Vec<String>), &'static str> {
+    let mut warnings = Vec::new();
+    let lowercase = text.to_lowercase();
+    if lowercase != text {
+        warnings.push("Read the words in lowercase".to_string());
+    }
+    let mut words: Vec<String> = Vec::new();
+    for token in tokenize(&lowercase) {
+        let Token::Word(word) = token else {
+            continue;
+        };
+        let digits: String = word.chars().filter(|c| *c != ',' && *c != '_').collect();
+        if word == "and" {
+            if words.last().map(String::as_str) != Some("hundred") {
+                warnings.push("Ignored an \"and\" not after \"hundred\"".to_string());
+            }
+        } else if is_known(word) {
+            words.push(word.to_string());
+        } else if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+            let spelled = to_word(
+                digits.parse::<u64>().map_err(|_| "Value too big.")?,
+                AndBehavior::None,
+            );
+            warnings.push(format!("Read \"{}\" as \"{}\"", word, spelled));
+            words.extend(tokenize(&spelled).into_iter().filter_map(|t| match t {
+                Token::Word(w) => Some(w.to_string()),
+                Token::Comma => None,
+            }));
+        } else if let Some(fixed) = closest_word(word) {
+            warnings.push(format!("Read \"{}\" as \"{}\"", word, fixed));
+            words.push(fixed.to_string());
+        } else {
+            warnings.push(format!("Ignored \"{}\"", word));
+        }
+    }
+    if words.is_empty() {
+        return Err("No number words.");
+    }
+    let tokens: Vec<Token> = words.iter().map(|w| Token::Word(w)).collect();
+    parse_tokens(&tokens)
+        .map(|value| (value, warnings))
+        .map_err(|(kind, _)| kind.message())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion_to_words::{to_word_with, FormatOptions, Hyphenation};
+
+    #[test]
+    fn test_from_words() {
+        let behaviors = [
+            AndBehavior::None,
+            AndBehavior::LastGroup,
+            AndBehavior::OnlyUnderThousand,
+            AndBehavior::All,
+        ];
+        for x in [
+            1,
+            12,
+            40,
+            99,
+            100,
+            101,
+            999,
+            1_000,
+            1_005,
+            20_019,
+            305_112,
+            1_000_000,
+            7_000_000_001,
+            u64::MAX,
+        ] {
+            for b in behaviors {
+                assert_eq!(from_words(&to_word(x, b)), Ok(x));
+                let options = FormatOptions::new()
+                    .and_behavior(b)
+                    .hyphenation(Hyphenation::None)
+                    .commas(false);
+                assert_eq!(from_words(&to_word_with(x as u128, &options)), Ok(x));
+            }
+        }
+        assert_eq!(from_words("a thousand, five"), Ok(1_005));
+        let kind = |text| from_words(text).map_err(|e| e.kind);
+        assert_eq!(kind(""), Err(ParseErrorKind::NoWords));
+        assert_eq!(kind("Five"), Err(ParseErrorKind::UnknownWord));
+        assert_eq!(
+            kind("five thousand, six thousand"),
+            Err(ParseErrorKind::ScaleOutOfOrder)
+        );
+        assert_eq!(kind("five thousand,"), Err(ParseErrorKind::UnexpectedComma));
+        assert_eq!(kind("five, six"), Err(ParseErrorKind::UnexpectedComma));
+        assert_eq!(kind("five hundred and"), Err(ParseErrorKind::UnexpectedEnd));
+        assert_eq!(kind("twenty twenty"), Err(ParseErrorKind::UnexpectedWord));
+        assert_eq!(kind("one zero"), Err(ParseErrorKind::UnexpectedWord));
+        assert_eq!(kind("and five"), Err(ParseErrorKind::UnexpectedWord));
+        assert_eq!(kind("five a"), Err(ParseErrorKind::UnexpectedWord));
+        assert_eq!(kind("twenty quintillion"), Err(ParseErrorKind::TooBig));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = |text| from_words(text).unwrap_err();
+        assert_eq!(
+            error("five thousand, six thousand"),
+            ParseError {
+                kind: ParseErrorKind::ScaleOutOfOrder,
+                token: "thousand".to_string(),
+                previous: Some("six".to_string()),
+                span: 19..27,
+            }
+        );
+        assert_eq!(error("   ").span, 3..3);
+        assert_eq!(error("five hundred and").span, 16..16);
+        assert_eq!(error("twenty quintillion").span, 7..18);
+        assert_eq!(error("ninety-fourty").span, 7..13);
+        assert_eq!(error("über zwei").span, 0..4);
+        assert_eq!(error("fifty- five").span, 5..6);
+        assert_eq!(
+            error("five thousand,").to_string(),
+            "unexpected comma after 'thousand' at chars 13–14"
+        );
+        assert_eq!(
+            error("sixteen\u{3000}quadrillion fourty").to_string(),
+            "unknown word 'fourty' after 'quadrillion' at chars 20–26"
+        );
+        assert_eq!(
+            error("five hundred and").to_string(),
+            "unexpected end of words after 'and' at chars 16–16"
+        );
+        assert_eq!(error("").to_string(), "no words");
+    }
+
+    #[test]
+    fn test_from_words_lenient() {
+        assert_eq!(
+            from_words_lenient("one thousand and five"),
+            Ok((
+                1_005,
+                vec![String::from("Ignored an \"and\" not after \"hundred\"")]
+            ))
+        );
+        assert_eq!(
+            from_words_lenient("ninty-nine milion, 1,500"),
+            Ok((
+                99_001_500,
+                vec![
+                    String::from("Read \"ninty\" as \"ninety\""),
+                    String::from("Read \"milion\" as \"million\""),
+                    String::from("Read \"1,500\" as \"one thousand, five-hundred\""),
+                ]
+            ))
+        );
+        assert_eq!(
+            from_words_lenient("about twelve dollars"),
+            Ok((
+                12,
+                vec![
+                    String::from("Ignored \"about\""),
+                    String::from("Ignored \"dollars\""),
+                ]
+            ))
+        );
+        assert_eq!(from_words_lenient("six seven"), Err("Unexpected word."));
+        assert_eq!(from_words_lenient(""), Err("No number words."));
+        assert_eq!(closest_word("dozen"), None);
+        assert_eq!(closest_word("eightteen"), Some("eighteen"));
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_extract_numbers() {
+        let text = "In twenty twenty, a thousand, five-hundred people saw one-zero; \
+                    two million, and, a few";
+        let found = extract_numbers(text);
+        assert_eq!(
+            found
+                .iter()
+                .map(|(span, value)| (&text[span.clone()], *value))
+                .collect::<Vec<_>>(),
+            vec![
+                ("twenty", 20),
+                ("twenty", 20),
+                ("a thousand, five-hundred", 1_500),
+                ("two million", 2_000_000),
+            ]
+        );
+        assert_eq!(extract_numbers("¡Dos! élan — nine"), vec![(17..21, 9)]);
+        assert_eq!(extract_numbers(""), vec![]);
+    }
+}
@@ -0,0 +1,85 @@
+/* src/powers.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{to_ordinal_word, AndBehavior};
+use super::to_word;
+
+/// Reads a power aloud, using "squared" and "cubed" where idiomatic
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::AndBehavior;
+/// use numbers_into_words::powers::power_to_words;
+/// assert_eq!(
+///     power_to_words(5, 2, AndBehavior::All),
+///     "five squared".to_string()
+/// );
+/// assert_eq!(
+///     power_to_words(12, 3, AndBehavior::All),
+///     "twelve cubed".to_string()
+/// );
+/// assert_eq!(
+///     power_to_words(2, 10, AndBehavior::All),
+///     "two to the tenth power".to_string()
+/// );
+/// assert_eq!(
+///     power_to_words(10, 100, AndBehavior::All),
+///     "ten to the one-hundredth power".to_string()
+/// );
+/// ```
+pub fn power_to_words(base: u64, exponent: u64, and_behavior: AndBehavior) -> String {
+    let base_words = to_word(base, and_behavior);
+    match exponent {
+        2 => format!("{} squared", base_words),
+        3 => format!("{} cubed", base_words),
+        _ => format!(
+            "{} to the {} power",
+            base_words,
+            to_ordinal_word(exponent, and_behavior)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_power_to_words() {
+        assert_eq!(
+            power_to_words(7, 0, AndBehavior::All),
+            String::from("seven to the zeroth power")
+        );
+        assert_eq!(
+            power_to_words(7, 1, AndBehavior::All),
+            String::from("seven to the first power")
+        );
+        assert_eq!(
+            power_to_words(3, 21, AndBehavior::All),
+            String::from("three to the twenty-first power")
+        );
+        assert_eq!(
+            power_to_words(150, 2, AndBehavior::None),
+            String::from("one-hundred fifty squared")
+        );
+        assert_eq!(
+            power_to_words(2, 102, AndBehavior::All),
+            String::from("two to the one-hundred and second power")
+        );
+        assert_eq!(
+            power_to_words(2, 102, AndBehavior::None),
+            String::from("two to the one-hundred second power")
+        );
+    }
+}
@@ -0,0 +1,116 @@
+/* src/random.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The most numbers `--random=` writes
+pub const MAX_COUNT: u64 = 10_000_000;
+
+/// The largest number `--random=` writes unless `--max=` is given
+pub const DEFAULT_MAX: u64 = 1_000_000;
+
+/// An endless stream of random numbers from `min` to `max` (both included), each as
+/// likely as any other, for quizzes and test fixtures; not for anything that needs to be
+/// unpredictable
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::random::UniformNumbers;
+/// let dice: Vec<u64> = UniformNumbers::new(42, 1, 6).take(100).collect();
+/// assert!(dice.iter().all(|x| (1..=6).contains(x)));
+/// assert_eq!(dice, UniformNumbers::new(42, 1, 6).take(100).collect::<Vec<u64>>());
+/// ```
+#[derive(Clone, Debug)]
+pub struct UniformNumbers {
+    state: u64,
+    min: u64,
+    max: u64,
+}
+
+impl UniformNumbers {
+    /// The stream for `seed`, which always gives the same numbers; if `min` is more than
+    /// `max`, they are swapped
+    pub fn new(seed: u64, min: u64, max: u64) -> Self {
+        Self {
+            state: seed,
+            min: min.min(max),
+            max: max.max(min),
+        }
+    }
+
+    /// A stream seeded from the clock, which differs from run to run
+    pub fn from_clock(min: u64, max: u64) -> Self {
+        Self::new(clock_seed(), min, max)
+    }
+
+    /// The next 64 random bits (SplitMix64)
+    fn next_bits(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl Iterator for UniformNumbers {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let span = (self.max - self.min) as u128 + 1;
+        // skip the last, partial run of `span` values, which would favor small numbers
+        let limit = (1_u128 << 64) - (1_u128 << 64) % span;
+        loop {
+            let bits = self.next_bits() as u128;
+            if bits < limit {
+                return Some(self.min + (bits % span) as u64);
+            }
+        }
+    }
+}
+
+/// A seed from the current time
+pub fn clock_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |t| t.as_nanos() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_numbers() {
+        let numbers: Vec<u64> = UniformNumbers::new(7, 10, 19).take(1_000).collect();
+        assert!(numbers.iter().all(|x| (10..=19).contains(x)));
+        for k in 10..=19 {
+            let count = numbers.iter().filter(|x| **x == k).count();
+            assert!((50..150).contains(&count), "{} came up {} times", k, count);
+        }
+        assert_ne!(
+            numbers,
+            UniformNumbers::new(8, 10, 19)
+                .take(1_000)
+                .collect::<Vec<u64>>()
+        );
+        assert!(UniformNumbers::new(1, 5, 5).take(10).all(|x| x == 5));
+        assert!(UniformNumbers::new(1, 9, 3)
+            .take(100)
+            .all(|x| (3..=9).contains(&x)));
+        assert!(UniformNumbers::new(3, 0, u64::MAX)
+            .take(10)
+            .any(|x| x > u64::MAX / 2));
+    }
+}
@@ -0,0 +1,133 @@
+/* src/ranges.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::numerals::parse_strict;
+
+/// The most numbers `--range` writes, so that a typo like "1..10000000000000" is caught
+/// rather than spelled out for days
+pub const MAX_RANGE_LEN: u128 = 10_000_000;
+
+/// A run of whole numbers such as "1..100" or "1..=100", counting up or, if the start
+/// is past the end, down, by a step of 1 unless another is set
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::ranges::NumberRange;
+/// let range = NumberRange::parse("0..=20").unwrap().step(5);
+/// assert_eq!(range.iter().collect::<Vec<u128>>(), vec![0, 5, 10, 15, 20]);
+/// let range = NumberRange::parse("10..7").unwrap();
+/// assert_eq!(range.iter().collect::<Vec<u128>>(), vec![10, 9, 8]);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct NumberRange {
+    start: u128,
+    end: u128,
+    inclusive: bool,
+    step: u128,
+}
+
+impl NumberRange {
+    /// A range from `start` up to (or down to) `end`, including `end` if `inclusive`
+    pub fn new(start: u128, end: u128, inclusive: bool) -> Self {
+        Self {
+            start,
+            end,
+            inclusive,
+            step: 1,
+        }
+    }
+
+    /// Parses "A..B" or "A..=B", where the numbers may have `_` and `,` separators
+    pub fn parse(text: &str) -> Result<Self, &'static str> {
+        let (start, end) = text.trim().split_once("..").ok_or("Invalid range.")?;
+        let (end, inclusive) = match end.strip_prefix('=') {
+            Some(end) => (end, true),
+            None => (end, false),
+        };
+        Ok(Self::new(
+            parse_strict(start)?,
+            parse_strict(end)?,
+            inclusive,
+        ))
+    }
+
+    /// Sets the difference between one number and the next; a step of 0 is taken as 1
+    pub fn step(mut self, step: u128) -> Self {
+        self.step = step.max(1);
+        self
+    }
+
+    /// How many numbers are in the range
+    pub fn len(&self) -> u128 {
+        let span = self.start.abs_diff(self.end);
+        match (self.inclusive, span) {
+            (true, _) => (span / self.step).saturating_add(1),
+            (false, 0) => 0,
+            (false, _) => (span - 1) / self.step + 1,
+        }
+    }
+
+    /// Whether the range has no numbers, as with "5..5"
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The numbers in the range, in order, computed as they are needed
+    pub fn iter(&self) -> impl Iterator<Item = u128> {
+        let Self {
+            start, end, step, ..
+        } = *self;
+        (0..self.len()).map(move |k| {
+            if start <= end {
+                start + k * step
+            } else {
+                start - k * step
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number_range() {
+        let values = |range: NumberRange| range.iter().collect::<Vec<u128>>();
+        assert_eq!(values(NumberRange::parse("1..4").unwrap()), vec![1, 2, 3]);
+        assert_eq!(
+            values(NumberRange::parse("1..=4").unwrap()),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            values(NumberRange::parse("1..=10").unwrap().step(4)),
+            vec![1, 5, 9]
+        );
+        assert_eq!(
+            values(NumberRange::parse("1..9").unwrap().step(4)),
+            vec![1, 5]
+        );
+        assert_eq!(
+            values(NumberRange::parse("10..=0").unwrap().step(5)),
+            vec![10, 5, 0]
+        );
+        assert_eq!(values(NumberRange::parse("1..1").unwrap().step(0)), vec![]);
+        assert!(NumberRange::parse("1..1").unwrap().is_empty());
+        assert_eq!(NumberRange::parse("1_000..=2,000").unwrap().len(), 1001);
+        assert_eq!(NumberRange::new(0, u128::MAX, true).len(), u128::MAX);
+        assert_eq!(NumberRange::parse("1-5"), Err("Invalid range."));
+        assert_eq!(NumberRange::parse("1..x"), Err("Invalid digit."));
+        assert_eq!(NumberRange::parse("..5"), Err("No digits."));
+    }
+}
@@ -0,0 +1,180 @@
+/* src/results.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{to_word_with, FormatOptions};
+use super::decimal::Decimal;
+
+/// The result of converting one number, with the input as it was given
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::results::Conversion;
+/// use numbers_into_words::FormatOptions;
+/// let conversion = Conversion::new("1,005", &FormatOptions::new()).unwrap();
+/// assert_eq!(conversion.value, 1005);
+/// assert_eq!(
+///     conversion.to_json(),
+///     "{\"input\":\"1,005\",\"value\":1005,\"words\":\"one thousand, five\"}".to_string()
+/// );
+/// assert_eq!(
+///     conversion.to_csv_row(),
+///     "\"1,005\",1005,\"one thousand, five\"".to_string()
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Conversion {
+    /// The numeral that was converted, e.g. "1,005"
+    pub input: String,
+
+    /// The value of the numeral
+    pub value: u128,
+
+    /// The words for the value
+    pub words: String,
+}
+
+impl Conversion {
+    /// Converts a numeral, which may contain `_` or `,` separators, in the style given by
+    /// `options`
+    pub fn new(input: &str, options: &FormatOptions) -> Result<Self, &'static str> {
+        let decimal = Decimal::parse(input)?;
+        if decimal.negative || !decimal.fraction.is_empty() {
+            return Err("Not a whole number.");
+        }
+        Ok(Self {
+            input: input.to_string(),
+            value: decimal.whole,
+            words: to_word_with(decimal.whole, options),
+        })
+    }
+
+    /// The conversion as a JSON object with the fields `input`, `value`, and `words`
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"input\":{},\"value\":{},\"words\":{}}}",
+            json_string(&self.input),
+            self.value,
+            json_string(&self.words)
+        )
+    }
+
+    /// The conversion as a CSV row with the fields `input`, `value`, and `words`, quoted
+    /// where needed
+    pub fn to_csv_row(&self) -> String {
+        csv_row(&[&self.input, &self.value.to_string(), &self.words])
+    }
+}
+
+/// Conversions as a JSON array of objects, as given by `Conversion::to_json`
+pub fn to_json_array(conversions: &[Conversion]) -> String {
+    let objects: Vec<String> = conversions.iter().map(Conversion::to_json).collect();
+    format!("[{}]", objects.join(","))
+}
+
+/// The header of the CSV written by `to_csv`
+pub const CSV_HEADER: &str = "input,value,words";
+
+/// Conversions as CSV, with a header and one row per conversion, as given by
+/// `Conversion::to_csv_row`
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::results::{to_csv, Conversion};
+/// use numbers_into_words::FormatOptions;
+/// let conversions = vec![
+///     Conversion::new("12", &FormatOptions::new()).unwrap(),
+///     Conversion::new("2_001", &FormatOptions::new()).unwrap(),
+/// ];
+/// assert_eq!(
+///     to_csv(&conversions),
+///     "input,value,words\n12,12,twelve\n2_001,2001,\"two thousand, one\"".to_string()
+/// );
+/// ```
+pub fn to_csv(conversions: &[Conversion]) -> String {
+    let mut rows = vec![CSV_HEADER.to_string()];
+    rows.extend(conversions.iter().map(Conversion::to_csv_row));
+    rows.join("\n")
+}
+
+/// Joins fields into a CSV row, quoting any field with a comma, quote, or line break
+pub(crate) fn csv_row(fields: &[&str]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// `text` as a quoted JSON string
+pub(crate) fn json_string(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\t' => quoted.push_str("\\t"),
+            c if u32::from(c) < 0x20 => quoted.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion_to_words::Case;
+
+    #[test]
+    fn test_conversion() {
+        let options = FormatOptions::new().group_separator("\n").case(Case::Title);
+        let conversions = vec![
+            Conversion::new("7", &FormatOptions::new()).unwrap(),
+            Conversion::new("2_000_001", &options).unwrap(),
+        ];
+        assert_eq!(
+            to_json_array(&conversions),
+            "[{\"input\":\"7\",\"value\":7,\"words\":\"seven\"},\
+             {\"input\":\"2_000_001\",\"value\":2000001,\"words\":\"Two Million\\nOne\"}]"
+                .to_string()
+        );
+        assert_eq!(to_json_array(&[]), "[]".to_string());
+        assert_eq!(
+            to_csv(&conversions),
+            "input,value,words\n7,7,seven\n2_000_001,2000001,\"Two Million\nOne\"".to_string()
+        );
+        assert_eq!(to_csv(&[]), CSV_HEADER.to_string());
+        assert_eq!(
+            csv_row(&["say \"hi\"", "", "a\rb"]),
+            "\"say \"\"hi\"\"\",,\"a\rb\"".to_string()
+        );
+        assert_eq!(Conversion::new("-4", &options), Err("Not a whole number."));
+        assert_eq!(Conversion::new("1.5", &options), Err("Not a whole number."));
+        assert_eq!(Conversion::new("x", &options), Err("Invalid digit."));
+        assert_eq!(
+            json_string("say \"hi\"\\\u{1}"),
+            "\"say \\\"hi\\\"\\\\\\u0001\"".to_string()
+        );
+    }
+}
@@ -0,0 +1,171 @@
+/* src/round_trip.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{to_word, AndBehavior};
+use super::parse_words::{from_words, ParseError};
+use std::fmt;
+
+/// Every `AndBehavior`, in the order the round trip checks them
+pub const AND_BEHAVIORS: [AndBehavior; 4] = [
+    AndBehavior::None,
+    AndBehavior::LastGroup,
+    AndBehavior::OnlyUnderThousand,
+    AndBehavior::All,
+];
+
+/// A number whose words did not read back as the same number
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure {
+    /// The number converted to words
+    pub value: u64,
+
+    /// The style the words were written in
+    pub and_behavior: AndBehavior,
+
+    /// The words written for `value`
+    pub words: String,
+
+    /// What reading `words` back gave
+    pub parsed: Result<u64, ParseError>,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} ({:?}) was written \"{}\", which ",
+            self.value, self.and_behavior, self.words
+        )?;
+        match &self.parsed {
+            Ok(x) => write!(f, "read back as {}", x),
+            Err(e) => write!(f, "could not be read back: {}", e),
+        }
+    }
+}
+
+/// Checks that the words for `value` read back as `value` with every `AndBehavior`
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::round_trip::check;
+/// assert_eq!(check(4_352), Ok(()));
+/// assert_eq!(check(u64::MAX), Ok(()));
+/// ```
+pub fn check(value: u64) -> Result<(), Failure> {
+    for and_behavior in AND_BEHAVIORS {
+        let words = to_word(value, and_behavior);
+        let parsed = from_words(&words);
+        if parsed != Ok(value) {
+            return Err(Failure {
+                value,
+                and_behavior,
+                words,
+                parsed,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// A repeatable stream of random numbers for round trips, weighted towards the cases
+/// most likely to go wrong: small numbers, powers of ten and their neighbors, and values
+/// near `u64::MAX`
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::round_trip::RandomNumbers;
+/// let first: Vec<u64> = RandomNumbers::new(7).take(5).collect();
+/// assert_eq!(first, RandomNumbers::new(7).take(5).collect::<Vec<u64>>());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RandomNumbers {
+    state: u64,
+}
+
+impl RandomNumbers {
+    /// The stream for `seed`; the same seed always gives the same numbers
+    pub fn new(seed: u64) -> Self {
+        // xorshift never leaves a state of zero, so mix the seed first
+        RandomNumbers {
+            state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1,
+        }
+    }
+}
+
+impl Iterator for RandomNumbers {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        // xorshift64
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        let bits = self.state;
+        let power = 10_u64.pow((bits >> 8) as u32 % 20);
+        Some(match bits % 5 {
+            0 => bits >> 54,
+            1 => power,
+            2 => power.wrapping_add((bits >> 32) % 3).wrapping_sub(1),
+            3 => u64::MAX - (bits >> 44),
+            _ => bits >> (bits >> 58),
+        })
+    }
+}
+
+/// Checks 0, `u64::MAX`, and `cases` numbers from `RandomNumbers::new(seed)`, stopping
+/// at the first failure
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::round_trip::check_random;
+/// assert_eq!(check_random(2024, 500), Ok(()));
+/// ```
+pub fn check_random(seed: u64, cases: usize) -> Result<(), Failure> {
+    [0, u64::MAX]
+        .into_iter()
+        .chain(RandomNumbers::new(seed).take(cases))
+        .try_for_each(check)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for seed in 0..4 {
+            assert_eq!(check_random(seed, 2_000), Ok(()));
+        }
+        let numbers: Vec<u64> = RandomNumbers::new(1).take(1_000).collect();
+        assert!(numbers.iter().any(|x| *x < 1000));
+        assert!(numbers.iter().any(|x| *x > u64::MAX - (1 << 20)));
+        assert!(numbers.contains(&999_999));
+        assert_ne!(
+            numbers,
+            RandomNumbers::new(2).take(1_000).collect::<Vec<u64>>()
+        );
+        let failure = Failure {
+            value: 12,
+            and_behavior: AndBehavior::All,
+            words: "twelve".to_string(),
+            parsed: Ok(2),
+        };
+        assert_eq!(
+            failure.to_string(),
+            "12 (All) was written \"twelve\", which read back as 2"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+/* src/ssml.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{to_word, AndBehavior};
+
+/// Signals how a number is written in SSML
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SsmlStyle {
+    /// Leave the numeral to the speech engine, marked as a cardinal
+    /// (`<say-as interpret-as="cardinal">42</say-as>`)
+    SayAs,
+
+    /// Spell out the words as a sentence (`<s>forty-two</s>`)
+    Spelled,
+
+    /// Spell out the words as a sentence, pausing for the given number of milliseconds
+    /// between the groups of three digits
+    SpelledWithBreaks(u32),
+}
+
+/// Escapes the characters that have a meaning in XML
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// The SSML for one number, given its numeral and its words, where `group_separator`
+/// joins the groups of the words (", " unless changed)
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::ssml::{fragment, SsmlStyle};
+/// assert_eq!(
+///     fragment(SsmlStyle::SayAs, "2859", "two thousand, eight-hundred", ", "),
+///     "<say-as interpret-as=\"cardinal\">2859</say-as>".to_string()
+/// );
+/// assert_eq!(
+///     fragment(
+///         SsmlStyle::SpelledWithBreaks(300),
+///         "2859",
+///         "two thousand, eight-hundred and fifty-nine",
+///         ", "
+///     ),
+///     "<s>two thousand<break time=\"300ms\"/> eight-hundred and fifty-nine</s>".to_string()
+/// );
+/// ```
+pub fn fragment(style: SsmlStyle, numeral: &str, words: &str, group_separator: &str) -> String {
+    match style {
+        SsmlStyle::SayAs => format!(
+            "<say-as interpret-as=\"cardinal\">{}</say-as>",
+            escape(numeral)
+        ),
+        SsmlStyle::Spelled => format!("<s>{}</s>", escape(words)),
+        SsmlStyle::SpelledWithBreaks(ms) => {
+            let pause = format!("<break time=\"{}ms\"/> ", ms);
+            let groups: Vec<String> = if group_separator.is_empty() {
+                vec![escape(words)]
+            } else {
+                words.split(group_separator).map(escape).collect()
+            };
+            format!("<s>{}</s>", groups.join(&pause))
+        }
+    }
+}
+
+/// Converts a number to SSML in the given style
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::ssml::{to_ssml, SsmlStyle};
+/// use numbers_into_words::AndBehavior;
+/// assert_eq!(
+///     to_ssml(1_000_005, AndBehavior::All, SsmlStyle::Spelled),
+///     "<s>one million, five</s>".to_string()
+/// );
+/// ```
+pub fn to_ssml(x: u64, and_behavior: AndBehavior, style: SsmlStyle) -> String {
+    fragment(style, &x.to_string(), &to_word(x, and_behavior), ", ")
+}
+
+/// Wraps SSML fragments in a `<speak>` document, one fragment per line
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::ssml::{speak, to_ssml, SsmlStyle};
+/// use numbers_into_words::AndBehavior;
+/// assert_eq!(
+///     speak(&[to_ssml(12, AndBehavior::All, SsmlStyle::SayAs)]),
+///     "<speak>\n<say-as interpret-as=\"cardinal\">12</say-as>\n</speak>".to_string()
+/// );
+/// ```
+pub fn speak<S: AsRef<str>>(fragments: &[S]) -> String {
+    let mut document = String::from("<speak>\n");
+    for fragment in fragments {
+        document.push_str(fragment.as_ref());
+        document.push('\n');
+    }
+    document.push_str("</speak>");
+    document
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ssml() {
+        assert_eq!(
+            escape("<a & \"b\" 'c'>"),
+            "&lt;a &amp; &quot;b&quot; &apos;c&apos;&gt;".to_string()
+        );
+        assert_eq!(
+            to_ssml(
+                330_759_736,
+                AndBehavior::None,
+                SsmlStyle::SpelledWithBreaks(250)
+            ),
+            "<s>three-hundred thirty million<break time=\"250ms\"/> seven-hundred fifty-nine \
+             thousand<break time=\"250ms\"/> seven-hundred thirty-six</s>"
+                .to_string()
+        );
+        assert_eq!(
+            to_ssml(7, AndBehavior::All, SsmlStyle::SpelledWithBreaks(250)),
+            "<s>seven</s>".to_string()
+        );
+        assert_eq!(
+            fragment(SsmlStyle::SpelledWithBreaks(5), "1", "a<b", ""),
+            "<s>a&lt;b</s>".to_string()
+        );
+        assert_eq!(speak::<String>(&[]), "<speak>\n</speak>".to_string());
+    }
+}
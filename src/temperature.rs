@@ -0,0 +1,205 @@
+/* src/temperature.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{to_word_signed_prefixed, AndBehavior, NegativePrefix};
+use super::to_word;
+
+/// The temperature scale named after the reading
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TemperatureUnit {
+    /// "degrees Celsius"
+    Celsius,
+
+    /// "degrees Fahrenheit"
+    Fahrenheit,
+
+    /// "kelvins" (no "degrees")
+    Kelvin,
+
+    /// "degrees", with no scale named
+    Unspecified,
+}
+
+/// Signals how a temperature below zero is read
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NegativeStyle {
+    /// "twelve degrees below zero Celsius"
+    BelowZero,
+
+    /// "minus twelve degrees Celsius"
+    Minus,
+}
+
+impl TemperatureUnit {
+    fn words(&self, magnitude: u64) -> (&'static str, &'static str) {
+        let plural = magnitude != 1;
+        match (self, plural) {
+            (Self::Celsius, true) => ("degrees", " Celsius"),
+            (Self::Celsius, false) => ("degree", " Celsius"),
+            (Self::Fahrenheit, true) => ("degrees", " Fahrenheit"),
+            (Self::Fahrenheit, false) => ("degree", " Fahrenheit"),
+            (Self::Kelvin, true) => ("kelvins", ""),
+            (Self::Kelvin, false) => ("kelvin", ""),
+            (Self::Unspecified, true) => ("degrees", ""),
+            (Self::Unspecified, false) => ("degree", ""),
+        }
+    }
+}
+
+/// Reads a temperature aloud, for weather reports and the like
+///
+/// Kelvins start at absolute zero, so a negative reading in kelvins is an error.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::AndBehavior;
+/// use numbers_into_words::temperature::{temperature_to_words, NegativeStyle, TemperatureUnit};
+/// assert_eq!(
+///     temperature_to_words(
+///         -12,
+///         TemperatureUnit::Celsius,
+///         NegativeStyle::BelowZero,
+///         AndBehavior::All
+///     ),
+///     Ok("twelve degrees below zero Celsius".to_string())
+/// );
+/// assert_eq!(
+///     temperature_to_words(
+///         -12,
+///         TemperatureUnit::Unspecified,
+///         NegativeStyle::Minus,
+///         AndBehavior::All
+///     ),
+///     Ok("minus twelve degrees".to_string())
+/// );
+/// assert_eq!(
+///     temperature_to_words(
+///         451,
+///         TemperatureUnit::Fahrenheit,
+///         NegativeStyle::Minus,
+///         AndBehavior::All
+///     ),
+///     Ok("four-hundred and fifty-one degrees Fahrenheit".to_string())
+/// );
+/// assert_eq!(
+///     temperature_to_words(-1, TemperatureUnit::Kelvin, NegativeStyle::Minus, AndBehavior::All),
+///     Err("Negative kelvins are below absolute zero.")
+/// );
+/// ```
+pub fn temperature_to_words(
+    degrees: i64,
+    unit: TemperatureUnit,
+    style: NegativeStyle,
+    and_behavior: AndBehavior,
+) -> Result<String, &'static str> {
+    if degrees < 0 && unit == TemperatureUnit::Kelvin {
+        return Err("Negative kelvins are below absolute zero.");
+    }
+    let magnitude = degrees.unsigned_abs();
+    let words = to_word(magnitude, and_behavior);
+    let (degree_word, scale) = unit.words(magnitude);
+    Ok(match (degrees < 0, style) {
+        (false, _) => format!("{} {}{}", words, degree_word, scale),
+        (true, NegativeStyle::BelowZero) => {
+            format!("{} {} below zero{}", words, degree_word, scale)
+        }
+        (true, NegativeStyle::Minus) => format!(
+            "{} {}{}",
+            to_word_signed_prefixed(degrees, and_behavior, NegativePrefix::Minus),
+            degree_word,
+            scale
+        ),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temperature_to_words() {
+        assert_eq!(
+            temperature_to_words(
+                0,
+                TemperatureUnit::Celsius,
+                NegativeStyle::BelowZero,
+                AndBehavior::All
+            ),
+            Ok(String::from("zero degrees Celsius"))
+        );
+        assert_eq!(
+            temperature_to_words(
+                1,
+                TemperatureUnit::Celsius,
+                NegativeStyle::BelowZero,
+                AndBehavior::All
+            ),
+            Ok(String::from("one degree Celsius"))
+        );
+        assert_eq!(
+            temperature_to_words(
+                -1,
+                TemperatureUnit::Fahrenheit,
+                NegativeStyle::BelowZero,
+                AndBehavior::All
+            ),
+            Ok(String::from("one degree below zero Fahrenheit"))
+        );
+        assert_eq!(
+            temperature_to_words(
+                -40,
+                TemperatureUnit::Fahrenheit,
+                NegativeStyle::Minus,
+                AndBehavior::All
+            ),
+            Ok(String::from("minus forty degrees Fahrenheit"))
+        );
+        assert_eq!(
+            temperature_to_words(
+                -273,
+                TemperatureUnit::Unspecified,
+                NegativeStyle::BelowZero,
+                AndBehavior::None
+            ),
+            Ok(String::from("two-hundred seventy-three degrees below zero"))
+        );
+        assert_eq!(
+            temperature_to_words(
+                373,
+                TemperatureUnit::Kelvin,
+                NegativeStyle::Minus,
+                AndBehavior::All
+            ),
+            Ok(String::from("three-hundred and seventy-three kelvins"))
+        );
+        assert_eq!(
+            temperature_to_words(
+                1,
+                TemperatureUnit::Kelvin,
+                NegativeStyle::Minus,
+                AndBehavior::All
+            ),
+            Ok(String::from("one kelvin"))
+        );
+        assert_eq!(
+            temperature_to_words(
+                -1,
+                TemperatureUnit::Kelvin,
+                NegativeStyle::BelowZero,
+                AndBehavior::All
+            ),
+            Err("Negative kelvins are below absolute zero.")
+        );
+    }
+}
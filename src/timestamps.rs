@@ -0,0 +1,396 @@
+/* src/timestamps.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{to_ordinal_word, AndBehavior};
+use super::to_word;
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A calendar date and time of day in UTC
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Timestamp {
+    year: i64,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+}
+
+/// Days since 1970-01-01 to (year, month, day) in the proleptic Gregorian calendar
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// (year, month, day) in the proleptic Gregorian calendar to days since 1970-01-01
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153 * if month > 2 { month - 3 } else { month + 9 } + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn parse_field(text: &str, field: &'static str) -> Result<u32, &'static str> {
+    if text.is_empty() || !text.chars().all(|c| c.is_ascii_digit()) {
+        return Err(field);
+    }
+    text.parse::<u32>().map_err(|_| field)
+}
+
+impl Timestamp {
+    fn from_epoch(secs: i64) -> Self {
+        let (year, month, day) = civil_from_days(secs.div_euclid(SECONDS_PER_DAY));
+        let rem = secs.rem_euclid(SECONDS_PER_DAY);
+        Self {
+            year,
+            month,
+            day,
+            hour: (rem / 3600) as u32,
+            minute: (rem % 3600 / 60) as u32,
+            second: (rem % 60) as u32,
+        }
+    }
+
+    /// Parses an RFC 3339 timestamp, returning the number of seconds since the epoch and
+    /// whether it names a leap second (":60"), which is counted as the second before it
+    fn parse_rfc3339(text: &str) -> Result<(i64, bool), &'static str> {
+        let text = text.trim();
+        if !text.is_ascii() || text.len() < 20 {
+            return Err("Timestamp too short.");
+        }
+        let bytes = text.as_bytes();
+        if bytes[4] != b'-'
+            || bytes[7] != b'-'
+            || !matches!(bytes[10], b'T' | b't' | b' ')
+            || bytes[13] != b':'
+            || bytes[16] != b':'
+        {
+            return Err("Timestamp not in YYYY-MM-DDTHH:MM:SS form.");
+        }
+        let year = parse_field(&text[0..4], "Invalid year.")? as i64;
+        let month = parse_field(&text[5..7], "Invalid month.")?;
+        let day = parse_field(&text[8..10], "Invalid day.")?;
+        let hour = parse_field(&text[11..13], "Invalid hour.")?;
+        let minute = parse_field(&text[14..16], "Invalid minute.")?;
+        let second = parse_field(&text[17..19], "Invalid second.")?;
+        if !(1..=12).contains(&month) {
+            return Err("Invalid month.");
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return Err("Invalid day.");
+        }
+        if hour > 23 || minute > 59 || second > 60 {
+            return Err("Invalid time.");
+        }
+
+        let mut rest = &text[19..];
+        if let Some(frac) = rest.strip_prefix('.') {
+            let digits = frac.chars().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 {
+                return Err("Invalid fractional seconds.");
+            }
+            rest = &frac[digits..];
+        }
+        let offset = match rest {
+            "Z" | "z" => 0,
+            _ if rest.len() == 6 && &rest[3..4] == ":" => {
+                let sign = match &rest[0..1] {
+                    "+" => 1,
+                    "-" => -1,
+                    _ => return Err("Invalid time zone offset."),
+                };
+                let hours = parse_field(&rest[1..3], "Invalid time zone offset.")?;
+                let minutes = parse_field(&rest[4..6], "Invalid time zone offset.")?;
+                if hours > 23 || minutes > 59 {
+                    return Err("Invalid time zone offset.");
+                }
+                sign * (hours as i64 * 3600 + minutes as i64 * 60)
+            }
+            _ => return Err("Invalid time zone offset."),
+        };
+
+        let leap = second == 60;
+        let secs = days_from_civil(year, month, day) * SECONDS_PER_DAY
+            + hour as i64 * 3600
+            + minute as i64 * 60
+            + second.min(59) as i64
+            - offset;
+        // leap seconds are inserted at the end of a UTC day
+        if leap && secs.rem_euclid(SECONDS_PER_DAY) != SECONDS_PER_DAY - 1 {
+            return Err("Invalid leap second.");
+        }
+        Ok((secs, leap))
+    }
+
+    fn to_words(self) -> String {
+        format!(
+            "{} {}, {}, {} UTC",
+            MONTHS[self.month as usize - 1],
+            to_ordinal_word(self.day as u64, AndBehavior::None),
+            year_words(self.year),
+            time_words(self.hour, self.minute, self.second)
+        )
+    }
+}
+
+/// Reads a year the way it is usually spoken ("nineteen oh five", "twenty twenty-four")
+fn year_words(year: i64) -> String {
+    let (high, low) = (year / 100, year % 100);
+    match year {
+        y if y <= 0 => format!("{} BC", to_word((1 - y) as u64, AndBehavior::None)),
+        y if !(1000..=9999).contains(&y) || y % 1000 == 0 => to_word(y as u64, AndBehavior::None),
+        2001..=2009 => format!("two thousand {}", to_word(low as u64, AndBehavior::None)),
+        _ if low == 0 => format!("{} hundred", to_word(high as u64, AndBehavior::None)),
+        _ if low < 10 => format!(
+            "{} oh {}",
+            to_word(high as u64, AndBehavior::None),
+            to_word(low as u64, AndBehavior::None)
+        ),
+        _ => format!(
+            "{} {}",
+            to_word(high as u64, AndBehavior::None),
+            to_word(low as u64, AndBehavior::None)
+        ),
+    }
+}
+
+/// Reads a time of day on the 24-hour clock ("fourteen oh five and thirty seconds")
+fn time_words(hour: u32, minute: u32, second: u32) -> String {
+    let hour_word = to_word(hour as u64, AndBehavior::None);
+    let hour_minute = match minute {
+        0 => format!("{} hundred", hour_word),
+        1..=9 => format!(
+            "{} oh {}",
+            hour_word,
+            to_word(minute as u64, AndBehavior::None)
+        ),
+        _ => format!(
+            "{} {}",
+            hour_word,
+            to_word(minute as u64, AndBehavior::None)
+        ),
+    };
+    match second {
+        0 => hour_minute,
+        1 => format!("{} and one second", hour_minute),
+        _ => format!(
+            "{} and {} seconds",
+            hour_minute,
+            to_word(second as u64, AndBehavior::None)
+        ),
+    }
+}
+
+/// Reads a Unix timestamp (seconds since 1970-01-01T00:00:00Z) aloud, in UTC
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::timestamps::epoch_to_words;
+/// assert_eq!(
+///     epoch_to_words(1_709_474_730),
+///     "March third, twenty twenty-four, fourteen oh five and thirty seconds UTC".to_string()
+/// );
+/// assert_eq!(
+///     epoch_to_words(0),
+///     "January first, nineteen seventy, zero hundred UTC".to_string()
+/// );
+/// ```
+pub fn epoch_to_words(secs: i64) -> String {
+    Timestamp::from_epoch(secs).to_words()
+}
+
+/// Reads an RFC 3339 timestamp aloud, after converting it to UTC
+///
+/// Fractional seconds are dropped. A leap second is read as the sixtieth second of
+/// 23:59 UTC, and is an error at any other time.
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::timestamps::rfc3339_to_words;
+/// assert_eq!(
+///     rfc3339_to_words("2024-03-03T14:05:30Z"),
+///     Ok("March third, twenty twenty-four, fourteen oh five and thirty seconds UTC".to_string())
+/// );
+/// assert_eq!(
+///     rfc3339_to_words("2024-03-03T16:05:30.25+02:00"),
+///     Ok("March third, twenty twenty-four, fourteen oh five and thirty seconds UTC".to_string())
+/// );
+/// assert_eq!(
+///     rfc3339_to_words("2016-12-31T23:59:60Z"),
+///     Ok("December thirty-first, twenty sixteen, twenty-three fifty-nine and sixty seconds \
+///         UTC"
+///         .to_string())
+/// );
+/// assert!(rfc3339_to_words("2024-02-30T00:00:00Z").is_err());
+/// ```
+pub fn rfc3339_to_words(text: &str) -> Result<String, &'static str> {
+    let (secs, leap) = Timestamp::parse_rfc3339(text)?;
+    let mut timestamp = Timestamp::from_epoch(secs);
+    if leap {
+        timestamp.second = 60;
+    }
+    Ok(timestamp.to_words())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_days_round_trip() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_016), (2000, 2, 29));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        for days in [-1_000_000, -719_468, -1, 0, 59, 11_016, 19_785, 2_932_896] {
+            let (y, m, d) = civil_from_days(days);
+            assert_eq!(days_from_civil(y, m, d), days);
+        }
+    }
+
+    #[test]
+    fn test_from_epoch() {
+        assert_eq!(
+            Timestamp::from_epoch(951_816_600),
+            Timestamp {
+                year: 2000,
+                month: 2,
+                day: 29,
+                hour: 9,
+                minute: 30,
+                second: 0
+            }
+        );
+        assert_eq!(
+            Timestamp::from_epoch(-2_020_636_799),
+            Timestamp {
+                year: 1905,
+                month: 12,
+                day: 21,
+                hour: 0,
+                minute: 0,
+                second: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(
+            Timestamp::parse_rfc3339("2024-03-03T14:05:30Z"),
+            Ok((1_709_474_730, false))
+        );
+        assert_eq!(
+            Timestamp::parse_rfc3339("2024-03-03 09:05:30.999-05:00"),
+            Ok((1_709_474_730, false))
+        );
+        assert_eq!(
+            Timestamp::parse_rfc3339("1970-01-01t00:00:00z"),
+            Ok((0, false))
+        );
+        assert_eq!(
+            Timestamp::parse_rfc3339("2016-12-31T18:59:60-05:00"),
+            Ok((1_483_228_799, true))
+        );
+        assert_eq!(
+            Timestamp::parse_rfc3339("2024-03-03T14:05:60Z"),
+            Err("Invalid leap second.")
+        );
+        assert!(Timestamp::parse_rfc3339("2024-03-03T14:05:61Z").is_err());
+        assert!(Timestamp::parse_rfc3339("2024-03-03").is_err());
+        assert!(Timestamp::parse_rfc3339("2024-13-03T14:05:30Z").is_err());
+        assert!(Timestamp::parse_rfc3339("2023-02-29T14:05:30Z").is_err());
+        assert!(Timestamp::parse_rfc3339("2024-03-03T24:05:30Z").is_err());
+        assert!(Timestamp::parse_rfc3339("2024-03-03T14:05:30").is_err());
+        assert!(Timestamp::parse_rfc3339("2024-03-03T14:05:30+0200").is_err());
+        assert!(Timestamp::parse_rfc3339("2024-03-03T14:05:30.Z").is_err());
+    }
+
+    #[test]
+    fn test_year_words() {
+        assert_eq!(year_words(2024), String::from("twenty twenty-four"));
+        assert_eq!(year_words(1905), String::from("nineteen oh five"));
+        assert_eq!(year_words(1900), String::from("nineteen hundred"));
+        assert_eq!(year_words(2000), String::from("two thousand"));
+        assert_eq!(year_words(2007), String::from("two thousand seven"));
+        assert_eq!(year_words(2010), String::from("twenty ten"));
+        assert_eq!(year_words(476), String::from("four-hundred seventy-six"));
+        assert_eq!(year_words(0), String::from("one BC"));
+        assert_eq!(year_words(-43), String::from("forty-four BC"));
+    }
+
+    #[test]
+    fn test_time_words() {
+        assert_eq!(time_words(14, 0, 0), String::from("fourteen hundred"));
+        assert_eq!(time_words(0, 5, 0), String::from("zero oh five"));
+        assert_eq!(
+            time_words(9, 30, 1),
+            String::from("nine thirty and one second")
+        );
+        assert_eq!(
+            time_words(23, 59, 59),
+            String::from("twenty-three fifty-nine and fifty-nine seconds")
+        );
+    }
+
+    #[test]
+    fn test_epoch_to_words() {
+        assert_eq!(
+            epoch_to_words(951_816_600),
+            String::from("February twenty-ninth, two thousand, nine thirty UTC")
+        );
+        assert_eq!(
+            epoch_to_words(-2_020_636_799),
+            String::from(
+                "December twenty-first, nineteen oh five, zero hundred and one second UTC"
+            )
+        );
+    }
+}
@@ -0,0 +1,311 @@
+/* src/tokens.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::conversion_to_words::{nonzero_groups, scale_word, AndBehavior, TEENS, TENS, UNITS};
+use std::fmt;
+
+/// The text between two words
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Separator {
+    /// "-", as in "forty-two" or "three-hundred"
+    Hyphen,
+
+    /// " "
+    Space,
+
+    /// ", ", between the groups of three digits
+    Comma,
+}
+
+/// A piece of the words for a number, with its role
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NumberToken {
+    /// "zero" to "nine", given as the digit
+    Digit(u8),
+
+    /// "ten" to "nineteen", given as the number
+    Teen(u8),
+
+    /// "twenty" to "ninety", given as the tens digit
+    Tens(u8),
+
+    /// "hundred"
+    Hundred,
+
+    /// "thousand", "million", ..., given as the group index (1 for the thousands)
+    ScaleWord(usize),
+
+    /// "and"
+    Conjunction,
+
+    /// The text between two words
+    Separator(Separator),
+}
+
+impl NumberToken {
+    /// The text of the token, as it appears in the words from `to_word`
+    pub fn text(&self) -> &'static str {
+        match self {
+            NumberToken::Digit(d) => UNITS[*d as usize],
+            NumberToken::Teen(n) => TEENS[*n as usize - 10],
+            NumberToken::Tens(t) => TENS[*t as usize],
+            NumberToken::Hundred => "hundred",
+            NumberToken::ScaleWord(group) => scale_word(*group).trim_start(),
+            NumberToken::Conjunction => "and",
+            NumberToken::Separator(Separator::Hyphen) => "-",
+            NumberToken::Separator(Separator::Space) => " ",
+            NumberToken::Separator(Separator::Comma) => ", ",
+        }
+    }
+
+    /// Whether the token is a word, rather than a separator
+    pub fn is_word(&self) -> bool {
+        !matches!(self, NumberToken::Separator(_))
+    }
+}
+
+impl fmt::Display for NumberToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text())
+    }
+}
+
+/// The tokens for 1 to 99
+fn under_100(x: u64, tokens: &mut Vec<NumberToken>) {
+    match x {
+        1..=9 => tokens.push(NumberToken::Digit(x as u8)),
+        10..=19 => tokens.push(NumberToken::Teen(x as u8)),
+        _ => {
+            tokens.push(NumberToken::Tens((x / 10) as u8));
+            if x % 10 != 0 {
+                tokens.push(NumberToken::Separator(Separator::Hyphen));
+                tokens.push(NumberToken::Digit((x % 10) as u8));
+            }
+        }
+    }
+}
+
+/// Converts a number to typed tokens, which joined together read the same as `to_word`
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::tokens::{to_tokens, NumberToken, Separator};
+/// use numbers_into_words::{to_word, AndBehavior};
+/// assert_eq!(
+///     to_tokens(2_105, AndBehavior::All),
+///     vec![
+///         NumberToken::Digit(2),
+///         NumberToken::Separator(Separator::Space),
+///         NumberToken::ScaleWord(1),
+///         NumberToken::Separator(Separator::Comma),
+///         NumberToken::Digit(1),
+///         NumberToken::Separator(Separator::Hyphen),
+///         NumberToken::Hundred,
+///         NumberToken::Separator(Separator::Space),
+///         NumberToken::Conjunction,
+///         NumberToken::Separator(Separator::Space),
+///         NumberToken::Digit(5),
+///     ]
+/// );
+/// let words: String = to_tokens(2_105, AndBehavior::All)
+///     .iter()
+///     .map(|token| token.text())
+///     .collect();
+/// assert_eq!(words, to_word(2_105, AndBehavior::All));
+/// ```
+pub fn to_tokens(x: u64, and_behavior: AndBehavior) -> Vec<NumberToken> {
+    if x == 0 {
+        return vec![NumberToken::Digit(0)];
+    }
+    let mut tokens = Vec::new();
+    for (k, (value, group)) in nonzero_groups(x as u128).into_iter().enumerate() {
+        if k > 0 {
+            tokens.push(NumberToken::Separator(Separator::Comma));
+        }
+        let (hundreds, rest) = (value / 100, value % 100);
+        if hundreds > 0 {
+            tokens.push(NumberToken::Digit(hundreds as u8));
+            tokens.push(NumberToken::Separator(Separator::Hyphen));
+            tokens.push(NumberToken::Hundred);
+            if rest > 0 {
+                tokens.push(NumberToken::Separator(Separator::Space));
+                if and_behavior.uses_and(group, x as u128) {
+                    tokens.push(NumberToken::Conjunction);
+                    tokens.push(NumberToken::Separator(Separator::Space));
+                }
+            }
+        }
+        if rest > 0 {
+            under_100(rest, &mut tokens);
+        }
+        if group > 0 {
+            tokens.push(NumberToken::Separator(Separator::Space));
+            tokens.push(NumberToken::ScaleWord(group));
+        }
+    }
+    tokens
+}
+
+/// Signals how the words of a number are joined into an identifier
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum IdentifierStyle {
+    /// "four_hundred_twenty_three"
+    Snake,
+
+    /// "four-hundred-twenty-three"
+    Kebab,
+
+    /// "FourHundredTwentyThree"
+    Camel,
+}
+
+/// Joins words into an identifier, dropping "and" and any punctuation
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::tokens::{identifier, IdentifierStyle};
+/// assert_eq!(
+///     identifier("Two thousand, and twenty-one", IdentifierStyle::Snake),
+///     "two_thousand_twenty_one".to_string()
+/// );
+/// assert_eq!(
+///     identifier("négatif cinq", IdentifierStyle::Camel),
+///     "NégatifCinq".to_string()
+/// );
+/// ```
+pub fn identifier(words: &str, style: IdentifierStyle) -> String {
+    let parts = words
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty() && !part.eq_ignore_ascii_case("and"))
+        .map(|part| part.to_lowercase());
+    match style {
+        IdentifierStyle::Snake => parts.collect::<Vec<String>>().join("_"),
+        IdentifierStyle::Kebab => parts.collect::<Vec<String>>().join("-"),
+        IdentifierStyle::Camel => parts
+            .map(|part| {
+                let mut chars = part.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => String::new(),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Converts a number to an identifier, such as "four_hundred_twenty_three" or
+/// "FourHundredTwentyThree", for code generation and slugs
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::tokens::{to_identifier, IdentifierStyle};
+/// assert_eq!(
+///     to_identifier(423, IdentifierStyle::Snake),
+///     "four_hundred_twenty_three".to_string()
+/// );
+/// assert_eq!(
+///     to_identifier(1_000_001, IdentifierStyle::Kebab),
+///     "one-million-one".to_string()
+/// );
+/// assert_eq!(
+///     to_identifier(423, IdentifierStyle::Camel),
+///     "FourHundredTwentyThree".to_string()
+/// );
+/// ```
+pub fn to_identifier(x: u64, style: IdentifierStyle) -> String {
+    let words: Vec<&str> = to_tokens(x, AndBehavior::None)
+        .iter()
+        .filter(|token| token.is_word())
+        .map(|token| token.text())
+        .collect();
+    identifier(&words.join(" "), style)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::conversion_to_words::to_word;
+
+    #[test]
+    fn test_to_tokens() {
+        let and_behaviors = [
+            AndBehavior::None,
+            AndBehavior::LastGroup,
+            AndBehavior::OnlyUnderThousand,
+            AndBehavior::All,
+        ];
+        let values = (0..2_000)
+            .chain([
+                10_010,
+                999_999,
+                1_000_000,
+                330_759_736,
+                7_000_000_000_000_000_012,
+                u64::MAX,
+            ])
+            .chain((0..64).map(|k| 0x9E37_79B9_7F4A_7C15_u64 >> k));
+        for x in values {
+            for b in and_behaviors {
+                let words: String = to_tokens(x, b).iter().map(|t| t.text()).collect();
+                assert_eq!(words, to_word(x, b));
+            }
+        }
+        assert_eq!(
+            to_tokens(40_017, AndBehavior::None),
+            vec![
+                NumberToken::Tens(4),
+                NumberToken::Separator(Separator::Space),
+                NumberToken::ScaleWord(1),
+                NumberToken::Separator(Separator::Comma),
+                NumberToken::Teen(17),
+            ]
+        );
+        assert_eq!(
+            to_tokens(1_000_000, AndBehavior::All)
+                .iter()
+                .filter(|t| t.is_word())
+                .map(|t| t.to_string())
+                .collect::<Vec<String>>(),
+            vec!["one", "million"]
+        );
+    }
+
+    #[test]
+    fn test_identifier() {
+        for x in [0, 15, 2_859, 1_000_000, u64::MAX] {
+            let snake = to_identifier(x, IdentifierStyle::Snake);
+            assert_eq!(
+                identifier(&to_word(x, AndBehavior::All), IdentifierStyle::Snake),
+                snake
+            );
+            assert_eq!(
+                to_identifier(x, IdentifierStyle::Kebab),
+                snake.replace('_', "-")
+            );
+            assert!(!snake.split('_').any(|part| part == "and"));
+        }
+        assert_eq!(to_identifier(0, IdentifierStyle::Camel), "Zero".to_string());
+        assert_eq!(identifier("", IdentifierStyle::Snake), String::new());
+        assert_eq!(
+            identifier("ONE-HUNDRED AND FIVE (US$105)", IdentifierStyle::Kebab),
+            "one-hundred-five-us-105".to_string()
+        );
+        assert_eq!(
+            identifier("Thousand, anderson", IdentifierStyle::Camel),
+            "ThousandAnderson".to_string()
+        );
+    }
+}
@@ -0,0 +1,121 @@
+/* src/version_info.rs
+ *
+ * This file is part of numbers_into_words
+ *
+ * Copyright (C) 2023 Christopher Phan
+ * https://chrisphan.com/
+ *
+ * Licensed under MIT or APACHE 2.0
+ *
+ * See LICENSE-MIT.txt and LICENSE-APACHE-2.0.txt
+ * in repository root directory.
+ * */
+
+use super::languages::LANGUAGE_CODES;
+use std::fmt;
+
+/// The cargo features of this crate, and whether each was enabled when it was built
+const FEATURES: [(&str, bool); 5] = [
+    ("bigint", cfg!(feature = "bigint")),
+    ("clipboard", cfg!(feature = "clipboard")),
+    ("const-table", cfg!(feature = "const-table")),
+    ("count-allocations", cfg!(feature = "count-allocations")),
+    ("proptest", cfg!(feature = "proptest")),
+];
+
+/// The version of this crate and what was compiled into it, for tools that need to
+/// check what it can do
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VersionInfo {
+    /// The full version, e.g. "0.1.2"
+    pub version: &'static str,
+    /// The major version, e.g. 0 for "0.1.2"
+    pub major: u64,
+    /// The minor version, e.g. 1 for "0.1.2"
+    pub minor: u64,
+    /// The patch version, e.g. 2 for "0.1.2"
+    pub patch: u64,
+    /// The cargo features that were enabled, in alphabetical order
+    pub features: Vec<&'static str>,
+    /// The codes of the languages that can be written, as for `--lang=`, in alphabetical
+    /// order
+    pub languages: Vec<&'static str>,
+}
+
+impl VersionInfo {
+    /// Whether the cargo feature `name` was enabled, e.g. "bigint"
+    pub fn has_feature(&self, name: &str) -> bool {
+        self.features.contains(&name)
+    }
+
+    /// Whether words can be written in the language with this code, e.g. "fr"
+    pub fn has_language(&self, code: &str) -> bool {
+        self.languages.contains(&code)
+    }
+}
+
+impl fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "numbers_into_words {}", self.version)?;
+        writeln!(
+            f,
+            "Features: {}",
+            if self.features.is_empty() {
+                "none".to_string()
+            } else {
+                self.features.join(", ")
+            }
+        )?;
+        write!(f, "Languages: {}", self.languages.join(", "))
+    }
+}
+
+/// The version of this crate, the cargo features it was built with, and the languages
+/// it can write
+///
+/// # Examples
+///
+/// ```
+/// use numbers_into_words::version;
+/// let info = version();
+/// assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+/// assert!(info.has_language("fr"));
+/// assert_eq!(info.has_feature("bigint"), cfg!(feature = "bigint"));
+/// ```
+pub fn version() -> VersionInfo {
+    let version = env!("CARGO_PKG_VERSION");
+    let number = |part: &str| part.parse().expect("cargo versions are numeric");
+    VersionInfo {
+        version,
+        major: number(env!("CARGO_PKG_VERSION_MAJOR")),
+        minor: number(env!("CARGO_PKG_VERSION_MINOR")),
+        patch: number(env!("CARGO_PKG_VERSION_PATCH")),
+        features: FEATURES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| *name)
+            .collect(),
+        languages: LANGUAGE_CODES.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version() {
+        let info = version();
+        assert_eq!(
+            info.version,
+            format!("{}.{}.{}", info.major, info.minor, info.patch)
+        );
+        assert_eq!(info.has_feature("clipboard"), cfg!(feature = "clipboard"));
+        assert!(!info.has_feature("tables"));
+        assert!(info.has_language("en") && !info.has_language("xx"));
+        let text = info.to_string();
+        assert!(text.starts_with(&format!("numbers_into_words {}\nFeatures: ", info.version)));
+        assert!(text.contains("Languages: ar, cy, cy-traditional, da, de, en, eo, es, fr, "));
+        assert!(text.ends_with("zh, zh-daxie"));
+    }
+}